@@ -0,0 +1,58 @@
+"""Opt-in lazy imports (``-X lazy_imports``).
+
+When enabled, a meta-path finder wraps the loader of every plain module in
+:class:`importlib.util.LazyLoader`, so module execution is deferred until the
+first attribute access.  Packages, extension modules and anything without an
+``exec_module`` loader are imported eagerly as usual, since they commonly
+rely on import-time side effects.
+"""
+
+import sys
+
+__all__ = ["enable", "disable"]
+
+_finder = None
+
+
+class _LazyFinder:
+    def __init__(self, lazy_loader):
+        self._lazy_loader = lazy_loader
+
+    def find_spec(self, fullname, path=None, target=None):
+        for finder in sys.meta_path:
+            if finder is self:
+                continue
+            find_spec = getattr(finder, "find_spec", None)
+            if find_spec is None:
+                continue
+            spec = find_spec(fullname, path, target)
+            if spec is None:
+                continue
+            if (spec.loader is not None
+                    and spec.submodule_search_locations is None
+                    and hasattr(spec.loader, "exec_module")):
+                spec.loader = self._lazy_loader(spec.loader)
+            return spec
+        return None
+
+
+def enable():
+    """Install the lazy finder at the front of sys.meta_path."""
+    global _finder
+    if _finder is None:
+        # resolve LazyLoader eagerly; importing it through the finder would
+        # re-enter find_spec
+        from importlib.util import LazyLoader
+        _finder = _LazyFinder(LazyLoader)
+        sys.meta_path.insert(0, _finder)
+
+
+def disable():
+    """Remove the lazy finder; already-deferred modules stay lazy."""
+    global _finder
+    if _finder is not None:
+        try:
+            sys.meta_path.remove(_finder)
+        except ValueError:
+            pass
+        _finder = None
@@ -444,12 +444,23 @@ _b85chars = None
 _b85chars2 = None
 _b85dec = None
 
+try:
+    # XXX RUSTPYTHON: native kernels for the base85 hot loops
+    from _base85 import b85encode as _b85encode, b85decode as _b85decode
+except ImportError:
+    _b85encode = _b85decode = None
+
 def b85encode(b, pad=False):
     """Encode bytes-like object b in base85 format and return a bytes object.
 
     If pad is true, the input is padded with b'\\0' so its length is a multiple of
     4 bytes before encoding.
     """
+    if _b85encode is not None:
+        # XXX RUSTPYTHON
+        if not isinstance(b, bytes_types):
+            b = memoryview(b).tobytes()
+        return _b85encode(b, pad)
     global _b85chars, _b85chars2
     # Delay the initialization of tables to not waste memory
     # if the function is never called
@@ -472,6 +483,9 @@ def b85decode(b):
             _b85dec[c] = i
 
     b = _bytes_from_decode_data(b)
+    if _b85decode is not None:
+        # XXX RUSTPYTHON
+        return _b85decode(b)
     padding = (-len(b)) % 5
     b = b + b'~' * padding
     out = []
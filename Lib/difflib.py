@@ -34,6 +34,12 @@ from heapq import nlargest as _nlargest
 from collections import namedtuple as _namedtuple
 from types import GenericAlias
 
+# XXX RUSTPYTHON: native inner loop for find_longest_match
+try:
+    from _difflib import find_longest_match as _find_longest_match
+except ImportError:
+    _find_longest_match = None
+
 Match = _namedtuple('Match', 'a b size')
 
 def _calculate_ratio(matches, length):
@@ -365,27 +371,31 @@ class SequenceMatcher:
             ahi = len(a)
         if bhi is None:
             bhi = len(b)
-        besti, bestj, bestsize = alo, blo, 0
-        # find longest junk-free match
-        # during an iteration of the loop, j2len[j] = length of longest
-        # junk-free match ending with a[i-1] and b[j]
-        j2len = {}
-        nothing = []
-        for i in range(alo, ahi):
-            # look at all instances of a[i] in b; note that because
-            # b2j has no junk keys, the loop is skipped if a[i] is junk
-            j2lenget = j2len.get
-            newj2len = {}
-            for j in b2j.get(a[i], nothing):
-                # a[i] matches b[j]
-                if j < blo:
-                    continue
-                if j >= bhi:
-                    break
-                k = newj2len[j] = j2lenget(j-1, 0) + 1
-                if k > bestsize:
-                    besti, bestj, bestsize = i-k+1, j-k+1, k
-            j2len = newj2len
+        # XXX RUSTPYTHON
+        if _find_longest_match is not None and type(b2j) is dict:
+            besti, bestj, bestsize = _find_longest_match(a, b2j, alo, ahi, blo, bhi)
+        else:
+            besti, bestj, bestsize = alo, blo, 0
+            # find longest junk-free match
+            # during an iteration of the loop, j2len[j] = length of longest
+            # junk-free match ending with a[i-1] and b[j]
+            j2len = {}
+            nothing = []
+            for i in range(alo, ahi):
+                # look at all instances of a[i] in b; note that because
+                # b2j has no junk keys, the loop is skipped if a[i] is junk
+                j2lenget = j2len.get
+                newj2len = {}
+                for j in b2j.get(a[i], nothing):
+                    # a[i] matches b[j]
+                    if j < blo:
+                        continue
+                    if j >= bhi:
+                        break
+                    k = newj2len[j] = j2lenget(j-1, 0) + 1
+                    if k > bestsize:
+                        besti, bestj, bestsize = i-k+1, j-k+1, k
+                j2len = newj2len
 
         # Extend the best by non-junk elements on each end.  In particular,
         # "popular" non-junk elements aren't in b2j, which greatly speeds
@@ -35,6 +35,11 @@ NLCRE_crack = re.compile(r'(\r\n|\r|\n)')
 # RFC 2822 $3.6.8 Optional fields.  ftext is %d33-57 / %d59-126, Any character
 # except controls, SP, and ":".
 headerRE = re.compile(r'^(From |[\041-\071\073-\176]*:|[\t ])')
+# XXX RUSTPYTHON: native matcher for the per-line header check
+try:
+    from _feedparser import is_header_line as _is_header_line
+except ImportError:
+    _is_header_line = headerRE.match
 EMPTYSTRING = ''
 NL = '\n'
 boundaryendRE = re.compile(
@@ -225,7 +230,7 @@ class FeedParser:
             if line is NeedMoreData:
                 yield NeedMoreData
                 continue
-            if not headerRE.match(line):
+            if not _is_header_line(line):
                 # If we saw the RFC defined header/body separator
                 # (i.e. newline), just throw it away. Otherwise the line is
                 # part of the body so push it back.
@@ -22,6 +22,12 @@ from itertools import count
 _nextgroupnum = count().__next__
 del count
 
+# XXX RUSTPYTHON: native matcher avoids compiling a regex per pattern
+try:
+    from _fnmatch import fnmatchcase as _fnmatchcase, filter as _filter
+except ImportError:
+    _fnmatchcase = _filter = None
+
 def fnmatch(name, pat):
     """Test whether FILENAME matches PATTERN.
 
@@ -53,8 +59,11 @@ def _compile_pattern(pat):
 
 def filter(names, pat):
     """Construct a list from those elements of the iterable NAMES that match PAT."""
-    result = []
     pat = os.path.normcase(pat)
+    # XXX RUSTPYTHON
+    if _filter is not None and os.path is posixpath and type(pat) is str:
+        return _filter(names, pat)
+    result = []
     match = _compile_pattern(pat)
     if os.path is posixpath:
         # normcase on posix is NOP. Optimize it away from the loop.
@@ -73,6 +82,9 @@ def fnmatchcase(name, pat):
     This is a version of fnmatch() which doesn't case-normalize
     its arguments.
     """
+    # XXX RUSTPYTHON
+    if _fnmatchcase is not None and type(name) is str and type(pat) is str:
+        return _fnmatchcase(name, pat)
     match = _compile_pattern(pat)
     return match(name) is not None
 
@@ -0,0 +1,178 @@
+"""A greenlet compatibility layer for RustPython.
+
+The C ``greenlet`` extension switches between micro-threads by swapping the
+interpreter's C stack, which has no equivalent in RustPython's frame
+machinery.  This module emulates the API on top of OS threads instead: every
+greenlet runs on its own (daemon) thread, but a strict hand-off protocol
+guarantees that at most one greenlet per tree is runnable at any moment, so
+cooperative code written against ``greenlet`` -- eventlet/gevent-style hubs,
+SQLAlchemy's async bridge -- keeps its scheduling semantics.
+
+Known differences from the C extension:
+
+* greenlets are heavier (an OS thread each) and switching is slower;
+* unstarted greenlets are only collected at interpreter exit, not as soon as
+  they become unreferenced;
+* ``gr_frame`` introspection is not provided.
+"""
+
+import threading
+
+__all__ = ["greenlet", "getcurrent", "GreenletExit", "error"]
+
+
+class error(Exception):
+    """Raised on invalid switches."""
+
+
+class GreenletExit(BaseException):
+    """Raised in a greenlet to ask it to exit; quietly ends the greenlet."""
+
+
+_tls = threading.local()
+
+
+def getcurrent():
+    """Return the currently executing greenlet of this thread."""
+    try:
+        return _tls.current
+    except AttributeError:
+        main = greenlet.__new__(greenlet)
+        main._setup(run=None, parent=None)
+        main._started = True
+        _tls.current = main
+        return main
+
+
+def _unpack(payload):
+    """Turn the (args, kwargs) delivered by a switch into its return value."""
+    if payload is None:
+        return None
+    args, kwargs = payload
+    if kwargs and args:
+        return args, kwargs
+    if kwargs:
+        return kwargs
+    if len(args) == 1:
+        return args[0]
+    if not args:
+        return None
+    return args
+
+
+class greenlet:
+    def __init__(self, run=None, parent=None):
+        self._setup(run, parent if parent is not None else getcurrent())
+
+    def _setup(self, run, parent):
+        if run is not None:
+            # leave subclass run() methods visible when no callable is given
+            self.run = run
+        self.parent = parent
+        self._started = False
+        self._dead = False
+        self._resume = threading.Event()
+        # the (args, kwargs) or exception delivered by the switch resuming us
+        self._payload = None
+        self._pending_exc = None
+        self._thread = None
+
+    def __repr__(self):
+        state = (
+            "dead"
+            if self._dead
+            else "started" if self._started else "suspended"
+        )
+        return f"<greenlet.greenlet object at {id(self):#x} ({state})>"
+
+    def __bool__(self):
+        return self._started and not self._dead
+
+    @property
+    def dead(self):
+        return self._dead
+
+    def switch(self, *args, **kwargs):
+        """Switch execution to this greenlet, passing the given arguments."""
+        return self._transfer((args, kwargs), None)
+
+    def throw(self, typ=GreenletExit, val=None, tb=None):
+        """Switch to this greenlet and immediately raise an exception there."""
+        if isinstance(typ, BaseException):
+            exc = typ
+        elif isinstance(val, BaseException):
+            exc = val
+        elif val is None:
+            exc = typ()
+        else:
+            exc = typ(val)
+        if tb is not None:
+            exc = exc.with_traceback(tb)
+        return self._transfer(None, exc)
+
+    def _transfer(self, payload, exc):
+        current = getcurrent()
+        if self is current:
+            # switching to oneself just returns the arguments
+            if exc is not None:
+                raise exc
+            return _unpack(payload)
+
+        # a switch to a dead greenlet goes to its nearest live ancestor
+        target = self
+        while target._dead:
+            target = target.parent
+            if target is None:
+                raise error("cannot switch to a garbage collected greenlet")
+
+        target._payload = payload
+        target._pending_exc = exc
+        if not target._started:
+            if "run" not in target.__dict__ and type(target).run is greenlet.run:
+                raise error("greenlet has no run() method")
+            target._started = True
+            target._thread = threading.Thread(
+                target=target._bootstrap, daemon=True, name="greenlet"
+            )
+            target._thread.start()
+        else:
+            target._resume.set()
+
+        # block until another greenlet switches back to us
+        current._resume.wait()
+        current._resume.clear()
+        if current._pending_exc is not None:
+            pending = current._pending_exc
+            current._pending_exc = None
+            raise pending
+        return _unpack(current._payload)
+
+    # subclasses commonly override run() instead of passing it to __init__
+    def run(self, *args, **kwargs):
+        raise error("greenlet has no run() method")
+
+    def _bootstrap(self):
+        _tls.current = self
+        try:
+            if self._pending_exc is not None:
+                pending = self._pending_exc
+                self._pending_exc = None
+                raise pending
+            args, kwargs = self._payload
+            result = self.run(*args, **kwargs)
+            payload, exc = ((result,), {}), None
+        except GreenletExit as e:
+            # a greenlet asked to exit dies quietly; its parent receives the
+            # GreenletExit instance as the switch value
+            payload, exc = ((e,), {}), None
+        except BaseException as e:
+            # any other exception is re-raised in the parent
+            payload, exc = None, e
+
+        self._dead = True
+        parent = self.parent
+        while parent._dead:
+            parent = parent.parent
+        parent._payload = payload
+        parent._pending_exc = exc
+        parent._resume.set()
@@ -13,6 +13,12 @@ import _markupbase
 
 from html import unescape
 
+# XXX RUSTPYTHON: native fast path for scanning to the next token boundary
+try:
+    from _html_parser import find_interesting as _find_interesting
+except ImportError:
+    _find_interesting = None
+
 
 __all__ = ['HTMLParser']
 
@@ -150,10 +156,16 @@ class HTMLParser(_markupbase.ParserBase):
                         break  # wait till we get all the text
                     j = n
             else:
-                match = self.interesting.search(rawdata, i)  # < or &
-                if match:
-                    j = match.start()
-                else:
+                # XXX RUSTPYTHON: fast path; falls back to the regex for
+                # non-ASCII data or a customized self.interesting
+                j = None
+                if (_find_interesting is not None and
+                        (self.cdata_elem or self.interesting is interesting_normal)):
+                    j = _find_interesting(rawdata, i, self.cdata_elem)
+                if j is None:
+                    match = self.interesting.search(rawdata, i)  # < or &
+                    j = match.start() if match else -1
+                if j < 0:
                     if self.cdata_elem:
                         break
                     j = n
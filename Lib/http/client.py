@@ -79,6 +79,12 @@ import sys
 import collections.abc
 from urllib.parse import urlsplit
 
+# XXX RUSTPYTHON: native fast path for response header parsing
+try:
+    from _http_client import parse_header_lines as _parse_header_lines
+except ImportError:
+    _parse_header_lines = None
+
 # HTTPMessage, parse_headers(), and the HTTP status code constants are
 # intentionally omitted for simplicity
 __all__ = ["HTTPResponse", "HTTPConnection",
@@ -232,6 +238,16 @@ def parse_headers(fp, _class=HTTPMessage):
 
     """
     headers = _read_headers(fp)
+    # XXX RUSTPYTHON: fast path; the email.parser code below is kept as the
+    # fallback for folded headers and other unusual input
+    if _parse_header_lines is not None and _class is HTTPMessage:
+        parsed = _parse_header_lines(headers)
+        if parsed is not None:
+            msg = HTTPMessage()
+            for name, value in parsed:
+                msg[name] = value
+            msg.set_payload('')
+            return msg
     hstring = b''.join(headers).decode('iso-8859-1')
     return email.parser.Parser(_class=_class).parsestr(hstring)
 
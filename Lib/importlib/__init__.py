@@ -109,6 +109,11 @@ def reload(module):
 
     if sys.modules.get(name) is not module:
         raise ImportError(f"module {name} not in sys.modules", name=name)
+    # XXX RUSTPYTHON: native modules have no source to re-execute; reloading
+    # them would silently do nothing, so fail loudly instead
+    if name in sys.builtin_module_names:
+        raise ImportError(f"cannot reload native module {name!r}; "
+                          "its namespace is defined in Rust", name=name)
     if name in _RELOADING:
         return _RELOADING[name]
     _RELOADING[name] = module
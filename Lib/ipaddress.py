@@ -13,6 +13,12 @@ __version__ = '1.0'
 
 import functools
 
+# XXX RUSTPYTHON: native fast path for IPv4 address parsing
+try:
+    from _ipaddress import v4_int_from_string as _v4_int_from_string
+except ImportError:
+    _v4_int_from_string = None
+
 IPV4LENGTH = 32
 IPV6LENGTH = 128
 
@@ -1194,6 +1200,13 @@ class _BaseV4:
             AddressValueError: if ip_str isn't a valid IPv4 Address.
 
         """
+        # XXX RUSTPYTHON: fast path; the pure-Python code below is kept as
+        # the fallback for its detailed error messages
+        if _v4_int_from_string is not None and type(ip_str) is str:
+            ip_int = _v4_int_from_string(ip_str)
+            if ip_int is not None:
+                return ip_int
+
         if not ip_str:
             raise AddressValueError('Address cannot be empty')
 
@@ -119,6 +119,12 @@ def updatecache(filename, module_globals=None):
         if len(cache[filename]) != 1:
             cache.pop(filename, None)
     if not filename or (filename.startswith('<') and filename.endswith('>')):
+        # XXX RUSTPYTHON: interactive and exec'd sources are recorded in a
+        # VM-level registry keyed by their pseudo-filename; don't cache them
+        # here so re-compiled sources stay fresh
+        source = getattr(sys, '_get_registered_source', lambda _: None)(filename)
+        if source is not None:
+            return [line + '\n' for line in source.splitlines()]
         return []
 
     fullname = filename
@@ -41,6 +41,12 @@ import sys as _sys
 import types as _types
 from io import StringIO as _StringIO
 
+# XXX RUSTPYTHON: native fast path for _safe_repr
+try:
+    from _pprint import safe_repr as _safe_repr_native
+except ImportError:
+    _safe_repr_native = None
+
 __all__ = ["pprint","pformat","isreadable","isrecursive","saferepr",
            "PrettyPrinter", "pp"]
 
@@ -553,6 +559,14 @@ class PrettyPrinter:
 
     def _safe_repr(self, object, context, maxlevels, level):
         # Return triple (repr_string, isreadable, isrecursive).
+        # XXX RUSTPYTHON: the native pass handles exact builtin containers;
+        # it bails out (returns None) whenever overridden hooks could matter
+        if (_safe_repr_native is not None and not self._underscore_numbers
+                and type(self).format is PrettyPrinter.format):
+            result = _safe_repr_native(object, tuple(context), maxlevels or 0,
+                                       level, self._sort_dicts)
+            if result is not None:
+                return result
         typ = type(object)
         if typ in _builtin_scalars:
             return repr(object), True, False
@@ -0,0 +1,266 @@
+"""A Jupyter kernel for RustPython: ``rustpython -m rustpython_kernel file``.
+
+The protocol side — wire-format encoding, HMAC signing and the
+execute_request / complete_request / inspect_request / kernel_info_request
+handlers — is implemented here in full and independent of the transport, so
+it can be exercised (and tested) without sockets. The ZeroMQ transport
+itself is looked up at startup: running as a kernel needs a ``zmq`` module
+(pyzmq-compatible) importable; without one the entry point exits with an
+explanatory message instead of a traceback.
+
+This module is RustPython-specific.
+"""
+
+import hashlib
+import hmac
+import json
+import sys
+import uuid
+
+PROTOCOL_VERSION = "5.3"
+
+DELIMITER = b"<IDS|MSG>"
+
+
+def sign(key, parts):
+    """HMAC-SHA256 signature over the serialized message frames."""
+    mac = hmac.new(key, digestmod=hashlib.sha256)
+    for part in parts:
+        mac.update(part)
+    return mac.hexdigest().encode("ascii")
+
+
+def make_header(msg_type, session):
+    return {
+        "msg_id": uuid.uuid4().hex,
+        "session": session,
+        "username": "kernel",
+        "msg_type": msg_type,
+        "version": PROTOCOL_VERSION,
+        "date": __import__("datetime").datetime.utcnow().isoformat() + "Z",
+    }
+
+
+def serialize(key, identities, header, parent, content, metadata=None):
+    """Encode a message into its wire frames (identities included)."""
+    parts = [
+        json.dumps(header).encode("utf-8"),
+        json.dumps(parent or {}).encode("utf-8"),
+        json.dumps(metadata or {}).encode("utf-8"),
+        json.dumps(content).encode("utf-8"),
+    ]
+    return list(identities) + [DELIMITER, sign(key, parts)] + parts
+
+
+def deserialize(key, frames):
+    """Split wire frames into (identities, header, parent, content),
+    verifying the signature."""
+    idx = frames.index(DELIMITER)
+    identities, signature = frames[:idx], frames[idx + 1]
+    parts = frames[idx + 2:idx + 6]
+    expected = sign(key, parts)
+    if not hmac.compare_digest(signature, expected):
+        raise ValueError("invalid message signature")
+    header, parent, _metadata, content = (json.loads(p) for p in parts)
+    return identities, header, parent, content
+
+
+def _cursor_word(code, cursor_pos):
+    """The identifier (dotted path allowed) ending at cursor_pos."""
+    start = cursor_pos
+    while start > 0 and (code[start - 1].isalnum() or code[start - 1] in "._"):
+        start -= 1
+    return code[start:cursor_pos], start
+
+
+class Kernel:
+    """Transport-independent request handlers operating on one namespace."""
+
+    implementation = "rustpython"
+    banner = "RustPython kernel"
+
+    def __init__(self):
+        self.user_ns = {"__name__": "__main__", "__builtins__": __builtins__}
+        self.execution_count = 0
+
+    def kernel_info(self):
+        return {
+            "status": "ok",
+            "protocol_version": PROTOCOL_VERSION,
+            "implementation": self.implementation,
+            "implementation_version": ".".join(map(str, sys.version_info[:3])),
+            "language_info": {
+                "name": "python",
+                "version": sys.version.split()[0],
+                "mimetype": "text/x-python",
+                "file_extension": ".py",
+            },
+            "banner": self.banner,
+        }
+
+    def do_execute(self, code, silent=False):
+        """Run `code`; an expression statement's value is the result, like
+        the REPL. Returns an execute_reply content dict, plus the repr to
+        display (or None)."""
+        self.execution_count += 1
+        result = None
+        try:
+            try:
+                compiled = compile(code, "<ipython-input>", "eval")
+            except SyntaxError:
+                compiled = compile(code, "<ipython-input>", "exec")
+                exec(compiled, self.user_ns)
+            else:
+                value = eval(compiled, self.user_ns)
+                if value is not None:
+                    self.user_ns["_"] = value
+                    result = repr(value)
+        except BaseException as exc:
+            import traceback
+            return {
+                "status": "error",
+                "execution_count": self.execution_count,
+                "ename": type(exc).__name__,
+                "evalue": str(exc),
+                "traceback": traceback.format_exception(
+                    type(exc), exc, exc.__traceback__),
+            }, None
+        return {
+            "status": "ok",
+            "execution_count": self.execution_count,
+            "payload": [],
+            "user_expressions": {},
+        }, None if silent else result
+
+    def do_complete(self, code, cursor_pos):
+        import rlcompleter
+        word, start = _cursor_word(code, cursor_pos)
+        completer = rlcompleter.Completer(self.user_ns)
+        matches = []
+        for state in range(1000):
+            match = completer.complete(word, state)
+            if match is None:
+                break
+            matches.append(match)
+        return {
+            "status": "ok",
+            "matches": sorted(set(matches)),
+            "cursor_start": start,
+            "cursor_end": cursor_pos,
+            "metadata": {},
+        }
+
+    def do_inspect(self, code, cursor_pos):
+        import pydoc
+        word, _start = _cursor_word(code, cursor_pos)
+        obj = self.user_ns.get(word)
+        if obj is None:
+            try:
+                obj = eval(word, self.user_ns)
+            except BaseException:
+                return {"status": "ok", "found": False, "data": {},
+                        "metadata": {}}
+        text = pydoc.plain(pydoc.render_doc(obj))
+        return {
+            "status": "ok",
+            "found": True,
+            "data": {"text/plain": text},
+            "metadata": {},
+        }
+
+
+def serve(connection_file):
+    """Run the kernel against the sockets described by a Jupyter connection
+    file. Needs a pyzmq-compatible ``zmq`` module."""
+    try:
+        import zmq
+    except ImportError:
+        sys.exit("rustpython_kernel: the zmq module is not available; "
+                 "a pyzmq-compatible binding is required to serve a kernel")
+
+    with open(connection_file) as f:
+        conn = json.load(f)
+    key = conn["key"].encode("ascii")
+    url = "%s://%s:%%i" % (conn["transport"], conn["ip"])
+
+    ctx = zmq.Context()
+    shell = ctx.socket(zmq.ROUTER)
+    shell.bind(url % conn["shell_port"])
+    iopub = ctx.socket(zmq.PUB)
+    iopub.bind(url % conn["iopub_port"])
+    control = ctx.socket(zmq.ROUTER)
+    control.bind(url % conn["control_port"])
+    hb = ctx.socket(zmq.REP)
+    hb.bind(url % conn["hb_port"])
+
+    kernel = Kernel()
+    session = uuid.uuid4().hex
+
+    def publish(msg_type, content, parent):
+        iopub.send_multipart(serialize(
+            key, [msg_type.encode("ascii")],
+            make_header(msg_type, session), parent, content))
+
+    def reply(socket, identities, msg_type, content, parent):
+        socket.send_multipart(serialize(
+            key, identities, make_header(msg_type, session), parent, content))
+
+    poller = zmq.Poller()
+    poller.register(shell, zmq.POLLIN)
+    poller.register(control, zmq.POLLIN)
+    poller.register(hb, zmq.POLLIN)
+
+    while True:
+        for socket, _ in poller.poll():
+            if socket is hb:
+                hb.send(hb.recv())
+                continue
+            identities, header, _parent, content = deserialize(
+                key, socket.recv_multipart())
+            msg_type = header["msg_type"]
+            publish("status", {"execution_state": "busy"}, header)
+            if msg_type == "kernel_info_request":
+                reply(socket, identities, "kernel_info_reply",
+                      kernel.kernel_info(), header)
+            elif msg_type == "execute_request":
+                publish("execute_input",
+                        {"code": content["code"],
+                         "execution_count": kernel.execution_count + 1},
+                        header)
+                result, text = kernel.do_execute(
+                    content["code"], content.get("silent", False))
+                if result["status"] == "error":
+                    publish("error", {k: result[k] for k in
+                                      ("ename", "evalue", "traceback")},
+                            header)
+                elif text is not None:
+                    publish("execute_result",
+                            {"execution_count": result["execution_count"],
+                             "data": {"text/plain": text},
+                             "metadata": {}},
+                            header)
+                reply(socket, identities, "execute_reply", result, header)
+            elif msg_type == "complete_request":
+                reply(socket, identities, "complete_reply",
+                      kernel.do_complete(content["code"],
+                                         content["cursor_pos"]),
+                      header)
+            elif msg_type == "inspect_request":
+                reply(socket, identities, "inspect_reply",
+                      kernel.do_inspect(content["code"],
+                                        content["cursor_pos"]),
+                      header)
+            elif msg_type == "shutdown_request":
+                reply(socket, identities, "shutdown_reply",
+                      {"status": "ok",
+                       "restart": content.get("restart", False)},
+                      header)
+                publish("status", {"execution_state": "idle"}, header)
+                return
+            publish("status", {"execution_state": "idle"}, header)
+
+
+if __name__ == "__main__":
+    if len(sys.argv) != 2:
+        sys.exit("usage: rustpython -m rustpython_kernel CONNECTION_FILE")
+    serve(sys.argv[1])
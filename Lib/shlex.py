@@ -308,6 +308,14 @@ def split(s, comments=False, posix=True):
         import warnings
         warnings.warn("Passing None for 's' to shlex.split() is deprecated.",
                       DeprecationWarning, stacklevel=2)
+    if posix and isinstance(s, str):
+        # XXX RUSTPYTHON: fast path implemented in Rust
+        try:
+            from _shlex import split as _posix_split
+        except ImportError:
+            pass
+        else:
+            return _posix_split(s, comments)
     lex = shlex(s, posix=posix)
     lex.whitespace_split = True
     if not comments:
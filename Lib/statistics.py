@@ -340,6 +340,12 @@ def fmean(data):
     >>> fmean([3.5, 4.0, 5.25])
     4.25
     """
+    # XXX RUSTPYTHON: use the native kernel for common sequence types
+    if _fmean is not None and type(data) in (list, tuple):
+        try:
+            return _fmean(data)
+        except ValueError:
+            raise StatisticsError('fmean requires at least one data point') from None
     try:
         n = len(data)
     except TypeError:
@@ -1047,6 +1053,12 @@ try:
 except ImportError:
     pass
 
+# XXX RUSTPYTHON: native mean kernel used by fmean()
+try:
+    from _statistics import _fmean
+except ImportError:
+    _fmean = None
+
 
 class NormalDist:
     "Normal distribution of a random variable"
@@ -52,6 +52,14 @@ def capwords(s, sep=None):
 import re as _re
 from collections import ChainMap as _ChainMap
 
+# XXX RUSTPYTHON: native fast paths for the default Template pattern
+try:
+    from _string import (template_identifiers as _template_identifiers,
+                         template_is_valid as _template_is_valid,
+                         template_substitute as _template_substitute)
+except ImportError:
+    _template_substitute = None
+
 _sentinel_dict = {}
 
 class Template:
@@ -106,6 +114,13 @@ class Template:
             mapping = kws
         elif kws:
             mapping = _ChainMap(kws, mapping)
+        # XXX RUSTPYTHON: fast path; the regex path below is kept for custom
+        # patterns and for the detailed invalid-placeholder error
+        if (_template_substitute is not None
+                and self.pattern is _default_template_pattern):
+            result = _template_substitute(self.template, mapping, False)
+            if result is not None:
+                return result
         # Helper function for .sub()
         def convert(mo):
             # Check the most common path first.
@@ -125,6 +140,11 @@ class Template:
             mapping = kws
         elif kws:
             mapping = _ChainMap(kws, mapping)
+        # XXX RUSTPYTHON: fast path; never fails over to the regex since
+        # safe substitution leaves ill-formed placeholders untouched
+        if (_template_substitute is not None
+                and self.pattern is _default_template_pattern):
+            return _template_substitute(self.template, mapping, True)
         # Helper function for .sub()
         def convert(mo):
             named = mo.group('named') or mo.group('braced')
@@ -142,6 +162,10 @@ class Template:
         return self.pattern.sub(convert, self.template)
 
     def is_valid(self):
+        # XXX RUSTPYTHON: fast path for the default pattern
+        if (_template_substitute is not None
+                and self.pattern is _default_template_pattern):
+            return _template_is_valid(self.template)
         for mo in self.pattern.finditer(self.template):
             if mo.group('invalid') is not None:
                 return False
@@ -155,6 +179,10 @@ class Template:
         return True
 
     def get_identifiers(self):
+        # XXX RUSTPYTHON: fast path for the default pattern
+        if (_template_substitute is not None
+                and self.pattern is _default_template_pattern):
+            return _template_identifiers(self.template)
         ids = []
         for mo in self.pattern.finditer(self.template):
             named = mo.group('named') or mo.group('braced')
@@ -173,6 +201,8 @@ class Template:
 # Initialize Template.pattern.  __init_subclass__() is automatically called
 # only for subclasses, not for the Template class itself.
 Template.__init_subclass__()
+# XXX RUSTPYTHON: lets the fast paths detect that the default pattern applies
+_default_template_pattern = Template.pattern
 
 
 ########################################################################
@@ -14,6 +14,12 @@ __all__ = ['TextWrapper', 'wrap', 'fill', 'dedent', 'indent', 'shorten']
 # some Unicode spaces (like \u00a0) are non-breaking whitespaces.
 _whitespace = '\t\n\x0b\x0c\r '
 
+# XXX RUSTPYTHON: native splitter for the simple (no hyphen-breaking) case
+try:
+    from _textwrap import wordsep_simple as _wordsep_simple
+except ImportError:
+    _wordsep_simple = None
+
 class TextWrapper:
     """
     Object for wrapping/filling text.  The public interface consists of
@@ -175,6 +181,10 @@ class TextWrapper:
         if self.break_on_hyphens is True:
             chunks = self.wordsep_re.split(text)
         else:
+            # XXX RUSTPYTHON
+            if (_wordsep_simple is not None and type(text) is str
+                    and self.wordsep_simple_re is TextWrapper.wordsep_simple_re):
+                return _wordsep_simple(text)
             chunks = self.wordsep_simple_re.split(text)
         chunks = [c for c in chunks if c]
         return chunks
@@ -432,6 +442,14 @@ def dedent(text):
 
     Entirely blank lines are normalized to a newline character.
     """
+    if isinstance(text, str):
+        # XXX RUSTPYTHON: fast path implemented in Rust
+        try:
+            from _textwrap import dedent as _dedent
+        except ImportError:
+            pass
+        else:
+            return _dedent(text)
     # Look for the longest leading string of spaces and tabs common to
     # all lines.
     margin = None
@@ -479,6 +497,15 @@ def indent(text, prefix, predicate=None):
     consist solely of whitespace characters.
     """
     if predicate is None:
+        if isinstance(text, str) and isinstance(prefix, str):
+            # XXX RUSTPYTHON: fast path implemented in Rust
+            try:
+                from _textwrap import indent as _indent
+            except ImportError:
+                pass
+            else:
+                return _indent(text, prefix)
+
         def predicate(line):
             return line.strip()
 
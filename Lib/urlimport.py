@@ -0,0 +1,138 @@
+"""Opt-in meta path importer fetching modules from pinned HTTPS URLs.
+
+Nothing is registered by importing this module; an embedding application
+opts in explicitly:
+
+    import urlimport
+    urlimport.install()
+    urlimport.pin("plugin", "https://plugins.example/plugin.py",
+                  sha256="...")
+    import plugin
+
+Only module names that were pinned beforehand are ever fetched, only over
+https, and the payload must match its pinned sha256 digest. A zip archive of
+modules can be pinned as a content-addressed bundle with pin_bundle(); the
+archive is fetched and verified once, up front.
+
+This module is RustPython-specific; it is aimed at sandboxed plugin
+ecosystems distributing Python code to embeds.
+"""
+
+import hashlib
+import importlib.abc
+import importlib.util
+import sys
+
+__all__ = ["install", "uninstall", "pin", "pin_bundle", "UrlFinder",
+           "UrlImportError"]
+
+
+class UrlImportError(ImportError):
+    """A pinned module could not be fetched or failed verification."""
+
+
+# module name -> (url, sha256 hexdigest)
+_pins = {}
+# (url, zipfile.ZipFile) per verified bundle, in pin order
+_bundles = []
+
+
+def _fetch(url):
+    from urllib.request import urlopen
+    with urlopen(url) as resp:
+        return resp.read()
+
+
+def _check_url(url):
+    if not url.startswith("https://"):
+        raise UrlImportError(
+            "refusing to fetch %r: only https urls can be pinned" % (url,))
+
+
+def _verify(url, data, sha256):
+    actual = hashlib.sha256(data).hexdigest()
+    if actual != sha256:
+        raise UrlImportError(
+            "hash mismatch for %s: pinned sha256:%s, got sha256:%s"
+            % (url, sha256, actual))
+
+
+def pin(name, url, *, sha256):
+    """Allow module `name` to be imported from `url`, verified against the
+    given sha256 hexdigest. The fetch happens lazily, at import time."""
+    _check_url(url)
+    _pins[name] = (url, sha256.lower())
+
+
+def pin_bundle(url, *, sha256):
+    """Fetch and verify a zip archive of modules; its contents become
+    importable like a sys.path zip entry (but only through this finder)."""
+    import io
+    import zipfile
+    _check_url(url)
+    data = _fetch(url)
+    _verify(url, data, sha256.lower())
+    _bundles.append((url, zipfile.ZipFile(io.BytesIO(data))))
+
+
+class _SourceLoader(importlib.abc.Loader):
+    """Execute already-fetched (and verified) module source."""
+
+    def __init__(self, origin, source):
+        self._origin = origin
+        self._source = source
+
+    def create_module(self, spec):
+        return None
+
+    def exec_module(self, module):
+        code = compile(self._source, self._origin, "exec")
+        exec(code, module.__dict__)
+
+    def get_source(self, fullname):
+        return self._source
+
+
+class UrlFinder(importlib.abc.MetaPathFinder):
+    """Serves pinned module names; everything else is left to other finders."""
+
+    def find_spec(self, fullname, path=None, target=None):
+        pinned = _pins.get(fullname)
+        if pinned is not None:
+            url, sha256 = pinned
+            data = _fetch(url)
+            _verify(url, data, sha256)
+            loader = _SourceLoader(url, data.decode("utf-8"))
+            return importlib.util.spec_from_loader(fullname, loader,
+                                                   origin=url)
+
+        stem = fullname.replace(".", "/")
+        for url, archive in _bundles:
+            for arcname, is_package in ((stem + ".py", False),
+                                        (stem + "/__init__.py", True)):
+                try:
+                    source = archive.read(arcname).decode("utf-8")
+                except KeyError:
+                    continue
+                origin = "%s#%s" % (url, arcname)
+                loader = _SourceLoader(origin, source)
+                return importlib.util.spec_from_loader(
+                    fullname, loader, origin=origin, is_package=is_package)
+        return None
+
+
+_finder = UrlFinder()
+
+
+def install():
+    """Append the finder to sys.meta_path; imports stay untouched until the
+    first pin is added."""
+    if _finder not in sys.meta_path:
+        sys.meta_path.append(_finder)
+
+
+def uninstall():
+    try:
+        sys.meta_path.remove(_finder)
+    except ValueError:
+        pass
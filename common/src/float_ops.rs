@@ -206,7 +206,10 @@ pub fn ulp(x: f64) -> f64 {
 pub fn round_float_digits(x: f64, ndigits: i32) -> Option<f64> {
     let float = if ndigits.is_zero() {
         let fract = x.fract();
-        if (fract.abs() - 0.5).abs() < f64::EPSILON {
+        // only an exact half is a tie; values an ulp away round normally
+        #[allow(clippy::float_cmp)]
+        let is_tie = fract.abs() == 0.5;
+        if is_tie {
             if x.trunc() % 2.0 == 0.0 {
                 x - fract
             } else {
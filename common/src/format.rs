@@ -201,6 +201,77 @@ pub struct FormatSpec {
     format_type: Option<FormatType>,
 }
 
+/// Numeric formatting data for the current `LC_NUMERIC` locale, used by the
+/// `'n'` format type. The caller supplies it (e.g. from `localeconv`, which
+/// this crate stays free of); the default is the C locale, under which `'n'`
+/// formats exactly like `'d'`/`'g'`.
+#[derive(Debug, Clone)]
+pub struct NumericLocale {
+    pub decimal_point: String,
+    pub thousands_sep: String,
+    /// Group sizes, right to left; the last one repeats for the remaining
+    /// digits. `usize::MAX` leaves the remainder ungrouped, and an empty
+    /// list disables grouping entirely.
+    pub grouping: Vec<usize>,
+}
+
+impl Default for NumericLocale {
+    fn default() -> Self {
+        Self {
+            decimal_point: ".".to_owned(),
+            thousands_sep: String::new(),
+            grouping: Vec::new(),
+        }
+    }
+}
+
+impl NumericLocale {
+    /// Rewrite a C-locale rendering of a number into this locale: group the
+    /// integral digits and swap in the decimal point. Any exponent suffix or
+    /// `inf`/`nan` text is left alone.
+    fn localize(&self, magnitude_str: &str) -> String {
+        let (number, exponent) = match magnitude_str.find(['e', 'E']) {
+            Some(pos) => magnitude_str.split_at(pos),
+            None => (magnitude_str, ""),
+        };
+        let (int_part, frac_part) = match number.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (number, None),
+        };
+        let mut out = self.group_digits(int_part);
+        if let Some(frac_part) = frac_part {
+            out.push_str(&self.decimal_point);
+            out.push_str(frac_part);
+        }
+        out.push_str(exponent);
+        out
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        if self.thousands_sep.is_empty() || self.grouping.is_empty() {
+            return digits.to_owned();
+        }
+        let mut groups = Vec::new();
+        let mut rest = digits;
+        let mut sizes = self.grouping.iter().copied();
+        let mut size = sizes.next().unwrap();
+        while size != 0 && size != usize::MAX && rest.len() > size {
+            let (head, tail) = rest.split_at(rest.len() - size);
+            groups.push(tail);
+            rest = head;
+            if let Some(next) = sizes.next() {
+                size = next;
+            }
+        }
+        let mut out = rest.to_owned();
+        for group in groups.iter().rev() {
+            out.push_str(&self.thousands_sep);
+            out.push_str(group);
+        }
+        out
+    }
+}
+
 fn get_num_digits(text: &Wtf8) -> usize {
     for (index, character) in text.code_point_indices() {
         if !character.is_char_and(|c| c.is_ascii_digit()) {
@@ -446,7 +517,21 @@ impl FormatSpec {
         }
     }
 
+    /// Whether this spec uses the `'n'` format type and therefore wants a
+    /// [`NumericLocale`] snapshot from the caller.
+    pub fn is_locale_aware(&self) -> bool {
+        matches!(self.format_type, Some(FormatType::Number(_)))
+    }
+
     pub fn format_float(&self, num: f64) -> Result<String, FormatSpecError> {
+        self.format_float_locale(num, &NumericLocale::default())
+    }
+
+    pub fn format_float_locale(
+        &self,
+        num: f64,
+        locale: &NumericLocale,
+    ) -> Result<String, FormatSpecError> {
         self.validate_format(FormatType::FixedPoint(Case::Lower))?;
         let precision = self.precision.unwrap_or(6);
         let magnitude = num.abs();
@@ -518,7 +603,12 @@ impl FormatSpec {
             }
         };
         let magnitude_str = self.add_magnitude_separators(raw_magnitude_str?, sign_str);
-        self.format_sign_and_align(&AsciiStr::new(&magnitude_str), sign_str, FormatAlign::Right)
+        if self.is_locale_aware() {
+            let magnitude_str = locale.localize(&magnitude_str);
+            self.format_sign_and_align(&UnicodeStr::new(&magnitude_str), sign_str, FormatAlign::Right)
+        } else {
+            self.format_sign_and_align(&AsciiStr::new(&magnitude_str), sign_str, FormatAlign::Right)
+        }
     }
 
     #[inline]
@@ -530,6 +620,14 @@ impl FormatSpec {
     }
 
     pub fn format_int(&self, num: &BigInt) -> Result<String, FormatSpecError> {
+        self.format_int_locale(num, &NumericLocale::default())
+    }
+
+    pub fn format_int_locale(
+        &self,
+        num: &BigInt,
+        locale: &NumericLocale,
+    ) -> Result<String, FormatSpecError> {
         self.validate_format(FormatType::Decimal)?;
         let magnitude = num.abs();
         let prefix = if self.alternate_form {
@@ -573,7 +671,7 @@ impl FormatSpec {
             | Some(FormatType::FixedPoint(_))
             | Some(FormatType::Exponent(_))
             | Some(FormatType::Percentage) => match num.to_f64() {
-                Some(float) => return self.format_float(float),
+                Some(float) => return self.format_float_locale(float, locale),
                 _ => Err(FormatSpecError::UnableToConvert),
             },
             None => self.format_int_radix(magnitude, 10),
@@ -589,11 +687,20 @@ impl FormatSpec {
         };
         let sign_prefix = format!("{sign_str}{prefix}");
         let magnitude_str = self.add_magnitude_separators(raw_magnitude_str, &sign_prefix);
-        self.format_sign_and_align(
-            &AsciiStr::new(&magnitude_str),
-            &sign_prefix,
-            FormatAlign::Right,
-        )
+        if self.is_locale_aware() {
+            let magnitude_str = locale.localize(&magnitude_str);
+            self.format_sign_and_align(
+                &UnicodeStr::new(&magnitude_str),
+                &sign_prefix,
+                FormatAlign::Right,
+            )
+        } else {
+            self.format_sign_and_align(
+                &AsciiStr::new(&magnitude_str),
+                &sign_prefix,
+                FormatAlign::Right,
+            )
+        }
     }
 
     pub fn format_string<T>(&self, s: &T) -> Result<String, FormatSpecError>
@@ -695,6 +802,31 @@ impl Deref for AsciiStr<'_> {
     }
 }
 
+/// Like [`AsciiStr`] but counting real characters: localized separators and
+/// decimal points may be multi-byte.
+struct UnicodeStr<'a> {
+    inner: &'a str,
+}
+
+impl<'a> UnicodeStr<'a> {
+    fn new(inner: &'a str) -> Self {
+        Self { inner }
+    }
+}
+
+impl CharLen for UnicodeStr<'_> {
+    fn char_len(&self) -> usize {
+        self.inner.chars().count()
+    }
+}
+
+impl Deref for UnicodeStr<'_> {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum FormatSpecError {
     DecimalDigitsTooMany,
@@ -1178,6 +1310,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_number_locale() {
+        let locale = NumericLocale {
+            decimal_point: ",".to_owned(),
+            thousands_sep: ".".to_owned(),
+            grouping: vec![3],
+        };
+        assert_eq!(
+            FormatSpec::parse("n")
+                .unwrap()
+                .format_int_locale(&BigInt::from(1234567), &locale),
+            Ok("1.234.567".to_owned())
+        );
+        assert_eq!(
+            FormatSpec::parse("n")
+                .unwrap()
+                .format_float_locale(1234.5, &locale),
+            Ok("1.234,5".to_owned())
+        );
+        // the exponent of a scientific rendering is left alone
+        assert_eq!(
+            FormatSpec::parse(".2n")
+                .unwrap()
+                .format_float_locale(1234.5, &locale),
+            Ok("1,2e+03".to_owned())
+        );
+        // the C locale leaves 'n' identical to 'd'
+        assert_eq!(
+            FormatSpec::parse("n").unwrap().format_int(&BigInt::from(1234567)),
+            Ok("1234567".to_owned())
+        );
+    }
+
     #[test]
     fn test_format_parse() {
         let expected = Ok(FormatString {
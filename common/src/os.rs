@@ -1,8 +1,52 @@
 // cspell:disable
 // TODO: we can move more os-specific bindings/interfaces from stdlib::{os, posix, nt} to here
 
+use crate::wtf8::{CodePoint, Wtf8Buf};
+use std::ffi::OsString;
 use std::{io, str::Utf8Error};
 
+/// Decode an `OsString` the way CPython decodes command line arguments and
+/// other OS data: any byte sequence that is not valid UTF-8 is mapped to lone
+/// surrogates per PEP 383 (surrogateescape), so it round-trips through
+/// `fsencode` instead of being rejected.
+pub fn fs_decode_wtf8(s: impl Into<OsString>) -> Wtf8Buf {
+    let s = s.into();
+    #[cfg(windows)]
+    {
+        // on windows the OS encoding is already (potentially ill-formed)
+        // UTF-16, which converts to WTF-8 losslessly
+        use std::os::windows::ffi::OsStrExt;
+        let wide: Vec<u16> = s.as_os_str().encode_wide().collect();
+        Wtf8Buf::from_wide(&wide)
+    }
+    #[cfg(not(windows))]
+    {
+        let bytes = s.into_encoded_bytes();
+        let mut out = Wtf8Buf::with_capacity(bytes.len());
+        let mut rest = bytes.as_slice();
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    out.push_str(valid);
+                    break;
+                }
+                Err(e) => {
+                    let (valid, after) = rest.split_at(e.valid_up_to());
+                    // SAFETY: from_utf8 validated everything up to valid_up_to
+                    out.push_str(unsafe { std::str::from_utf8_unchecked(valid) });
+                    let invalid_len = e.error_len().unwrap_or(after.len());
+                    for &byte in &after[..invalid_len] {
+                        // surrogateescape: 0x80..=0xff -> U+DC80..=U+DCFF
+                        out.push(CodePoint::from_u32(0xDC00 + byte as u32).unwrap());
+                    }
+                    rest = &after[invalid_len..];
+                }
+            }
+        }
+        out
+    }
+}
+
 pub trait ErrorExt {
     fn posix_errno(&self) -> i32;
 }
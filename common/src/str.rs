@@ -318,7 +318,16 @@ impl StrData {
     pub fn nth_char(&self, index: usize) -> CodePoint {
         match self.as_str_kind() {
             PyKindStr::Ascii(s) => s[index].into(),
-            PyKindStr::Utf8(s) => s.chars().nth(index).unwrap().into(),
+            PyKindStr::Utf8(s) => {
+                // when the char length is already cached, walk in from
+                // whichever end is closer, halving the worst case
+                let len = self.len.0.load(Relaxed);
+                if len != usize::MAX && index >= len / 2 {
+                    s.chars().nth_back(len - index - 1).unwrap().into()
+                } else {
+                    s.chars().nth(index).unwrap().into()
+                }
+            }
             PyKindStr::Wtf8(w) => w.code_points().nth(index).unwrap(),
         }
     }
@@ -671,4 +680,14 @@ mod tests {
         let s = "0😀😃😄😁😆😅😂🤣9";
         assert_eq!(get_chars(s, 3..7), "😄😁😆😅");
     }
+
+    #[test]
+    fn test_nth_char() {
+        let s = StrData::from(Box::<str>::from("0유니코드 문자열9"));
+        assert_eq!(s.nth_char(1), '유'.into());
+        // populate the cached length so indexing can start from the back
+        assert_eq!(s.char_len(), 10);
+        assert_eq!(s.nth_char(6), '문'.into());
+        assert_eq!(s.nth_char(9), '9'.into());
+    }
 }
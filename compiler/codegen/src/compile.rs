@@ -93,6 +93,9 @@ pub struct CompileOpts {
     /// How optimized the bytecode output should be; any optimize > 0 does
     /// not emit assert statements
     pub optimize: u8,
+    /// Permit `await` (PyCF_ALLOW_TOP_LEVEL_AWAIT) at module level, turning
+    /// the module code object into a coroutine
+    pub allow_top_level_await: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -100,6 +103,9 @@ struct CompileContext {
     loop_data: Option<(ir::BlockIdx, ir::BlockIdx)>,
     in_class: bool,
     func: FunctionContext,
+    /// Inside the body of an `except*` handler (and not in a nested function
+    /// scope); `return`/`break`/`continue` are forbidden there
+    in_except_star_block: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -331,6 +337,7 @@ impl<'src> Compiler<'src> {
                 loop_data: None,
                 in_class: false,
                 func: FunctionContext::NoFunction,
+                in_except_star_block: false,
             },
             class_name: None,
             opts,
@@ -953,6 +960,16 @@ impl Compiler<'_> {
                 Some((_, end)) => {
                     emit!(self, Instruction::Break { target: end });
                 }
+                // a loop opened inside the handler hides the flag again, so
+                // this only rejects breaks that would escape the except*
+                None if self.ctx.in_except_star_block => {
+                    return Err(self.error_ranged(
+                        CodegenErrorType::SyntaxError(
+                            "'break' cannot appear in except* block".to_owned(),
+                        ),
+                        statement.range(),
+                    ));
+                }
                 None => {
                     return Err(
                         self.error_ranged(CodegenErrorType::InvalidBreak, statement.range())
@@ -963,6 +980,14 @@ impl Compiler<'_> {
                 Some((start, _)) => {
                     emit!(self, Instruction::Continue { target: start });
                 }
+                None if self.ctx.in_except_star_block => {
+                    return Err(self.error_ranged(
+                        CodegenErrorType::SyntaxError(
+                            "'continue' cannot appear in except* block".to_owned(),
+                        ),
+                        statement.range(),
+                    ));
+                }
                 None => {
                     return Err(
                         self.error_ranged(CodegenErrorType::InvalidContinue, statement.range())
@@ -975,6 +1000,14 @@ impl Compiler<'_> {
                         self.error_ranged(CodegenErrorType::InvalidReturn, statement.range())
                     );
                 }
+                if self.ctx.in_except_star_block {
+                    return Err(self.error_ranged(
+                        CodegenErrorType::SyntaxError(
+                            "'return' cannot appear in except* block".to_owned(),
+                        ),
+                        statement.range(),
+                    ));
+                }
                 match value {
                     Some(v) => {
                         if self.ctx.func == FunctionContext::AsyncFunction
@@ -1367,12 +1400,170 @@ impl Compiler<'_> {
 
     fn compile_try_star_statement(
         &mut self,
-        _body: &[Stmt],
-        _handlers: &[ExceptHandler],
-        _orelse: &[Stmt],
-        _finalbody: &[Stmt],
+        body: &[Stmt],
+        handlers: &[ExceptHandler],
+        orelse: &[Stmt],
+        finalbody: &[Stmt],
     ) -> CompileResult<()> {
-        Err(self.error(CodegenErrorType::NotImplementedYet))
+        let handler_block = self.new_block();
+        let finally_block = self.new_block();
+
+        // Setup a finally block if we have a finally statement.
+        if !finalbody.is_empty() {
+            emit!(
+                self,
+                Instruction::SetupFinally {
+                    handler: finally_block,
+                }
+            );
+        }
+
+        let else_block = self.new_block();
+
+        // try:
+        emit!(
+            self,
+            Instruction::SetupExcept {
+                handler: handler_block,
+            }
+        );
+        self.compile_statements(body)?;
+        emit!(self, Instruction::PopBlock);
+        emit!(self, Instruction::Jump { target: else_block });
+
+        // except* handlers. The stack invariant between clauses is
+        // [handler_raised_list, unhandled_rest]: exceptions raised inside a
+        // handler body are collected in the list instead of propagating, and
+        // each clause splits off the subgroup it matches from the rest.
+        self.switch_to_block(handler_block);
+        // Exception is on top of stack now
+        emit!(self, Instruction::BuildList { size: 0 });
+        emit!(self, Instruction::Rotate2);
+
+        for handler in handlers {
+            let ExceptHandler::ExceptHandler(ExceptHandlerExceptHandler {
+                type_, name, body, ..
+            }) = &handler;
+            let next_handler = self.new_block();
+            let handled = self.new_block();
+
+            // the grammar guarantees a type for every except* clause
+            let exc_type = type_.as_deref().ok_or_else(|| {
+                self.error(CodegenErrorType::SyntaxError(
+                    "expected an exception type for except*".to_owned(),
+                ))
+            })?;
+
+            // Split off the part of the exception this clause matches:
+            self.compile_expression(exc_type)?;
+            emit!(self, Instruction::CheckEgMatch);
+
+            // No match: clean the None match off and try the next clause.
+            emit!(self, Instruction::Duplicate);
+            self.emit_load_const(ConstantData::None);
+            emit!(
+                self,
+                Instruction::TestOperation {
+                    op: bytecode::TestOperator::Is,
+                }
+            );
+            emit!(self, Instruction::JumpIfFalse { target: handled });
+            emit!(self, Instruction::Pop);
+            emit!(
+                self,
+                Instruction::Jump {
+                    target: next_handler,
+                }
+            );
+
+            // We have a match, store in name (except* x as y)
+            self.switch_to_block(handled);
+            if let Some(alias) = name {
+                self.store_name(alias.as_str())?
+            } else {
+                // Drop match from top of stack:
+                emit!(self, Instruction::Pop);
+            }
+
+            // Handler code; anything it raises is recorded for the final
+            // reraise rather than propagated immediately:
+            let reraise_block = self.new_block();
+            emit!(
+                self,
+                Instruction::SetupExcept {
+                    handler: reraise_block,
+                }
+            );
+            // return/break/continue would unwind past PrepReraiseStar and
+            // silently drop the unhandled rest of the group; loop_data is
+            // cleared so a break can't target a loop around the try* either
+            let prev_ctx = self.ctx;
+            self.ctx.loop_data = None;
+            self.ctx.in_except_star_block = true;
+            let body_result = self.compile_statements(body);
+            self.ctx = prev_ctx;
+            body_result?;
+            emit!(self, Instruction::PopBlock);
+            emit!(
+                self,
+                Instruction::Jump {
+                    target: next_handler,
+                }
+            );
+
+            self.switch_to_block(reraise_block);
+            // Raised exception is on top of stack, above [list, rest]:
+            emit!(self, Instruction::ListAppend { i: 1 });
+            emit!(self, Instruction::PopException);
+            emit!(
+                self,
+                Instruction::Jump {
+                    target: next_handler,
+                }
+            );
+
+            // Emit a new label for the next handler
+            self.switch_to_block(next_handler);
+        }
+
+        // Reraise whatever was not handled, merged with anything the
+        // handlers raised; falls through when everything was consumed.
+        emit!(self, Instruction::PrepReraiseStar);
+        emit!(self, Instruction::PopException);
+
+        if !finalbody.is_empty() {
+            emit!(self, Instruction::PopBlock); // pop finally block
+            // We enter the finally block, without exception.
+            emit!(self, Instruction::EnterFinally);
+        }
+
+        emit!(
+            self,
+            Instruction::Jump {
+                target: finally_block,
+            }
+        );
+
+        // We successfully ran the try block:
+        // else:
+        self.switch_to_block(else_block);
+        self.compile_statements(orelse)?;
+
+        if !finalbody.is_empty() {
+            emit!(self, Instruction::PopBlock); // pop finally block
+
+            // We enter the finallyhandler block, without return / exception.
+            emit!(self, Instruction::EnterFinally);
+        }
+
+        // finally:
+        self.switch_to_block(finally_block);
+        if !finalbody.is_empty() {
+            self.compile_statements(finalbody)?;
+            emit!(self, Instruction::EndFinally);
+        }
+
+        Ok(())
     }
 
     fn is_forbidden_arg_name(name: &str) -> bool {
@@ -1408,6 +1599,7 @@ impl Compiler<'_> {
         self.ctx = CompileContext {
             loop_data: None,
             in_class: prev_ctx.in_class,
+            in_except_star_block: false,
             func: if is_async {
                 FunctionContext::AsyncFunction
             } else {
@@ -1611,6 +1803,7 @@ impl Compiler<'_> {
             func: FunctionContext::NoFunction,
             in_class: true,
             loop_data: None,
+            in_except_star_block: false,
         };
 
         let prev_class_name = self.class_name.replace(name.to_owned());
@@ -2865,6 +3058,11 @@ impl Compiler<'_> {
                     .to_string()
                     .into(),
             });
+        } else if let Expr::Starred(ExprStarred { value, .. }) = annotation {
+            // PEP 646: `*args: *Ts` is equivalent to `[annotation] = [*Ts]`;
+            // a TypeVarTuple unpacks to a single Unpack alias
+            self.compile_expression(value)?;
+            emit!(self, Instruction::UnpackSequence { size: 1 });
         } else {
             self.compile_expression(annotation)?;
         }
@@ -3319,8 +3517,16 @@ impl Compiler<'_> {
                 emit!(self, Instruction::YieldValue);
             }
             Expr::Await(ExprAwait { value, .. }) => {
-                if self.ctx.func != FunctionContext::AsyncFunction {
-                    return Err(self.error(CodegenErrorType::InvalidAwait));
+                match self.ctx.func {
+                    FunctionContext::AsyncFunction => {}
+                    FunctionContext::NoFunction
+                        if self.opts.allow_top_level_await && !self.ctx.in_class =>
+                    {
+                        // with PyCF_ALLOW_TOP_LEVEL_AWAIT the module itself
+                        // becomes a coroutine for the caller to drive
+                        self.current_code_info().flags |= bytecode::CodeFlags::IS_COROUTINE;
+                    }
+                    _ => return Err(self.error(CodegenErrorType::InvalidAwait)),
                 }
                 self.compile_expression(value)?;
                 emit!(self, Instruction::GetAwaitable);
@@ -3357,6 +3563,7 @@ impl Compiler<'_> {
                     loop_data: Option::None,
                     in_class: prev_ctx.in_class,
                     func: FunctionContext::Function,
+                    in_except_star_block: false,
                 };
 
                 self.current_code_info()
@@ -3793,6 +4000,7 @@ impl Compiler<'_> {
             } else {
                 FunctionContext::Function
             },
+            in_except_star_block: false,
         };
 
         // We must have at least one generator:
@@ -623,10 +623,18 @@ pub enum Instruction {
     TypeAlias,
     TypeVarTuple,
     ParamSpec,
+    /// For `except*`: pop a type (or tuple of types) and an exception (or
+    /// None), and push the non-matching rest and the matching subgroup
+    /// (either may be None).
+    CheckEgMatch,
+    /// For `except*`: pop the leftover unhandled exception (or None) and the
+    /// list of exceptions raised by the handlers, and reraise them as a
+    /// group if there are any.
+    PrepReraiseStar,
     // If you add a new instruction here, be sure to keep LAST_INSTRUCTION updated
 }
 // This must be kept up to date to avoid marshaling errors
-const LAST_INSTRUCTION: Instruction = Instruction::ParamSpec;
+const LAST_INSTRUCTION: Instruction = Instruction::PrepReraiseStar;
 const _: () = assert!(mem::size_of::<Instruction>() == 1);
 
 impl From<Instruction> for u8 {
@@ -1331,6 +1339,8 @@ impl Instruction {
             TypeAlias => -2,
             ParamSpec => 0,
             TypeVarTuple => 0,
+            CheckEgMatch => 0,
+            PrepReraiseStar => -2,
         }
     }
 
@@ -1514,6 +1524,8 @@ impl Instruction {
             TypeAlias => w!(TypeAlias),
             ParamSpec => w!(ParamSpec),
             TypeVarTuple => w!(TypeVarTuple),
+            CheckEgMatch => w!(CheckEgMatch),
+            PrepReraiseStar => w!(PrepReraiseStar),
         }
     }
 }
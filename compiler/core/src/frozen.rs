@@ -1,5 +1,6 @@
 use crate::bytecode::*;
 use crate::marshal::{self, Read, ReadBorrowed, Write};
+use std::collections::HashMap;
 
 /// A frozen module. Holds a frozen code object and whether it is part of a package
 #[derive(Copy, Clone)]
@@ -36,6 +37,63 @@ impl FrozenCodeObject<Vec<u8>> {
     }
 }
 
+/// Rewrite `code` so that identical constants share a single slot in each
+/// constant table, recursing into nested code objects first so that equal
+/// comprehension/lambda code constants also collapse. Run before
+/// [`FrozenCodeObject::encode`] to shrink the serialized form; at runtime the
+/// deduplicated slots then also decode to a single object.
+///
+/// `LoadConst` is the only instruction indexing the constant table, and the
+/// canonical index is never larger than the one it replaces, so arguments can
+/// be rewritten in place (zero-padded through any leading `ExtendedArg`
+/// units) without resizing the instruction stream.
+pub fn dedup_constants(code: &mut CodeObject<ConstantData>) {
+    for constant in &mut *code.constants {
+        if let ConstantData::Code { code } = constant {
+            dedup_constants(code);
+        }
+    }
+
+    // Key by the marshaled bytes: structural equality that distinguishes
+    // float bit patterns (0.0 vs -0.0, NaN) exactly like the serialized form.
+    let mut canonical = HashMap::new();
+    let mut unique = Vec::new();
+    let mut remap = Vec::with_capacity(code.constants.len());
+    for constant in &*code.constants {
+        let mut key = Vec::new();
+        marshal::serialize_value(&mut key, constant.borrow_constant().into())
+            .unwrap_or_else(|x| match x {});
+        let next = unique.len() as u32;
+        let idx = *canonical.entry(key).or_insert_with(|| {
+            unique.push(constant.clone());
+            next
+        });
+        remap.push(idx);
+    }
+    if unique.len() == code.constants.len() {
+        return;
+    }
+    code.constants = unique.into();
+
+    let mut op_arg_state = OpArgState::default();
+    let mut instr_start = 0;
+    for i in 0..code.instructions.len() {
+        let (op, arg) = op_arg_state.get(code.instructions[i]);
+        if let Instruction::ExtendedArg = op {
+            continue;
+        }
+        if let Instruction::LoadConst { idx } = op {
+            let new_idx = remap[idx.get(arg) as usize];
+            let bytes = new_idx.to_le_bytes();
+            for (byte, unit) in bytes.iter().zip(code.instructions[instr_start..=i].iter_mut().rev())
+            {
+                unit.arg = OpArgByte(*byte);
+            }
+        }
+        instr_start = i + 1;
+    }
+}
+
 #[repr(transparent)]
 pub struct FrozenLib<B: ?Sized = [u8]> {
     pub bytes: B,
@@ -19,6 +19,10 @@ pub enum MarshalError {
     InvalidLocation,
     /// Bad type marker
     BadType,
+    /// Data without the [`CODE_MAGIC`] envelope tag
+    BadMagic,
+    /// A versioned envelope written by a different [`FORMAT_VERSION`]
+    IncompatibleVersion(u32),
 }
 
 impl std::fmt::Display for MarshalError {
@@ -29,6 +33,11 @@ impl std::fmt::Display for MarshalError {
             Self::InvalidUtf8 => f.write_str("invalid utf8"),
             Self::InvalidLocation => f.write_str("invalid source location"),
             Self::BadType => f.write_str("bad type marker"),
+            Self::BadMagic => f.write_str("not a marshaled code object"),
+            Self::IncompatibleVersion(found) => write!(
+                f,
+                "incompatible marshal format version {found}, expected {FORMAT_VERSION}"
+            ),
         }
     }
 }
@@ -633,3 +642,38 @@ pub fn serialize_code<W: Write, C: Constant>(buf: &mut W, code: &CodeObject<C>)
     write_names(&code.cellvars);
     write_names(&code.freevars);
 }
+
+/// Magic tag opening the envelope written by [`serialize_code_versioned`].
+pub const CODE_MAGIC: &[u8; 4] = b"RPYC";
+
+/// Serialize `code` into a self-describing envelope: [`CODE_MAGIC`], the
+/// [`FORMAT_VERSION`] it was written with, then the marshaled code object.
+///
+/// This is the stable entry point for external bytecode caches and for
+/// shipping precompiled code between processes: unlike raw
+/// [`serialize_code`], a reader can cheaply reject data written by a
+/// different RustPython version instead of misinterpreting it.
+pub fn serialize_code_versioned<C: Constant>(code: &CodeObject<C>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.write_slice(CODE_MAGIC);
+    buf.write_u32(FORMAT_VERSION);
+    serialize_code(&mut buf, code);
+    buf
+}
+
+/// Deserialize an envelope written by [`serialize_code_versioned`], verifying
+/// its magic tag and format version first.
+pub fn deserialize_code_versioned<Bag: ConstantBag>(
+    mut data: &[u8],
+    bag: Bag,
+) -> Result<CodeObject<Bag::Constant>> {
+    let magic = data.read_slice(CODE_MAGIC.len() as u32)?;
+    if magic != CODE_MAGIC {
+        return Err(MarshalError::BadMagic);
+    }
+    let version = data.read_u32()?;
+    if version != FORMAT_VERSION {
+        return Err(MarshalError::IncompatibleVersion(version));
+    }
+    deserialize_code(&mut data, bag)
+}
@@ -341,9 +341,10 @@ pub fn impl_py_compile(
     let args = PyCompileArgs::parse(input, false)?;
 
     let crate_name = args.crate_name;
-    let code = args
+    let mut code = args
         .source
         .compile_single(args.mode, args.module_name, compiler)?;
+    frozen::dedup_constants(&mut code);
 
     let frozen = frozen::FrozenCodeObject::encode(&code);
     let bytes = LitByteStr::new(&frozen.bytes, Span::call_site());
@@ -362,7 +363,10 @@ pub fn impl_py_freeze(
     let args = PyCompileArgs::parse(input, true)?;
 
     let crate_name = args.crate_name;
-    let code_map = args.source.compile(args.mode, args.module_name, compiler)?;
+    let mut code_map = args.source.compile(args.mode, args.module_name, compiler)?;
+    for module in code_map.values_mut() {
+        frozen::dedup_constants(&mut module.code);
+    }
 
     let data = frozen::FrozenLib::encode(code_map.iter().map(|(k, v)| {
         let v = frozen::FrozenModule {
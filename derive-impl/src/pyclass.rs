@@ -752,7 +752,12 @@ where
         let raw = item_meta.raw()?;
         let sig_doc = text_signature(func.sig(), &py_name);
 
-        let doc = args.attrs.doc().map(|doc| format_doc(&sig_doc, &doc));
+        // keep the signature header even without a doc string; the __doc__
+        // getters strip it again, but __text_signature__ needs it
+        let doc = Some(format_doc(
+            &sig_doc,
+            args.attrs.doc().as_deref().unwrap_or_default(),
+        ));
         args.context.method_items.add_item(MethodNurseryItem {
             py_name,
             cfgs: args.cfgs.to_vec(),
@@ -462,11 +462,9 @@ impl ModuleItem for FunctionItem {
                 .flatten()
                 .map(str::to_owned)
         });
-        let doc = if let Some(doc) = doc {
-            format_doc(&sig_doc, &doc)
-        } else {
-            sig_doc
-        };
+        // always embed the signature header, even without a doc string, so
+        // __text_signature__ (and therefore inspect.signature) keeps working
+        let doc = format_doc(&sig_doc, doc.as_deref().unwrap_or_default());
 
         let py_names = {
             if self.py_attrs.is_empty() {
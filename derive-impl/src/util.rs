@@ -734,7 +734,17 @@ fn func_sig(sig: &Signature) -> String {
             if ident == "vm" {
                 unreachable!("type &VirtualMachine(`{}`) must be filtered already", ty);
             }
-            Some(ident)
+            // render the extractor types whose shape is visible from Python
+            let arg = if ty.starts_with("OptionalArg") || ty.starts_with("OptionalOption") {
+                format!("{ident}=None")
+            } else if ty.starts_with("PosArgs") {
+                format!("*{ident}")
+            } else if ty.starts_with("KwArgs") {
+                format!("**{ident}")
+            } else {
+                ident
+            };
+            Some(arg)
         })
         .collect::<Vec<_>>()
         .join(", ")
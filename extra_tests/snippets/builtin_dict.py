@@ -149,6 +149,26 @@ del d["a"]
 with assert_raises(RuntimeError):
     next(b)
 
+# A mutation that keeps the size the same (remove one key, insert another)
+# is detected too.
+d = {"a": 1, "b": 2}
+it = iter(d)
+assert next(it) == "a"
+d.popitem()
+d["c"] = 3
+assert len(d) == 2
+with assert_raises(RuntimeError):
+    next(it)
+
+# Replacing the value of an existing key is not a mutation of the keys, so
+# iteration carries on.
+d = {"a": 1, "b": 2}
+it = iter(d)
+assert next(it) == "a"
+d["a"] = 100
+d["b"] = 200
+assert next(it) == "b"
+
 # View isn't itself an iterator.
 with assert_raises(TypeError):
     next(data.keys())
@@ -433,3 +433,14 @@ class FS(frozenset):
 
 assert repr(FS()) == "FS()"
 assert repr(FS([1, 2, 3])) == "FS({1, 2, 3})"
+
+# Mutating a set during iteration is detected, even when a removal and an
+# insertion leave the size unchanged.
+s = {1, 2, 3}
+it = iter(s)
+next(it)
+s.pop()
+s.add(99)
+assert len(s) == 3
+with assert_raises(RuntimeError):
+    next(it)
@@ -0,0 +1,36 @@
+import codeop
+import code
+
+# complete statements compile to a code object
+assert codeop.compile_command("x = 1") is not None
+assert codeop.compile_command("print(1)\n") is not None
+assert codeop.compile_command("1+2", symbol="eval") is not None
+
+# incomplete input yields None so a REPL keeps reading
+assert codeop.compile_command("def f():") is None
+assert codeop.compile_command("if True:\n    x = 1") is None
+assert codeop.compile_command("'''unterminated") is None
+assert codeop.compile_command("(1, 2,") is None
+
+# blank/comment-only input is treated as a no-op statement
+assert codeop.compile_command("") is not None
+assert codeop.compile_command("# just a comment") is not None
+
+# outright syntax errors still raise
+try:
+    codeop.compile_command("def f(:")
+except SyntaxError:
+    pass
+else:
+    assert False, "expected SyntaxError"
+
+# InteractiveInterpreter.runsource mirrors compile_command's three outcomes
+interp = code.InteractiveInterpreter()
+assert interp.runsource("a = 40 + 2") is False
+assert interp.locals["a"] == 42
+assert interp.runsource("def g():") is True  # more input required
+
+# the stateful CommandCompiler behaves the same way
+cc = codeop.CommandCompiler()
+assert cc("x = 1") is not None
+assert cc("while True:") is None
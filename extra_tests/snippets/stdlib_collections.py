@@ -57,3 +57,39 @@ b = BadRepr()
 d = deque([1, b, 2])
 b.d = d
 repr(d)
+
+# OrderedDict keeps its class through copy, |, and pickling
+from collections import OrderedDict
+import copy as copy_mod
+import pickle
+
+od = OrderedDict([("a", 1), ("b", 2)])
+c = od.copy()
+assert type(c) is OrderedDict
+assert list(c.items()) == [("a", 1), ("b", 2)]
+c.move_to_end("a")
+assert list(c) == ["b", "a"]
+assert list(od) == ["a", "b"]
+
+u = od | {"c": 3}
+assert type(u) is OrderedDict
+assert list(u.items()) == [("a", 1), ("b", 2), ("c", 3)]
+
+u = {"c": 3} | od
+assert type(u) is OrderedDict
+assert list(u.items()) == [("c", 3), ("a", 1), ("b", 2)]
+
+try:
+    od | [("c", 3)]
+except TypeError:
+    pass
+else:
+    assert False, "OrderedDict | non-dict should raise TypeError"
+
+p = pickle.loads(pickle.dumps(od))
+assert type(p) is OrderedDict
+assert list(p.items()) == [("a", 1), ("b", 2)]
+
+d = copy_mod.deepcopy(od)
+assert type(d) is OrderedDict
+assert list(d.items()) == [("a", 1), ("b", 2)]
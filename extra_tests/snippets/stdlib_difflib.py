@@ -0,0 +1,49 @@
+# The _difflib native inner loop must produce the same matches as the
+# pure-Python find_longest_match it replaces.
+
+import difflib
+
+
+def pure(fn, *args, **kw):
+    orig = difflib._find_longest_match
+    difflib._find_longest_match = None
+    try:
+        return fn(*args, **kw)
+    finally:
+        difflib._find_longest_match = orig
+
+
+cases = [
+    ("", ""),
+    ("abcd", ""),
+    ("abcd", "bcde"),
+    ("qabxcd", "abycdf"),
+    ("private Thread currentThread;", "private volatile Thread currentThread;"),
+    (list("aabbccdd"), list("bbccddee")),
+    (["one", "two", "three", "two"], ["two", "three", "four", "two"]),
+    # long inputs with one popular element exercise the autojunk-pruned b2j
+    ("x" * 250 + "ay", "x" * 250 + "az"),
+]
+for a, b in cases:
+    def run(a=a, b=b):
+        sm = difflib.SequenceMatcher(None, a, b)
+        return sm.get_matching_blocks(), sm.get_opcodes(), sm.ratio()
+
+    native = run()
+    fallback = pure(run)
+    assert native == fallback, (a, b, native, fallback)
+
+# explicit sub-ranges and a junk predicate go through the same loop
+def run_flm():
+    sm = difflib.SequenceMatcher(
+        lambda c: c == " ", "private Thread currentThread", "private volatile Thread current"
+    )
+    return sm.find_longest_match(2, 26, 0, 28)
+
+
+assert run_flm() == pure(run_flm)
+
+# sanity-check a known answer on top of the parity run
+sm = difflib.SequenceMatcher(None, "qabxcd", "abycdf")
+assert sm.find_longest_match(0, 6, 0, 6) == difflib.Match(1, 0, 2)
+assert difflib.SequenceMatcher(None, "abcd", "bcde").ratio() == 0.75
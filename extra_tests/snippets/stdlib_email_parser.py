@@ -0,0 +1,49 @@
+# The _feedparser native header-line check must classify lines exactly like
+# email.feedparser.headerRE.
+
+import email
+import email.feedparser
+
+
+def pure(fn, *args):
+    orig = email.feedparser._is_header_line
+    email.feedparser._is_header_line = email.feedparser.headerRE.match
+    try:
+        return fn(*args)
+    finally:
+        email.feedparser._is_header_line = orig
+
+
+def parse(text):
+    msg = email.message_from_string(text)
+    return msg.items(), msg.get_payload(), [type(d).__name__ for d in msg.defects]
+
+
+messages = [
+    "From: a@example.com\nTo: b@example.com\n\nbody\n",
+    # continuation lines extend the previous header
+    "Subject: part one\n part two\n\tpart three\n\nbody\n",
+    # unix mbox From line
+    "From sender Sat Jan  1 00:00:00 2000\nFrom: a@example.com\n\nbody\n",
+    # an empty field name is still a header per RFC 2822 ftext
+    ":empty name\nFrom: a@example.com\n\nbody\n",
+    # missing separator: the non-header line ends the header block
+    "From: a@example.com\nnot a header line\nbody\n",
+    # colon inside the value, odd but legal field-name characters
+    "X-Odd_!#$%&'*+-^: value: with colon\n\nbody\n",
+    # a space before the colon makes it a (continuation) non-name
+    " Leading: space\n\nbody\n",
+    # non-ASCII in the field name is not a header
+    "Fröm: nope\n\nbody\n",
+    "\nonly a body\n",
+    "",
+]
+for text in messages:
+    native = parse(text)
+    fallback = pure(parse, text)
+    assert native == fallback, (text, native, fallback)
+
+msg = email.message_from_string("Subject: one\n two\n\nbody\n")
+assert msg["Subject"] == "one\n two"
+msg = email.message_from_string("From: a@example.com\nnot a header\n")
+assert [type(d).__name__ for d in msg.defects] == ["MissingHeaderBodySeparatorDefect"]
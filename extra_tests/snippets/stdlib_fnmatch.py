@@ -0,0 +1,77 @@
+# The _fnmatch native fast path must be indistinguishable from the
+# pure-Python regex implementation it replaces.
+
+import fnmatch
+
+
+def pure(func, *args):
+    orig = fnmatch._fnmatchcase, fnmatch._filter
+    fnmatch._fnmatchcase = fnmatch._filter = None
+    try:
+        return func(*args)
+    finally:
+        fnmatch._fnmatchcase, fnmatch._filter = orig
+
+
+def check_case(name, pat):
+    native = fnmatch.fnmatchcase(name, pat)
+    fallback = pure(fnmatch.fnmatchcase, name, pat)
+    assert native == fallback, (name, pat, native, fallback)
+    return native
+
+
+patterns = [
+    "*",
+    "?",
+    "a*c",
+    "a?c",
+    "*.py",
+    # character classes
+    "[abc]",
+    "[!abc]",
+    "[a-c]x",
+    "[a-c-e]",
+    "[]a]",
+    "[!]a]",
+    "[[]",
+    "[*]",
+    "[?]",
+    # degenerate / unclosed brackets fall back to literal matching
+    "[",
+    "a[",
+    "[!",
+    "[b",
+    "a[b*c",
+    "[a-",
+    # reversed range matches nothing
+    "[z-a]",
+    "[--0]",
+]
+names = [
+    "a", "b", "d", "abc", "aXc", "ac", "x.py", "]", "a]", "[", "a[", "a[bxc",
+    "[!", "[b", "[a-", ".", "-", "/", "a/c", "*", "?", "", "ax", "bx", "cx",
+]
+for pat in patterns:
+    for name in names:
+        check_case(name, pat)
+
+# spot-check a few known answers on top of the parity run
+assert check_case("abc", "a*c")
+assert check_case("aXc", "a?c")
+assert check_case("b", "[abc]")
+assert not check_case("d", "[abc]")
+assert check_case("d", "[!abc]")
+assert check_case("]", "[]a]")
+assert check_case("ax", "[a-c]x")
+assert not check_case("dx", "[a-c]x")
+assert check_case("[", "[")
+assert check_case("a[bxc", "a[b*c")
+assert not check_case("z", "[z-a]")
+
+# filter() goes through the same native matcher
+got = fnmatch.filter(names, "[!abc]")
+assert got == pure(fnmatch.filter, names, "[!abc]")
+assert fnmatch.filter(["x.py", "y.pyc", "z.txt"], "*.py") == ["x.py"]
+
+# fnmatch() normcases both sides before delegating
+assert fnmatch.fnmatch("ABC", "a*c") == pure(fnmatch.fnmatch, "ABC", "a*c")
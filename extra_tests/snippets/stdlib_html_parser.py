@@ -0,0 +1,83 @@
+# The _html_parser native scanner must hand the parser the same token
+# boundaries as the interesting_normal / CDATA regexes.
+
+import html.parser
+
+
+class Recorder(html.parser.HTMLParser):
+    def __init__(self, *, convert_charrefs=True):
+        super().__init__(convert_charrefs=convert_charrefs)
+        self.events = []
+
+    def handle_starttag(self, tag, attrs):
+        self.events.append(("start", tag, attrs))
+
+    def handle_endtag(self, tag):
+        self.events.append(("end", tag))
+
+    def handle_data(self, data):
+        self.events.append(("data", data))
+
+    def handle_comment(self, data):
+        self.events.append(("comment", data))
+
+    def handle_entityref(self, name):
+        self.events.append(("entityref", name))
+
+    def handle_charref(self, name):
+        self.events.append(("charref", name))
+
+
+def run(document, chunk=None, **kw):
+    parser = Recorder(**kw)
+    if chunk is None:
+        parser.feed(document)
+    else:
+        for i in range(0, len(document), chunk):
+            parser.feed(document[i : i + chunk])
+    parser.close()
+    return parser.events
+
+
+def pure(fn, *args, **kw):
+    orig = html.parser._find_interesting
+    html.parser._find_interesting = None
+    try:
+        return fn(*args, **kw)
+    finally:
+        html.parser._find_interesting = orig
+
+
+documents = [
+    "<p>hello &amp; goodbye</p>",
+    "plain text, no markup",
+    "a &lt; b &#60; c &unknown; d",
+    "text <b>bold</b> trailing",
+    # CDATA content elements: the closing tag scan must skip false endings
+    "<script>if (a < b && c) { x = '</scr'; }</script>after",
+    "<script>var s = \"</ script>\";</script>",
+    "<SCRIPT>x</SCRIPT>ok",
+    "<script>x</script  >ok",
+    "<style>p < q { color: red }</style>",
+    # an unterminated cdata element swallows the rest
+    "<script>never closed",
+    # non-ASCII input falls back to the regexes; results must still match
+    "<p>café &amp; naïve</p>",
+    "<script>unicode   inside</script>done",
+    "<!-- comment --><p>x</p>",
+    "trailing ampersand &",
+    "incomplete entity &am",
+]
+for doc in documents:
+    for kw in ({"convert_charrefs": True}, {"convert_charrefs": False}):
+        for chunk in (None, 1, 7):
+            native = run(doc, chunk, **kw)
+            fallback = pure(run, doc, chunk, **kw)
+            assert native == fallback, (doc, kw, chunk, native, fallback)
+
+events = run("<script>a < b</script>", convert_charrefs=False)
+assert events == [
+    ("start", "script", []),
+    ("data", "a < b"),
+    ("end", "script"),
+], events
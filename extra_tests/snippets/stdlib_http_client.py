@@ -0,0 +1,83 @@
+# The _http_client native header parser must produce the same HTTPMessage
+# content as the email.parser fallback.
+
+import http.client
+import io
+
+
+def pure(fn, *args):
+    orig = http.client._parse_header_lines
+    http.client._parse_header_lines = None
+    try:
+        return fn(*args)
+    finally:
+        http.client._parse_header_lines = orig
+
+
+def parse(raw):
+    msg = http.client.parse_headers(io.BytesIO(raw))
+    return sorted(msg.items()), msg.get_payload()
+
+
+header_blocks = [
+    b"Host: example.com\r\nContent-Type: text/html\r\n\r\n",
+    # same header repeated must keep every occurrence
+    b"Set-Cookie: a=1\r\nSet-Cookie: b=2\r\n\r\n",
+    # no space after the colon, tab used as separator, empty value
+    b"Host:example.com\r\nAccept:\tapplication/json\r\nX-Empty:\r\n\r\n",
+    # latin-1 bytes in the value survive the decode
+    b"X-Name: caf\xe9\r\n\r\n",
+    # bare-LF line endings
+    b"Host: example.com\nAccept: */*\n\n",
+    # trailing whitespace in the value is preserved
+    b"X-Pad: padded value  \r\n\r\n",
+    # mixed case names pass through untouched
+    b"conTENT-length: 42\r\n\r\n",
+    b"\r\n",
+    # obs-fold continuations make the native pass bail out; both paths must
+    # still agree on the result
+    b"X-Folded: line one\r\n\tline two\r\nHost: example.com\r\n\r\n",
+]
+for raw in header_blocks:
+    native = parse(raw)
+    fallback = pure(parse, raw)
+    assert native == fallback, (raw, native, fallback)
+
+msg = http.client.parse_headers(
+    io.BytesIO(b"Set-Cookie: a=1\r\nSet-Cookie: b=2\r\n\r\n")
+)
+assert msg.get_all("Set-Cookie") == ["a=1", "b=2"]
+msg = http.client.parse_headers(io.BytesIO(b"Host:example.com\r\n\r\n"))
+assert msg["Host"] == "example.com"
+assert msg["host"] == "example.com"
+
+
+# a full response drives the same parser
+class FakeSocket:
+    def __init__(self, data):
+        self.data = data
+
+    def makefile(self, mode):
+        assert mode == "rb"
+        return io.BytesIO(self.data)
+
+
+raw_response = (
+    b"HTTP/1.1 200 OK\r\n"
+    b"Content-Type: text/plain\r\n"
+    b"Content-Length: 5\r\n"
+    b"\r\n"
+    b"hello"
+)
+
+
+def fetch():
+    resp = http.client.HTTPResponse(FakeSocket(raw_response))
+    resp.begin()
+    return resp.status, resp.reason, sorted(resp.getheaders()), resp.read()
+
+
+native = fetch()
+assert native == pure(fetch), native
+assert native[0] == 200
+assert native[3] == b"hello"
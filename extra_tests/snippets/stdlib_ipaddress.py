@@ -0,0 +1,66 @@
+# The _ipaddress native IPv4 parser must accept exactly what the
+# pure-Python _ip_int_from_string accepts, with the same values.
+
+import ipaddress
+
+
+def pure(fn, *args):
+    orig = ipaddress._v4_int_from_string
+    ipaddress._v4_int_from_string = None
+    try:
+        return fn(*args)
+    finally:
+        ipaddress._v4_int_from_string = orig
+
+
+valid = [
+    "0.0.0.0",
+    "255.255.255.255",
+    "192.168.0.1",
+    "1.2.3.4",
+    "10.0.0.255",
+    "100.200.30.4",
+]
+for s in valid:
+    native = int(ipaddress.IPv4Address(s))
+    fallback = int(pure(ipaddress.IPv4Address, s))
+    assert native == fallback, (s, native, fallback)
+    assert str(ipaddress.IPv4Address(s)) == s
+
+assert int(ipaddress.IPv4Address("1.2.3.4")) == 0x01020304
+assert int(ipaddress.IPv4Address("255.255.255.255")) == 2**32 - 1
+
+invalid = [
+    "",
+    "1.2.3",
+    "1.2.3.4.5",
+    "256.1.1.1",
+    "1.2.3.256",
+    "01.2.3.4",       # leading zero, bpo-36384
+    "1.2.3.04",
+    "1.2.3.0x4",
+    " 1.2.3.4",
+    "1.2.3.4 ",
+    "1..3.4",
+    ".1.2.3",
+    "1.2.3.",
+    "+1.2.3.4",
+    "-1.2.3.4",
+    "1.2.3.4\n",
+    "1٢.3.4.5",        # non-ASCII digit
+    "1234.1.1.1",
+]
+for s in invalid:
+    for parse in (ipaddress.IPv4Address, lambda s: pure(ipaddress.IPv4Address, s)):
+        try:
+            parse(s)
+        except ipaddress.AddressValueError:
+            pass
+        else:
+            assert False, ("accepted invalid address", s)
+
+# interfaces and networks route through the same parser
+assert ipaddress.ip_network("192.168.0.0/24") == pure(
+    ipaddress.ip_network, "192.168.0.0/24"
+)
+assert ipaddress.ip_interface("10.0.0.1/8") == pure(ipaddress.ip_interface, "10.0.0.1/8")
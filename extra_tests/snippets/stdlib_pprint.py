@@ -0,0 +1,79 @@
+# The _pprint native _safe_repr must agree with the pure-Python
+# implementation it shadows.
+
+import pprint
+
+
+def pure(fn, *args, **kw):
+    orig = pprint._safe_repr_native
+    pprint._safe_repr_native = None
+    try:
+        return fn(*args, **kw)
+    finally:
+        pprint._safe_repr_native = orig
+
+
+class DictSub(dict):
+    pass
+
+
+class ListSub(list):
+    pass
+
+
+class Plain:
+    def __repr__(self):
+        return "<plain>"
+
+
+objects = [
+    {},
+    [],
+    (),
+    {"b": 2, "a": 1},
+    {"nested": {"z": [1, 2, (3, {"k": "v"})], "a": (1,)}},
+    [1, [2, [3, [4]]]],
+    (1, (2, (3,))),
+    # unsortable keys fall back to pprint._safe_key ordering
+    {1: "int", "s": "str", (2, 3): "tuple", None: "none"},
+    {True: 1, 2: 2},
+    DictSub(a=1),  # subclasses bail out of the native pass
+    ListSub([1, 2]),
+    [Plain(), {"p": Plain()}],
+    {"big": list(range(30))},
+    "just a string",
+    b"bytes",
+    bytearray(b"ba"),
+    3.25,
+    10**40,
+    complex(1, -2),
+]
+for obj in objects:
+    for fn in (pprint.saferepr, pprint.pformat, pprint.isreadable, pprint.isrecursive):
+        native = fn(obj)
+        fallback = pure(fn, obj)
+        assert native == fallback, (obj, fn.__name__, native, fallback)
+
+# recursive structures produce the same <Recursion on ...> markers
+rec_list = [1]
+rec_list.append(rec_list)
+rec_dict = {}
+rec_dict["self"] = rec_dict
+for obj in (rec_list, rec_dict):
+    assert pprint.saferepr(obj) == pure(pprint.saferepr, obj), obj
+    assert pprint.isrecursive(obj)
+    assert not pprint.isreadable(obj)
+
+# depth cutoff and unsorted dicts take the same route
+deep = {"a": {"b": {"c": {"d": 1}}}}
+printer = pprint.PrettyPrinter(depth=2)
+assert printer.pformat(deep) == pure(pprint.PrettyPrinter(depth=2).pformat, deep)
+printer = pprint.PrettyPrinter(sort_dicts=False)
+unsorted = {"b": 1, "a": 2, "c": 3}
+assert printer.pformat(unsorted) == pure(
+    pprint.PrettyPrinter(sort_dicts=False).pformat, unsorted
+)
+
+assert pprint.saferepr({"b": 2, "a": 1}) == "{'a': 1, 'b': 2}"
+assert pprint.isreadable({"a": [1, 2]})
+assert not pprint.isreadable([Plain()])
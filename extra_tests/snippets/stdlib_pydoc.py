@@ -0,0 +1,27 @@
+import io
+import pydoc
+
+# the interactive help() browser: drive the help> sub-prompt through a
+# scripted session instead of a tty
+inp = io.StringIO("keywords\ntopics\nq\n")
+out = io.StringIO()
+helper = pydoc.Helper(input=inp, output=out)
+helper()
+text = out.getvalue()
+
+assert "help utility" in text
+assert "help>" in text
+assert "Here is a list of the Python keywords" in text
+assert "lambda" in text
+# the topic index comes from pydoc_data.topics
+assert "TRUTHVALUE" in text
+assert "leaving help" in text
+
+# an empty line leaves the browser just like "quit"
+out = io.StringIO()
+pydoc.Helper(input=io.StringIO("\n"), output=out)()
+assert "leaving help" in out.getvalue()
+
+# keyword and symbol requests resolve without entering the browser
+assert "if" in pydoc.Helper.keywords
+assert "@" in pydoc.Helper(io.StringIO(), io.StringIO()).symbols
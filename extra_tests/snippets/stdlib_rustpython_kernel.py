@@ -0,0 +1,42 @@
+import rustpython_kernel as rk
+
+key = b"secret"
+
+# wire format round-trips and rejects tampering
+frames = rk.serialize(key, [b"id1"], rk.make_header("execute_request", "s"),
+                      None, {"code": "x = 2"})
+ids, header, _parent, content = rk.deserialize(key, frames)
+assert ids == [b"id1"]
+assert header["msg_type"] == "execute_request"
+assert content["code"] == "x = 2"
+
+tampered = list(frames)
+tampered[-1] = b'{"code": "import os"}'
+try:
+    rk.deserialize(key, tampered)
+except ValueError:
+    pass
+else:
+    assert False, "tampered message accepted"
+
+kernel = rk.Kernel()
+assert kernel.kernel_info()["implementation"] == "rustpython"
+
+# execute: statements bind, expressions produce a repr and set _
+reply, text = kernel.do_execute("x = 21 * 2")
+assert reply["status"] == "ok" and text is None
+reply, text = kernel.do_execute("x")
+assert text == "42"
+assert kernel.user_ns["_"] == 42
+reply, _text = kernel.do_execute("1/0")
+assert reply["status"] == "error"
+assert reply["ename"] == "ZeroDivisionError"
+
+# completion and inspection against the kernel namespace
+kernel.do_execute("import math")
+completions = kernel.do_complete("math.sq", 7)
+assert any(m.startswith("math.sq") for m in completions["matches"])
+inspected = kernel.do_inspect("math.sqrt", 9)
+assert inspected["found"]
+assert "sqrt" in inspected["data"]["text/plain"]
+assert not kernel.do_inspect("no_such_name", 12)["found"]
@@ -0,0 +1,83 @@
+# The _shlex native split must tokenize exactly like the pure-Python
+# shlex lexer it shadows.
+
+import shlex
+import sys
+
+_MISSING = object()
+
+
+def pure_split(s, comments=False):
+    """shlex.split with `import _shlex` failing, forcing the pure lexer."""
+    saved = sys.modules.get("_shlex", _MISSING)
+    sys.modules["_shlex"] = None
+    try:
+        return shlex.split(s, comments)
+    finally:
+        if saved is _MISSING:
+            del sys.modules["_shlex"]
+        else:
+            sys.modules["_shlex"] = saved
+
+
+inputs = [
+    "",
+    "   ",
+    "a b c",
+    "  leading and   trailing  ",
+    "tabs\tand\nnewlines\rhandled",
+    # quoting edge cases
+    "''",
+    '""',
+    "a''b",
+    'a""b',
+    "'single quoted'",
+    '"double quoted"',
+    "'it'\\''s'",
+    "nested \"'single' inside\"",
+    "nested '\"double\" inside'",
+    "adjacent'quo'ted\"frag\"ments",
+    "empty '' in \"\" middle",
+    # escapes: everything outside quotes, only \\ and \" inside double quotes
+    r"back\slash",
+    r"escaped\ space",
+    r'"escaped \" quote"',
+    r'"kept \n backslash"',
+    r"'no \escape in single'",
+    r"trailing\\",
+    "unicode café naïve tokens",
+]
+for s in inputs:
+    native = shlex.split(s)
+    fallback = pure_split(s)
+    assert native == fallback, (s, native, fallback)
+
+# comments=True strips from '#' to end of line
+comment_inputs = [
+    "a b # the rest is a comment",
+    "a#b",
+    "'quoted # not a comment' x",
+    '"also # quoted" y',
+    "# whole line\nnext line",
+    "cmd arg # trailing\nmore",
+]
+for s in comment_inputs:
+    native = shlex.split(s, comments=True)
+    fallback = pure_split(s, comments=True)
+    assert native == fallback, (s, native, fallback)
+
+# both paths reject unterminated quotes and dangling escapes the same way
+for s in ["'unclosed", '"unclosed', "mid'quote", "dangling\\", '"dangling\\']:
+    for split in (shlex.split, pure_split):
+        try:
+            split(s)
+        except ValueError:
+            pass
+        else:
+            assert False, ("expected ValueError", s, split)
+
+assert shlex.split("a 'b c' d") == ["a", "b c", "d"]
+assert shlex.split("''") == [""]
+assert shlex.split(r'"a \" b"') == ['a " b']
+assert shlex.split("a#b") == ["a#b"]
+assert shlex.split("a #b", comments=True) == ["a"]
@@ -25,3 +25,89 @@ assert r == "tim likes kung pow"
 from string import Formatter
 
 f = Formatter()
+
+
+# The _string native Template fast paths must agree with the regex
+# implementation across substitute, safe_substitute, is_valid and
+# get_identifiers.
+def template_pure(fn, *args, **kw):
+    orig = string._template_substitute
+    string._template_substitute = None
+    try:
+        return fn(*args, **kw)
+    finally:
+        string._template_substitute = orig
+
+
+mapping = {"who": "tim", "what": "kung pow", "_x": "u", "n1": "1"}
+templates = [
+    "$who likes $what",
+    "${who} likes ${what}",
+    "$$ is escaped, $who is not",
+    "$_x and $n1",
+    "no placeholders at all",
+    "",
+    "$who$what",
+    "adjacent ${who}${what} braces",
+    # ASCII-only identifier rule: the accent ends the identifier
+    "$whoé",
+    # missing keys and ill-formed placeholders in safe mode
+    "$missing stays, $$who stays escaped",
+    "${missing} stays",
+    "bare $ dollar",
+    "trailing $",
+    "$1 digit",
+    "${ } bad brace",
+    "${unclosed",
+    "${}",
+]
+for tmpl in templates:
+    t = Template(tmpl)
+    native = t.safe_substitute(mapping)
+    fallback = template_pure(t.safe_substitute, mapping)
+    assert native == fallback, (tmpl, native, fallback)
+
+    native_valid = t.is_valid()
+    assert native_valid == template_pure(t.is_valid), tmpl
+    native_ids = t.get_identifiers()
+    assert native_ids == template_pure(t.get_identifiers), tmpl
+
+    try:
+        native = t.substitute(mapping)
+        native_exc = None
+    except (ValueError, KeyError) as exc:
+        native = None
+        native_exc = (type(exc), str(exc))
+    try:
+        fallback = template_pure(t.substitute, mapping)
+        fallback_exc = None
+    except (ValueError, KeyError) as exc:
+        fallback = None
+        fallback_exc = (type(exc), str(exc))
+    assert native == fallback, (tmpl, native, fallback)
+    assert native_exc == fallback_exc, (tmpl, native_exc, fallback_exc)
+
+t = Template("$who likes ${what}: $$100")
+assert t.substitute(who="tim", what="cash") == "tim likes cash: $100"
+assert t.get_identifiers() == ["who", "what"]
+assert t.is_valid()
+assert not Template("$1").is_valid()
+assert Template("$missing").safe_substitute() == "$missing"
+try:
+    Template("abc ${ bad").substitute(x=1)
+except ValueError as exc:
+    assert "line 1, col 5" in str(exc), exc
+else:
+    assert False, "invalid placeholder must raise ValueError"
+try:
+    Template("$missing").substitute()
+except KeyError:
+    pass
+else:
+    assert False, "missing key must raise KeyError"
+
+# keyword arguments are layered over the positional mapping
+t = Template("$a $b")
+assert t.substitute({"a": 1, "b": 2}, b=3) == template_pure(
+    t.substitute, {"a": 1, "b": 2}, b=3
+)
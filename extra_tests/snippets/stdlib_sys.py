@@ -147,3 +147,10 @@ env["PYTHONSAFEPATH"] = "1"
 proc = subprocess.run(args, stdout=subprocess.PIPE, universal_newlines=True, env=env)
 assert proc.stdout.rstrip() == "True"
 assert proc.returncode == 0, proc
+
+# stdlib_module_names covers the pure-Python library too, not just the
+# native and frozen modules
+assert isinstance(sys.stdlib_module_names, frozenset)
+for name in ("sys", "builtins", "os", "collections", "json", "typing"):
+    assert name in sys.stdlib_module_names, name
+assert "__main__" not in sys.stdlib_module_names
@@ -0,0 +1,89 @@
+# The _textwrap fast paths (wordsep_simple, dedent, indent) must be
+# indistinguishable from the pure-Python implementations they replace.
+
+import sys
+import textwrap
+
+_MISSING = object()
+
+
+def without_module(name, fn, *args):
+    """Run fn with `import name` failing, forcing the pure-Python path."""
+    saved = sys.modules.get(name, _MISSING)
+    sys.modules[name] = None
+    try:
+        return fn(*args)
+    finally:
+        if saved is _MISSING:
+            del sys.modules[name]
+        else:
+            sys.modules[name] = saved
+
+
+def pure_wordsep(fn, *args):
+    orig = textwrap._wordsep_simple
+    textwrap._wordsep_simple = None
+    try:
+        return fn(*args)
+    finally:
+        textwrap._wordsep_simple = orig
+
+
+# wordsep_simple: only the six ASCII whitespace characters split chunks
+texts = [
+    "The quick  brown\tfox\njumps \x0bover \x0cthe \rlazy dog",
+    "  leading and trailing  ",
+    "nospaces",
+    "",
+    " ",
+    "\t\t",
+    "no break spaces stay inside words",
+    "hy-phen-ated words are not broken in this mode",
+]
+for text in texts:
+    w = textwrap.TextWrapper(width=10, break_on_hyphens=False)
+    native = w.wrap(text)
+    fallback = pure_wordsep(w.wrap, text)
+    assert native == fallback, (text, native, fallback)
+
+assert textwrap.TextWrapper(width=10, break_on_hyphens=False).wrap(
+    "aa bb cc dd"
+) == ["aa bb cc", "dd"]
+
+# dedent
+dedent_cases = [
+    "    hello\n    world\n",
+    "    hello\n\tworld\n",
+    "\thello\n\t\tworld",
+    "  a\n\n   \n  b\n",
+    "no margin\n  indented\n",
+    "",
+    "\n\n",
+    "   \n \t \n",
+    "  only one line",
+]
+for text in dedent_cases:
+    native = textwrap.dedent(text)
+    fallback = without_module("_textwrap", textwrap.dedent, text)
+    assert native == fallback, (text, native, fallback)
+
+assert textwrap.dedent("    a\n      b\n    c\n") == "a\n  b\nc\n"
+assert textwrap.dedent("  a\n   \n  b\n") == "a\n\nb\n"
+
+# indent
+indent_cases = [
+    "hello\nworld\n",
+    "hello\n\nworld",
+    "a\r\nb\rc\nd",
+    "u v\x0bw\x85x",
+    "line still the same line for splitlines next\n",
+    "   \nspaced\n\t\n",
+    "",
+    "no newline at end",
+]
+for text in indent_cases:
+    native = textwrap.indent(text, "  ")
+    fallback = without_module("_textwrap", textwrap.indent, text, "  ")
+    assert native == fallback, (text, native, fallback)
+
+assert textwrap.indent("a\n\nb\n", "> ") == "> a\n\n> b\n"
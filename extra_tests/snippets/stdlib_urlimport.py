@@ -0,0 +1,72 @@
+import hashlib
+import io
+import sys
+import zipfile
+
+import urlimport
+
+# no network in tests: serve canned payloads instead of fetching
+payloads = {}
+urlimport._fetch = lambda url: payloads[url]
+
+source = b"VALUE = 42\n"
+url = "https://plugins.example/plugin_mod.py"
+payloads[url] = source
+
+urlimport.install()
+urlimport.install()  # idempotent
+assert sys.meta_path.count(urlimport._finder) == 1
+
+# pinning requires https
+try:
+    urlimport.pin("evil", "http://plugins.example/evil.py", sha256="0" * 64)
+except urlimport.UrlImportError:
+    pass
+else:
+    assert False, "non-https pin should be refused"
+
+# a correct pin imports and carries its origin
+urlimport.pin("plugin_mod", url, sha256=hashlib.sha256(source).hexdigest())
+import plugin_mod
+
+assert plugin_mod.VALUE == 42
+assert plugin_mod.__spec__.origin == url
+
+# a wrong digest fails the import
+urlimport.pin("plugin_bad", url, sha256="0" * 64)
+try:
+    import plugin_bad
+except urlimport.UrlImportError:
+    pass
+else:
+    assert False, "hash mismatch should be rejected"
+
+# content-addressed bundle: verified once at pin time, then served from it
+buf = io.BytesIO()
+with zipfile.ZipFile(buf, "w") as zf:
+    zf.writestr("bundled.py", "WHO = 'bundle'\n")
+    zf.writestr("pkg/__init__.py", "NAME = 'pkg'\n")
+bundle = buf.getvalue()
+bundle_url = "https://plugins.example/bundle.zip"
+payloads[bundle_url] = bundle
+
+try:
+    urlimport.pin_bundle(bundle_url, sha256="0" * 64)
+except urlimport.UrlImportError:
+    pass
+else:
+    assert False, "bundle hash mismatch should be rejected"
+
+urlimport.pin_bundle(bundle_url, sha256=hashlib.sha256(bundle).hexdigest())
+import bundled
+import pkg
+
+assert bundled.WHO == "bundle"
+assert pkg.NAME == "pkg"
+assert pkg.__spec__.submodule_search_locations is not None
+
+# unpinned names are untouched, and uninstall removes the finder
+assert urlimport._finder.find_spec("json") is None
+urlimport.uninstall()
+assert urlimport._finder not in sys.meta_path
+urlimport.uninstall()  # tolerates not being installed
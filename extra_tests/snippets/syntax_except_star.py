@@ -0,0 +1,149 @@
+# try/except* — exception group matching and re-raising
+
+# basic: a matching group is caught and delivered as an ExceptionGroup
+caught = None
+try:
+    raise ExceptionGroup("eg", [ValueError(1), ValueError(2)])
+except* ValueError as eg:
+    caught = eg
+assert isinstance(caught, ExceptionGroup)
+assert [type(e) for e in caught.exceptions] == [ValueError, ValueError]
+assert [e.args[0] for e in caught.exceptions] == [1, 2]
+
+# a naked exception is wrapped into an ExceptionGroup for the handler
+caught = None
+try:
+    raise ValueError("naked")
+except* ValueError as eg:
+    caught = eg
+assert isinstance(caught, ExceptionGroup)
+assert len(caught.exceptions) == 1
+assert type(caught.exceptions[0]) is ValueError
+assert caught.exceptions[0].args == ("naked",)
+
+# the unmatched rest of the group propagates after the handlers run
+caught_v = None
+rest = None
+try:
+    try:
+        raise ExceptionGroup("eg", [ValueError(1), TypeError(2), ValueError(3)])
+    except* ValueError as eg:
+        caught_v = eg
+except ExceptionGroup as eg:
+    rest = eg
+assert [e.args[0] for e in caught_v.exceptions] == [1, 3]
+assert [type(e) for e in rest.exceptions] == [TypeError]
+assert rest.exceptions[0].args == (2,)
+
+# multiple except* clauses can each take their slice of one group
+got = []
+try:
+    raise ExceptionGroup("eg", [ValueError(1), TypeError(2)])
+except* ValueError as eg:
+    got.append(("ValueError", len(eg.exceptions)))
+except* TypeError as eg:
+    got.append(("TypeError", len(eg.exceptions)))
+assert got == [("ValueError", 1), ("TypeError", 1)]
+
+# an unmatched naked exception propagates unwrapped
+rest = None
+try:
+    try:
+        raise KeyError("k")
+    except* ValueError:
+        assert False, "should not match"
+except KeyError as exc:
+    rest = exc
+assert type(rest) is KeyError
+
+# except* ExceptionGroup is rejected at match time
+err = None
+try:
+    try:
+        raise ValueError(1)
+    except* ExceptionGroup:
+        pass
+except TypeError as exc:
+    err = exc
+assert err is not None
+assert "catching ExceptionGroup with except*" in str(err)
+
+# matching against something that isn't an exception type is a TypeError
+err = None
+try:
+    try:
+        raise ValueError(1)
+    except* int:
+        pass
+except TypeError as exc:
+    err = exc
+assert err is not None
+
+# return/break/continue may not escape an except* handler: they would skip
+# the final reraise and silently drop unhandled parts of the group
+def check_syntax_error(src, what):
+    try:
+        compile(src, "<test>", "exec")
+    except SyntaxError as exc:
+        # CPython's parser phrases this as "'break', 'continue' and 'return'
+        # cannot appear in an except* block"; accept both spellings
+        assert f"'{what}'" in str(exc) and "cannot appear in" in str(exc), exc
+    else:
+        assert False, (src, "compiled but should not")
+
+
+check_syntax_error(
+    "def f():\n"
+    "    try:\n"
+    "        pass\n"
+    "    except* ValueError:\n"
+    "        return\n",
+    "return",
+)
+check_syntax_error(
+    "def f():\n"
+    "    try:\n"
+    "        pass\n"
+    "    except* ValueError:\n"
+    "        if x:\n"
+    "            return 1\n",
+    "return",
+)
+check_syntax_error(
+    "while True:\n"
+    "    try:\n"
+    "        pass\n"
+    "    except* ValueError:\n"
+    "        break\n",
+    "break",
+)
+check_syntax_error(
+    "while True:\n"
+    "    try:\n"
+    "        pass\n"
+    "    except* ValueError:\n"
+    "        continue\n",
+    "continue",
+)
+
+# a loop opened inside the handler, or a nested function, makes them legal
+compile(
+    "try:\n"
+    "    pass\n"
+    "except* ValueError:\n"
+    "    for x in y:\n"
+    "        if x:\n"
+    "            break\n"
+    "        continue\n",
+    "<test>",
+    "exec",
+)
+compile(
+    "try:\n"
+    "    pass\n"
+    "except* ValueError:\n"
+    "    def f():\n"
+    "        return 1\n",
+    "<test>",
+    "exec",
+)
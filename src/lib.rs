@@ -57,7 +57,7 @@ use std::process::ExitCode;
 pub use interpreter::InterpreterConfig;
 pub use rustpython_vm as vm;
 pub use settings::{InstallPipMode, RunMode, parse_opts};
-pub use shell::run_shell;
+pub use shell::{ShellConfig, run_shell};
 
 /// The main cli of the `rustpython` interpreter. This function will return `std::process::ExitCode`
 /// based on the return code of the python code ran through the cli.
@@ -161,13 +161,24 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
     let scope = setup_main_module(vm)?;
 
     if !vm.state.settings.safe_path {
-        // TODO: The prepending path depends on running mode
-        // See https://docs.python.org/3/using/cmdline.html#cmdoption-P
-        vm.run_code_string(
-            vm.new_scope_with_builtins(),
-            "import sys; sys.path.insert(0, '')",
-            "<embedded>".to_owned(),
-        )?;
+        // What lands in sys.path[0] depends on the running mode
+        // (https://docs.python.org/3/using/cmdline.html#cmdoption-P):
+        // a script prepends its own directory (run_script does that itself),
+        // -m prepends the current directory so the named package resolves
+        // the way it would under CPython, and -c/REPL prepend ''
+        let path0 = match &run_mode {
+            RunMode::Script(_) => None,
+            RunMode::Module(_) => Some(
+                std::env::current_dir()
+                    .ok()
+                    .and_then(|dir| dir.into_os_string().into_string().ok())
+                    .unwrap_or_default(),
+            ),
+            RunMode::Command(_) | RunMode::InstallPip(_) | RunMode::Repl => Some(String::new()),
+        };
+        if let Some(path0) = path0 {
+            vm.insert_sys_path(vm.new_pyobj(path0))?;
+        }
     }
 
     let site_result = vm.import("site", 0);
@@ -178,6 +189,22 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
         );
     }
 
+    if vm
+        .state
+        .settings
+        .xoptions
+        .iter()
+        .any(|(name, _)| name == "lazy_imports")
+    {
+        let enable_lazy = vm
+            .import("_lazy_imports", 0)
+            .and_then(|module| module.get_attr("enable", vm))
+            .and_then(|enable| enable.call((), vm));
+        if enable_lazy.is_err() {
+            warn!("Failed to enable -X lazy_imports");
+        }
+    }
+
     let is_repl = matches!(run_mode, RunMode::Repl);
     if !vm.state.settings.quiet
         && (vm.state.settings.verbose > 0 || (is_repl && std::io::stdin().is_terminal()))
@@ -294,6 +321,10 @@ mod tests {
                 // test module run
                 vm.run_script(scope, "extra_tests/snippets/dir_main")?;
 
+                let scope = setup_main_module(vm)?;
+                // test zipapp run
+                vm.run_script(scope, "extra_tests/snippets/zipapp_main.pyz")?;
+
                 Ok(())
             })());
         })
@@ -1,5 +1,6 @@
 use lexopt::Arg::*;
 use lexopt::ValueExt;
+use rustpython_vm::common::{os::fs_decode_wtf8, wtf8::Wtf8Buf};
 use rustpython_vm::{Settings, vm::CheckHashPycsMode};
 use std::str::FromStr;
 use std::{cmp, env};
@@ -100,6 +101,7 @@ Options (and corresponding environment variables):
 --help-all: print complete help information and exit
 
 RustPython extensions:
+--async: run the asyncio REPL, where top-level await is allowed (same as -m asyncio)
 
 
 Arguments:
@@ -111,10 +113,12 @@ arg ...: arguments passed to program in sys.argv[1:]
 fn parse_args() -> Result<(CliArgs, RunMode, Vec<String>), lexopt::Error> {
     let mut args = CliArgs::default();
     let mut parser = lexopt::Parser::from_env();
-    fn argv(argv0: String, mut parser: lexopt::Parser) -> Result<Vec<String>, lexopt::Error> {
-        std::iter::once(Ok(argv0))
-            .chain(parser.raw_args()?.map(|arg| arg.string()))
-            .collect()
+    fn argv(argv0: String, mut parser: lexopt::Parser) -> Result<Vec<Wtf8Buf>, lexopt::Error> {
+        // decode with surrogateescape rather than erroring out, so non-UTF-8
+        // arguments still show up in sys.argv
+        Ok(std::iter::once(Wtf8Buf::from_string(argv0))
+            .chain(parser.raw_args()?.map(fs_decode_wtf8))
+            .collect())
     }
     while let Some(arg) = parser.next()? {
         match arg {
@@ -161,9 +165,14 @@ fn parse_args() -> Result<(CliArgs, RunMode, Vec<String>), lexopt::Error> {
             #[cfg(feature = "flame-it")]
             Long("profile-format") => args.profile_format = Some(parser.value()?.string()?),
 
+            Long("async") => {
+                // the asyncio REPL, exactly like `-m asyncio`
+                let argv = argv("PLACEHOLDER".to_owned(), parser)?;
+                return Ok((args, RunMode::Module("asyncio".to_owned()), argv));
+            }
             Long("install-pip") => {
                 let (mode, argv) = if let Some(val) = parser.optional_value() {
-                    (val.parse()?, vec![val.string()?])
+                    (val.parse()?, vec![Wtf8Buf::from_string(val.string()?)])
                 } else if let Ok(argv0) = parser.value() {
                     let mode = argv0.parse()?;
                     (mode, argv(argv0.string()?, parser)?)
@@ -171,7 +180,7 @@ fn parse_args() -> Result<(CliArgs, RunMode, Vec<String>), lexopt::Error> {
                     (
                         InstallPipMode::Ensurepip,
                         ["ensurepip", "--upgrade", "--default-pip"]
-                            .map(str::to_owned)
+                            .map(|s| Wtf8Buf::from_string(s.to_owned()))
                             .into(),
                     )
                 };
@@ -269,6 +278,7 @@ pub fn parse_opts() -> Result<(Settings, RunMode), lexopt::Error> {
             "dev" => settings.dev_mode = true,
             "warn_default_encoding" => settings.warn_default_encoding = true,
             "no_sig_int" => settings.install_signal_handlers = false,
+            "deterministic" => settings.deterministic = true,
             "int_max_str_digits" => {
                 settings.int_max_str_digits = match value.unwrap().parse() {
                     Ok(digits) if digits == 0 || digits >= 640 => digits,
@@ -322,6 +332,12 @@ pub fn parse_opts() -> Result<(Settings, RunMode), lexopt::Error> {
         None => None,
     };
 
+    // reproducible runs need a pinned hash seed; an explicit PYTHONHASHSEED
+    // still wins so a particular randomized ordering can be replayed
+    if settings.deterministic && settings.hash_seed.is_none() {
+        settings.hash_seed = Some(0);
+    }
+
     settings.argv = argv;
 
     #[cfg(feature = "flame-it")]
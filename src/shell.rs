@@ -6,7 +6,7 @@ use rustpython_compiler::{
 };
 use rustpython_vm::{
     AsObject, PyResult, VirtualMachine,
-    builtins::PyBaseExceptionRef,
+    builtins::{PyBaseExceptionRef, PyStr},
     compiler::{self},
     readline::{Readline, ReadlineResult},
     scope::Scope,
@@ -108,29 +108,535 @@ fn shell_exec(
     }
 }
 
+/// A one-line `pip install` pointer for an `import` that raised
+/// ModuleNotFoundError, mapping the import names that differ from their
+/// distribution names. Only the interactive shell prints it; scripts and
+/// `-c` runs never see the hint.
+fn missing_module_hint(vm: &VirtualMachine, exc: &PyBaseExceptionRef) -> Option<String> {
+    if !exc.fast_isinstance(vm.ctx.exceptions.module_not_found_error) {
+        return None;
+    }
+    let name = exc.as_object().get_attr("name", vm).ok()?;
+    let name = name.downcast_ref::<PyStr>()?.as_str().to_owned();
+    let top = name.split('.').next()?;
+    // import name -> PyPI distribution, where the two differ
+    let dist = match top {
+        "cv2" => "opencv-python",
+        "PIL" => "Pillow",
+        "sklearn" => "scikit-learn",
+        "yaml" => "PyYAML",
+        "bs4" => "beautifulsoup4",
+        "Crypto" => "pycryptodome",
+        "dateutil" => "python-dateutil",
+        "dotenv" => "python-dotenv",
+        "git" => "GitPython",
+        other => other,
+    };
+    Some(format!(
+        "Hint: '{top}' is not installed; 'pip install {dist}' may provide it."
+    ))
+}
+
+/// Format a duration in seconds with the unit a human would pick for it.
+fn format_seconds(secs: f64) -> String {
+    if secs >= 1.0 {
+        format!("{secs:.3} s")
+    } else if secs >= 1e-3 {
+        format!("{:.3} ms", secs * 1e3)
+    } else if secs >= 1e-6 {
+        format!("{:.3} µs", secs * 1e6)
+    } else {
+        format!("{:.0} ns", secs * 1e9)
+    }
+}
+
+/// CPU time consumed by the process so far, via `time.process_time()`.
+fn cpu_seconds(vm: &VirtualMachine) -> Option<f64> {
+    let time = vm.import("time", 0).ok()?;
+    let process_time = time.get_attr("process_time", vm).ok()?;
+    let now = process_time.call((), vm).ok()?;
+    Some(now.try_float(vm).ok()?.to_f64())
+}
+
+/// `%time stmt`: run the statement once, reporting CPU and wall time.
+fn magic_time(vm: &VirtualMachine, scope: Scope, source: &str) {
+    let code = match vm.compile(source, compiler::Mode::Single, "<magic-time>".to_owned()) {
+        Ok(code) => code,
+        Err(err) => return vm.print_exception(vm.new_syntax_error(&err, Some(source))),
+    };
+    let cpu_before = cpu_seconds(vm);
+    let wall = std::time::Instant::now();
+    let result = vm.run_code_obj(code, scope);
+    let wall = wall.elapsed().as_secs_f64();
+    let cpu_after = cpu_seconds(vm);
+    if let Err(exc) = result {
+        vm.print_exception(exc);
+    }
+    if let (Some(before), Some(after)) = (cpu_before, cpu_after) {
+        println!(
+            "CPU time: {}, Wall time: {}",
+            format_seconds(after - before),
+            format_seconds(wall)
+        );
+    } else {
+        println!("Wall time: {}", format_seconds(wall));
+    }
+}
+
+/// `%timeit stmt`: grow the loop count geometrically until one batch takes
+/// long enough to measure, then report the best batch of several.
+fn magic_timeit(vm: &VirtualMachine, scope: Scope, source: &str) {
+    const MIN_BATCH_SECS: f64 = 0.2;
+    const MAX_LOOPS: u32 = 10_000_000;
+    const BATCHES: u32 = 5;
+
+    let code = match vm.compile(source, compiler::Mode::Exec, "<magic-timeit>".to_owned()) {
+        Ok(code) => code,
+        Err(err) => return vm.print_exception(vm.new_syntax_error(&err, Some(source))),
+    };
+    let mut run_batch = |loops: u32| -> Option<f64> {
+        let start = std::time::Instant::now();
+        for _ in 0..loops {
+            if let Err(exc) = vm.run_code_obj(code.clone(), scope.clone()) {
+                vm.print_exception(exc);
+                return None;
+            }
+        }
+        Some(start.elapsed().as_secs_f64())
+    };
+
+    let mut loops: u32 = 1;
+    let mut batch = match run_batch(loops) {
+        Some(t) => t,
+        None => return,
+    };
+    while batch < MIN_BATCH_SECS && loops < MAX_LOOPS {
+        loops = loops.saturating_mul(10).min(MAX_LOOPS);
+        batch = match run_batch(loops) {
+            Some(t) => t,
+            None => return,
+        };
+    }
+
+    let mut best = batch;
+    for _ in 1..BATCHES {
+        match run_batch(loops) {
+            Some(t) => best = best.min(t),
+            None => return,
+        }
+    }
+    println!(
+        "{loops} loop{} each, best of {BATCHES}: {} per loop",
+        if loops == 1 { "" } else { "s" },
+        format_seconds(best / loops as f64)
+    );
+}
+
+/// `%logstart file.py` transcript: executed input blocks are appended to the
+/// file as the session goes, so an exploratory session can be reconstructed
+/// as a script. Magic lines are logged as comments; output is not captured,
+/// keeping the file runnable Python.
+#[derive(Default)]
+struct SessionLog {
+    file: Option<(String, std::fs::File)>,
+}
+
+impl SessionLog {
+    fn start(&mut self, path: &str) {
+        use std::io::Write;
+        if let Some((active, _)) = &self.file {
+            println!("Already logging to {active}; use %logstop first");
+            return;
+        }
+        match std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+        {
+            Ok(mut file) => {
+                let _ = writeln!(file, "# RustPython session log");
+                println!("Logging session input to {path}");
+                self.file = Some((path.to_owned(), file));
+            }
+            Err(err) => eprintln!("Could not open log file {path}: {err}"),
+        }
+    }
+
+    fn stop(&mut self) {
+        match self.file.take() {
+            Some((path, _)) => println!("Stopped logging to {path}"),
+            None => println!("Not currently logging"),
+        }
+    }
+
+    fn record(&mut self, input: &str) {
+        use std::io::Write;
+        if let Some((path, file)) = &mut self.file {
+            if writeln!(file, "{}", input.trim_end()).is_err() {
+                eprintln!("Could not write session log {path}; logging stopped");
+                self.file = None;
+            }
+        }
+    }
+
+    /// Log a non-Python line (a magic) commented out, like IPython does.
+    fn record_comment(&mut self, line: &str) {
+        if self.file.is_some() {
+            self.record(&format!("# {}", line.trim()));
+        }
+    }
+}
+
+/// `%paste`/`%cpaste`: read raw lines until a `--` sentinel (or EOF) and run
+/// the whole buffer with `Mode::Exec`, bypassing the blank-line block
+/// termination in `shell_exec`. For terminals without bracketed paste, where
+/// code with embedded empty lines would otherwise be cut into pieces.
+fn run_paste_mode(
+    repl: &mut Readline<helper::ShellHelper<'_>>,
+    vm: &VirtualMachine,
+    scope: Scope,
+    log: &mut SessionLog,
+) -> PyResult<()> {
+    println!("Paste mode: reading until a line containing only '--' (or EOF)");
+    let mut buffer = String::new();
+    loop {
+        match repl.readline(": ") {
+            ReadlineResult::Line(line) => {
+                if line.trim_end() == "--" {
+                    break;
+                }
+                buffer.push_str(&line);
+                buffer.push('\n');
+            }
+            ReadlineResult::Eof => break,
+            ReadlineResult::Interrupt => {
+                let _ = vm.check_signals();
+                return Err(vm.new_exception_empty(vm.ctx.exceptions.keyboard_interrupt.to_owned()));
+            }
+            ReadlineResult::Other(err) => {
+                eprintln!("Readline error: {err:?}");
+                return Ok(());
+            }
+            ReadlineResult::Io(err) => {
+                eprintln!("IO error: {err:?}");
+                return Ok(());
+            }
+        }
+    }
+    if buffer.trim().is_empty() {
+        return Ok(());
+    }
+    let code = vm
+        .compile(&buffer, compiler::Mode::Exec, "<paste>".to_owned())
+        .map_err(|err| vm.new_syntax_error(&err, Some(&buffer)))?;
+    log.record(&buffer);
+    vm.run_code_obj(code, scope).map(drop)
+}
+
+/// Dispatch an IPython-style magic line (already stripped of its prefix).
+/// Returns false for unknown magics so the line passes through unmodified.
+fn try_magic(vm: &VirtualMachine, scope: Scope, log: &mut SessionLog, line: &str) -> bool {
+    let (magic, rest) = match line.split_once(char::is_whitespace) {
+        Some((magic, rest)) => (magic, rest.trim()),
+        None => (line.trim_end(), ""),
+    };
+    match magic {
+        "time" if !rest.is_empty() => magic_time(vm, scope, rest),
+        "timeit" if !rest.is_empty() => magic_timeit(vm, scope, rest),
+        "logstart" if !rest.is_empty() => log.start(rest),
+        "logstop" if rest.is_empty() => log.stop(),
+        _ => return false,
+    }
+    true
+}
+
+/// Indentation to pre-fill at a block continuation prompt: the previous
+/// line's leading whitespace, plus one level when it ends with a `:`.
+fn next_line_indent(input: &str) -> String {
+    let last_line = input.lines().next_back().unwrap_or("");
+    let mut indent: String = last_line
+        .chars()
+        .take_while(|c| matches!(c, ' ' | '\t'))
+        .collect();
+    let trimmed = last_line.trim_end();
+    if trimmed.ends_with(':') && !trimmed.trim_start().starts_with('#') {
+        indent.push_str("    ");
+    }
+    indent
+}
+
+/// Replace `sys.displayhook` with one that pipes oversized reprs through
+/// `pydoc.pager` (space/q navigation, `less` when available). The hook only
+/// pages when stdout is a tty and the repr is taller than the terminal;
+/// everything else falls through to the original displayhook.
+fn install_pager_displayhook(vm: &VirtualMachine) {
+    let source = r#"import sys
+
+def _pager_displayhook(value, _default=sys.displayhook):
+    if value is None:
+        return _default(value)
+    try:
+        text = repr(value)
+    except BaseException:
+        return _default(value)
+    try:
+        import os
+        pageable = (
+            sys.stdout.isatty()
+            and text.count("\n") + 1 > os.get_terminal_size().lines
+        )
+    except (OSError, ValueError, AttributeError):
+        pageable = False
+    if not pageable:
+        return _default(value)
+    import builtins
+    import pydoc
+    pydoc.pager(text)
+    builtins._ = value
+
+sys.displayhook = _pager_displayhook
+"#;
+    let result = vm
+        .compile(source, compiler::Mode::Exec, "<pager>".to_owned())
+        .map_err(|err| vm.new_syntax_error(&err, Some(source)))
+        .and_then(|code| vm.run_code_obj(code, vm.new_scope_with_builtins()));
+    if let Err(exc) = result {
+        vm.print_exception(exc);
+    }
+}
+
+/// Configuration for driving the interactive loop over arbitrary byte
+/// streams instead of the process terminal — a TCP socket, an SSH channel,
+/// an in-app console. See [`ShellConfig::run`].
+pub struct ShellConfig<R, W> {
+    /// Where input lines come from.
+    pub input: R,
+    /// Where prompts and the loop's own tracebacks go. What the executed
+    /// code prints still follows `sys.stdout`/`sys.stderr`; an embedder
+    /// will usually point those at the same place.
+    pub output: W,
+    /// Fixed `(ps1, ps2)` prompts; `sys.ps1`/`sys.ps2` when `None`.
+    pub prompt: Option<(String, String)>,
+    /// Append each executed input block to this file, as a session record.
+    pub history: Option<std::path::PathBuf>,
+}
+
+impl<R: std::io::BufRead, W: std::io::Write> ShellConfig<R, W> {
+    /// Run the interactive loop over the configured streams: the same
+    /// incomplete-input continuation behavior as the terminal shell, minus
+    /// everything that needs a tty (line editing, completion, magics).
+    ///
+    /// Returns when the input stream ends or breaks; `SystemExit` raised by
+    /// the running code is passed back to the caller like in [`run_shell`].
+    pub fn run(mut self, vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
+        use rustpython_vm::py_io::IoWriter;
+        use std::io::Write;
+
+        let mut history = self.history.and_then(|path| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .ok()
+        });
+        let mut record = |input: &str| {
+            if let Some(file) = &mut history {
+                let _ = writeln!(file, "{}", input.trim_end());
+            }
+        };
+
+        let mut full_input = String::new();
+        let mut continuing_block = false;
+        let mut continuing_line = false;
+
+        loop {
+            let prompt = match &self.prompt {
+                Some((ps1, ps2)) => {
+                    if continuing_block || continuing_line {
+                        ps2.clone()
+                    } else {
+                        ps1.clone()
+                    }
+                }
+                None => {
+                    let name = if continuing_block || continuing_line {
+                        "ps2"
+                    } else {
+                        "ps1"
+                    };
+                    vm.sys_module
+                        .get_attr(name, vm)
+                        .and_then(|prompt| prompt.str(vm))
+                        .map(|s| s.as_str().to_owned())
+                        .unwrap_or_default()
+                }
+            };
+            if write!(self.output, "{prompt}").is_err() || self.output.flush().is_err() {
+                break;
+            }
+
+            let mut line = String::new();
+            match self.input.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            let empty_line_given = line.trim().is_empty();
+            full_input.push_str(line);
+            full_input.push('\n');
+
+            continuing_line = false;
+            let result = vm.check_signals().map(|()| {
+                shell_exec(
+                    vm,
+                    &full_input,
+                    scope.clone(),
+                    empty_line_given,
+                    continuing_block,
+                )
+            });
+            let err = match result {
+                Err(err) => {
+                    continuing_block = false;
+                    record(&full_input);
+                    full_input.clear();
+                    Some(err)
+                }
+                Ok(ShellExecResult::Ok) => {
+                    if !continuing_block || empty_line_given {
+                        continuing_block = false;
+                        record(&full_input);
+                        full_input.clear();
+                    }
+                    None
+                }
+                Ok(ShellExecResult::ContinueLine) => {
+                    continuing_line = true;
+                    None
+                }
+                Ok(ShellExecResult::ContinueBlock) => {
+                    continuing_block = true;
+                    None
+                }
+                Ok(ShellExecResult::PyErr(err)) => {
+                    continuing_block = false;
+                    record(&full_input);
+                    full_input.clear();
+                    Some(err)
+                }
+            };
+            if let Some(exc) = err {
+                if exc.fast_isinstance(vm.ctx.exceptions.system_exit) {
+                    return Err(exc);
+                }
+                let _ = vm.write_exception(IoWriter::from_ref(&mut self.output), &exc);
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Enter a repl loop
 pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
-    let mut repl = Readline::new(helper::ShellHelper::new(vm, scope.globals.clone()));
-    let mut full_input = String::new();
+    let env = |name: &str| {
+        if vm.state.settings.ignore_environment {
+            None
+        } else {
+            std::env::var_os(name)
+        }
+    };
 
-    // Retrieve a `history_path_str` dependent on the OS
-    let repl_history_path = match dirs::config_dir() {
-        Some(mut path) => {
-            path.push("rustpython");
-            path.push("repl_history.txt");
-            path
+    // RUSTPYTHON_HISTORY overrides where history is stored; an empty value
+    // disables it entirely (nothing is loaded or written), for secure
+    // environments. Otherwise XDG_STATE_HOME is preferred over the config
+    // dir, falling back to the working directory.
+    let repl_history_path: Option<std::path::PathBuf> = match env("RUSTPYTHON_HISTORY") {
+        Some(path) if path.is_empty() => None,
+        Some(path) => Some(path.into()),
+        None => {
+            let in_dir = |mut dir: std::path::PathBuf| {
+                dir.push("rustpython");
+                dir.push("repl_history.txt");
+                dir
+            };
+            Some(match env("XDG_STATE_HOME").filter(|dir| !dir.is_empty()) {
+                Some(state_dir) => in_dir(state_dir.into()),
+                None => match dirs::config_dir() {
+                    Some(config_dir) => in_dir(config_dir),
+                    None => ".repl_history.txt".into(),
+                },
+            })
         }
-        None => ".repl_history.txt".into(),
     };
 
-    if repl.load_history(&repl_history_path).is_err() {
-        println!("No previous history.");
+    // RUSTPYTHON_HISTORY_SIZE caps how many entries are kept and saved
+    let max_history_size = env("RUSTPYTHON_HISTORY_SIZE")
+        .and_then(|size| size.to_str()?.trim().parse().ok())
+        .unwrap_or(rustpython_vm::readline::DEFAULT_MAX_HISTORY_SIZE);
+
+    // opt-in: RUSTPYTHON_PAGER pages expression reprs taller than the screen
+    if env("RUSTPYTHON_PAGER").is_some_and(|pager| !pager.is_empty()) {
+        install_pager_displayhook(vm);
+    }
+
+    let mut repl = Readline::with_max_history_size(
+        helper::ShellHelper::new(vm, scope.globals.clone()),
+        max_history_size,
+    );
+    let mut full_input = String::new();
+
+    if let Some(path) = &repl_history_path {
+        if repl.load_history(path).is_err() {
+            println!("No previous history.");
+        }
+    }
+
+    // run the file named by PYTHONSTARTUP in the shell's scope, so users get
+    // their customary imports and prompt tweaks; errors are printed but don't
+    // abort the shell
+    if !vm.state.settings.ignore_environment {
+        if let Some(startup) = std::env::var_os("PYTHONSTARTUP").filter(|s| !s.is_empty()) {
+            let path = startup.to_string_lossy().into_owned();
+            match std::fs::read_to_string(&startup) {
+                Ok(source) => match vm.compile(&source, compiler::Mode::Exec, path) {
+                    Ok(code) => {
+                        if let Err(exc) = vm.run_code_obj(code, scope.clone()) {
+                            vm.print_exception(exc);
+                        }
+                    }
+                    Err(err) => vm.print_exception(vm.new_syntax_error(&err, Some(&source))),
+                },
+                Err(err) => eprintln!("Could not open PYTHONSTARTUP file {path}: {err}"),
+            }
+        }
+    }
+
+    // sys.__interactivehook__ is how site configures readline, history and
+    // completion; CPython calls it after the startup file, before the first
+    // prompt
+    if let Ok(hook) = vm.sys_module.get_attr("__interactivehook__", vm) {
+        if let Err(exc) = hook.call((), vm) {
+            vm.print_exception(exc);
+        }
     }
 
+    // `%time`/`%timeit` magics; the prefix can be remapped (or disabled with
+    // an empty value) through the RUSTPYTHONMAGIC environment variable
+    let magic_prefix = if vm.state.settings.ignore_environment {
+        "%".to_owned()
+    } else {
+        std::env::var("RUSTPYTHONMAGIC").unwrap_or_else(|_| "%".to_owned())
+    };
+
     // We might either be waiting to know if a block is complete, or waiting to know if a multiline
     // statement is complete. In the former case, we need to ensure that we read one extra new line
     // to know that the block is complete. In the latter, we can execute as soon as the statement is
     // valid.
+    // `%logstart`/`%logstop` session transcript
+    let mut session_log = SessionLog::default();
+
     let mut continuing_block = false;
     let mut continuing_line = false;
 
@@ -145,19 +651,127 @@ pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
             .get_attr(prompt_name, vm)
             .and_then(|prompt| prompt.str(vm));
         let prompt = match prompt {
-            Ok(ref s) => s.as_str(),
-            Err(_) => "",
+            Ok(s) => s.as_str().to_owned(),
+            Err(_) => String::new(),
+        };
+        // a dynamic prompt object may color itself; the line editor measures
+        // the prompt it is handed for cursor positioning, so hand it the
+        // stripped text and let the helper swap the original back in for
+        // display
+        let prompt = if prompt.contains('\x1b') {
+            let plain = helper::strip_ansi(&prompt);
+            if let Some(helper) = repl.helper() {
+                helper.set_prompt_display(Some(prompt));
+            }
+            plain
+        } else {
+            if let Some(helper) = repl.helper() {
+                helper.set_prompt_display(None);
+            }
+            prompt
         };
 
+        // at a block continuation prompt, pre-fill the expected indentation;
+        // it is ordinary editable input, so the user can still dedent
+        let initial = if continuing_block && !continuing_line {
+            next_line_indent(&full_input)
+        } else {
+            String::new()
+        };
+
+        // readline.set_startup_hook: runs before each prompt, errors are
+        // printed but don't abort the shell
+        let startup_hook = vm.state.readline_state.startup_hook.lock().clone();
+        if let Some(hook) = startup_hook {
+            if let Err(exc) = hook.call((), vm) {
+                vm.print_exception(exc);
+            }
+        }
+
         continuing_line = false;
-        let result = match repl.readline(prompt) {
+        let result = match repl.readline_with_initial(&prompt, &initial) {
             ReadlineResult::Line(line) => {
                 #[cfg(debug_assertions)]
                 debug!("You entered {line:?}");
 
-                repl.add_history_entry(line.trim_end()).unwrap();
+                // paste mode drives the line editor itself, so it is handled
+                // here rather than in the ordinary magic dispatch
+                if full_input.is_empty()
+                    && !magic_prefix.is_empty()
+                    && line
+                        .trim_start()
+                        .strip_prefix(&magic_prefix)
+                        .is_some_and(|magic| matches!(magic.trim_end(), "paste" | "cpaste"))
+                {
+                    repl.add_history_entry(line.trim_end()).unwrap();
+                    if let Err(exc) = run_paste_mode(&mut repl, vm, scope.clone(), &mut session_log)
+                    {
+                        if exc.fast_isinstance(vm.ctx.exceptions.system_exit) {
+                            if let Some(path) = &repl_history_path {
+                                repl.save_history(path).unwrap();
+                            }
+                            return Err(exc);
+                        }
+                        vm.print_exception(exc);
+                    }
+                    continue;
+                }
+
+                // a logical block becomes a single history entry (embedded
+                // newlines included) once it is submitted, so Up recalls the
+                // whole function/loop as one editable unit instead of
+                // replaying it line by line
+                let mut commit_history = |input: &str| {
+                    let entry = input.trim_end();
+                    if !entry.is_empty() {
+                        repl.add_history_entry(entry).unwrap();
+                        // mirror into the `readline` module's history so
+                        // Python code sees what was typed
+                        if vm.state.readline_state.auto_history.load() {
+                            vm.state.readline_state.push_history(entry);
+                        }
+                    }
+                };
 
-                let empty_line_given = line.is_empty();
+                // site.setquit() installs Quitter objects whose repr prints
+                // this hint; keep the hint working when site was skipped (-S)
+                // instead of surfacing a NameError
+                if full_input.is_empty() {
+                    let name = line.trim();
+                    if matches!(name, "exit" | "quit")
+                        && scope.globals.get_item_opt(name, vm).ok().flatten().is_none()
+                        && vm.builtins.as_object().get_attr(name, vm).is_err()
+                    {
+                        let eof = if cfg!(windows) {
+                            "Ctrl-Z plus Return"
+                        } else {
+                            "Ctrl-D (i.e. EOF)"
+                        };
+                        println!("Use {name}() or {eof} to exit");
+                        continue;
+                    }
+                }
+
+                // magic lines never reach the compiler; unknown magics fall
+                // through and fail as ordinary (invalid) Python instead
+                if full_input.is_empty()
+                    && !magic_prefix.is_empty()
+                    && line.trim_start().starts_with(&magic_prefix)
+                    && try_magic(
+                        vm,
+                        scope.clone(),
+                        &mut session_log,
+                        &line.trim_start()[magic_prefix.len()..],
+                    )
+                {
+                    commit_history(&line);
+                    session_log.record_comment(&line);
+                    continue;
+                }
+
+                // any whitespace-only line ends a block, since the pre-filled
+                // indentation is still there when the user just hits enter
+                let empty_line_given = line.trim().is_empty();
 
                 if full_input.is_empty() {
                     full_input = line;
@@ -166,37 +780,58 @@ pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
                 }
                 full_input.push('\n');
 
-                match shell_exec(
-                    vm,
-                    &full_input,
-                    scope.clone(),
-                    empty_line_given,
-                    continuing_block,
-                ) {
-                    ShellExecResult::Ok => {
+                // signals that arrived while we sat at the prompt (e.g. a
+                // SIGINT sent from another process) are dispatched before the
+                // new input runs, so they surface here instead of being
+                // charged to the first instruction of this statement
+                let pending_signal = vm.check_signals();
+
+                match pending_signal.map(|()| {
+                    shell_exec(
+                        vm,
+                        &full_input,
+                        scope.clone(),
+                        empty_line_given,
+                        continuing_block,
+                    )
+                }) {
+                    Err(err) => {
+                        continuing_block = false;
+                        commit_history(&full_input);
+                        session_log.record(&full_input);
+                        full_input.clear();
+                        Err(err)
+                    }
+                    Ok(ShellExecResult::Ok) => {
                         if continuing_block {
                             if empty_line_given {
                                 // We should exit continue mode since the block successfully executed
                                 continuing_block = false;
+                                commit_history(&full_input);
+                                session_log.record(&full_input);
                                 full_input.clear();
                             }
                         } else {
                             // We aren't in continue mode so proceed normally
+                            commit_history(&full_input);
+                            session_log.record(&full_input);
                             full_input.clear();
                         }
                         Ok(())
                     }
                     // Continue, but don't change the mode
-                    ShellExecResult::ContinueLine => {
+                    Ok(ShellExecResult::ContinueLine) => {
                         continuing_line = true;
                         Ok(())
                     }
-                    ShellExecResult::ContinueBlock => {
+                    Ok(ShellExecResult::ContinueBlock) => {
                         continuing_block = true;
                         Ok(())
                     }
-                    ShellExecResult::PyErr(err) => {
+                    Ok(ShellExecResult::PyErr(err)) => {
                         continuing_block = false;
+                        commit_history(&full_input);
+                        session_log.record(&full_input);
                         full_input.clear();
                         Err(err)
                     }
@@ -205,6 +840,9 @@ pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
             ReadlineResult::Interrupt => {
                 continuing_block = false;
                 full_input.clear();
+                // drain any trigger the ^C also set, so the same interrupt
+                // isn't delivered a second time to the next statement
+                let _ = vm.check_signals();
                 let keyboard_interrupt =
                     vm.new_exception_empty(vm.ctx.exceptions.keyboard_interrupt.to_owned());
                 Err(keyboard_interrupt)
@@ -224,13 +862,21 @@ pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
 
         if let Err(exc) = result {
             if exc.fast_isinstance(vm.ctx.exceptions.system_exit) {
-                repl.save_history(&repl_history_path).unwrap();
+                if let Some(path) = &repl_history_path {
+                    repl.save_history(path).unwrap();
+                }
                 return Err(exc);
             }
+            let pip_hint = missing_module_hint(vm, &exc);
             vm.print_exception(exc);
+            if let Some(hint) = pip_hint {
+                eprintln!("{hint}");
+            }
         }
     }
-    repl.save_history(&repl_history_path).unwrap();
+    if let Some(path) = &repl_history_path {
+        repl.save_history(path).unwrap();
+    }
 
     Ok(())
 }
@@ -1,6 +1,6 @@
 #![cfg_attr(target_arch = "wasm32", allow(dead_code))]
 use rustpython_vm::{
-    AsObject, PyResult, TryFromObject, VirtualMachine,
+    AsObject, PyObjectRef, PyResult, TryFromObject, VirtualMachine,
     builtins::{PyDictRef, PyStrRef},
     function::ArgIterable,
     identifier,
@@ -9,6 +9,17 @@ use rustpython_vm::{
 pub struct ShellHelper<'vm> {
     vm: &'vm VirtualMachine,
     globals: PyDictRef,
+    highlight: bool,
+    /// ANSI-colored prompt to display in place of the plain one the line
+    /// editor was handed (and measures the cursor position from).
+    prompt_display: std::cell::RefCell<Option<String>>,
+}
+
+/// Whether the shell should emit ANSI colors: suppressed by the conventional
+/// `NO_COLOR` variable and for dumb terminals.
+fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none()
+        && std::env::var("TERM").map_or(true, |term| term != "dumb")
 }
 
 fn reverse_string(s: &mut String) {
@@ -53,9 +64,106 @@ fn split_idents_on_dot(line: &str) -> Option<(usize, Vec<String>)> {
     Some((startpos, words))
 }
 
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Collect importable module names found in `dir`, prefixed with `prefix`
+/// (a dotted package path, or empty for top-level modules).
+fn collect_modules_in_dir(dir: &str, prefix: &str, names: &mut Vec<String>) {
+    let dir = if dir.is_empty() { "." } else { dir };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else { continue };
+        if let Some(stem) = name.strip_suffix(".py") {
+            if is_identifier(stem) && stem != "__init__" {
+                names.push(format!("{prefix}{stem}"));
+            }
+        } else if is_identifier(name) && entry.path().join("__init__.py").exists() {
+            names.push(format!("{prefix}{name}"));
+        }
+    }
+}
+
+/// If `line` ends inside an open string literal, the byte offset just past
+/// its opening quote.
+fn open_string_start(line: &str) -> Option<usize> {
+    let mut chars = line.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            // a comment: nothing after this can open a string
+            '#' => return None,
+            '\'' | '"' => {
+                let mut escaped = false;
+                let mut closed = false;
+                for (_, c2) in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if c2 == '\\' {
+                        escaped = true;
+                    } else if c2 == c {
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    return Some(i + c.len_utf8());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Byte offset of the innermost unclosed `(` in `line`, skipping string
+/// literals and comments. `None` when no call is open, or when the innermost
+/// open bracket is a `[` or `{`.
+fn open_call_paren(line: &str) -> Option<usize> {
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let mut stack = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        match c {
+            '#' => break,
+            '"' | '\'' => {
+                i = scan_string(&chars, i);
+                continue;
+            }
+            '(' | '[' | '{' => stack.push((pos, c)),
+            ')' | ']' | '}' => {
+                stack.pop();
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    match stack.pop() {
+        Some((pos, '(')) => Some(pos),
+        _ => None,
+    }
+}
+
 impl<'vm> ShellHelper<'vm> {
     pub fn new(vm: &'vm VirtualMachine, globals: PyDictRef) -> Self {
-        ShellHelper { vm, globals }
+        ShellHelper {
+            vm,
+            globals,
+            highlight: use_color(),
+            prompt_display: std::cell::RefCell::new(None),
+        }
+    }
+
+    /// Install the colored rendition of the next prompt; `None` displays the
+    /// prompt as given. See [`strip_ansi`].
+    pub fn set_prompt_display(&self, prompt: Option<String>) {
+        *self.prompt_display.borrow_mut() = prompt;
     }
 
     fn get_available_completions<'w>(
@@ -99,7 +207,404 @@ impl<'vm> ShellHelper<'vm> {
         Some((word_start, iter1.chain(iter2.into_iter().flatten())))
     }
 
+    /// All module names that could appear in an `import` statement: native and
+    /// frozen modules, everything already imported, and what's on `sys.path`.
+    /// For a dotted `word`, submodules of the parent package are added too.
+    fn module_names(&self, word: &str) -> Vec<String> {
+        let vm = self.vm;
+        let mut names: Vec<String> = vm
+            .state
+            .module_inits
+            .keys()
+            .map(|s| s.to_string())
+            .chain(vm.state.frozen.keys().map(|s| s.to_string()))
+            .collect();
+
+        let str_elements = |obj: PyObjectRef| {
+            ArgIterable::<PyStrRef>::try_from_object(vm, obj)
+                .ok()?
+                .iter(vm)
+                .ok()
+        };
+
+        if let Some(keys) = vm
+            .sys_module
+            .get_attr("modules", vm)
+            .ok()
+            .and_then(str_elements)
+        {
+            names.extend(keys.flatten().map(|key| key.as_str().to_owned()));
+        }
+
+        if let Some(entries) = vm
+            .sys_module
+            .get_attr("path", vm)
+            .ok()
+            .and_then(str_elements)
+        {
+            for entry in entries.flatten() {
+                collect_modules_in_dir(entry.as_str(), "", &mut names);
+            }
+        }
+
+        // for a dotted name, offer the submodules of the parent package
+        if let Some((parent, _)) = word.rsplit_once('.') {
+            if let Some(paths) = vm
+                .sys_module
+                .get_attr("modules", vm)
+                .ok()
+                .and_then(|modules| modules.get_item(parent, vm).ok())
+                .and_then(|module| module.get_attr("__path__", vm).ok())
+                .and_then(str_elements)
+            {
+                for path in paths.flatten() {
+                    collect_modules_in_dir(path.as_str(), &format!("{parent}."), &mut names);
+                }
+            }
+        }
+
+        names
+    }
+
+    /// Names importable `from` an already-imported module: its attributes,
+    /// plus submodules living next to a package's `__init__`.
+    fn module_members(&self, module_name: &str) -> Option<Vec<String>> {
+        let vm = self.vm;
+        let module = vm
+            .sys_module
+            .get_attr("modules", vm)
+            .ok()?
+            .get_item(module_name, vm)
+            .ok()?;
+
+        let str_elements = |obj: PyObjectRef| {
+            ArgIterable::<PyStrRef>::try_from_object(vm, obj)
+                .ok()?
+                .iter(vm)
+                .ok()
+        };
+
+        let dir = vm
+            .call_special_method(&module, identifier!(vm, __dir__), ())
+            .ok()?;
+        let mut names: Vec<String> = str_elements(dir)?
+            .flatten()
+            .map(|s| s.as_str().to_owned())
+            .collect();
+
+        if let Some(paths) = module
+            .get_attr("__path__", vm)
+            .ok()
+            .and_then(str_elements)
+        {
+            for path in paths.flatten() {
+                collect_modules_in_dir(path.as_str(), "", &mut names);
+            }
+        }
+
+        Some(names)
+    }
+
+    /// Complete module names after `import`/`from`, and importable members
+    /// after `from module import`.
+    fn complete_import(&self, line: &str) -> Option<(usize, Vec<String>)> {
+        let word_start = line
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+            .map_or(0, |i| i + line[i..].chars().next().map_or(1, char::len_utf8));
+        let word = &line[word_start..];
+        let head: Vec<&str> = line[..word_start].split_whitespace().collect();
+        let mut candidates = match head.as_slice() {
+            ["import", ..] | ["from"] => self.module_names(word),
+            ["from", module, "import", ..] => self.module_members(module)?,
+            _ => return None,
+        };
+        candidates.retain(|name| name.starts_with(word) && name != word);
+        candidates.sort();
+        candidates.dedup();
+        Some((word_start, candidates))
+    }
+
+    /// Complete `mapping["<partial>` by listing the object's string keys.
+    /// The object is resolved from globals through plain attribute access
+    /// only, so nothing gets called.
+    fn complete_dict_key(&self, line: &str) -> Option<(usize, Vec<String>)> {
+        let vm = self.vm;
+        let bracket = line.rfind('[')?;
+        let mut content = line[bracket + 1..].chars();
+        let quote = content.next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let partial = content.as_str();
+        if partial.contains([quote, '\\']) {
+            return None;
+        }
+
+        let (_, words) = split_idents_on_dot(line[..bracket].trim_end())?;
+        let mut obj = self
+            .globals
+            .get_item_opt(words.first()?.as_str(), vm)
+            .ok()??;
+        for attr in &words[1..] {
+            let attr = vm.ctx.new_str(attr.as_str());
+            obj = obj.get_attr(&attr, vm).ok()?;
+        }
+
+        let keys = obj.get_attr("keys", vm).ok()?.call((), vm).ok()?;
+        let keys = ArgIterable::<PyStrRef>::try_from_object(vm, keys)
+            .ok()?
+            .iter(vm)
+            .ok()?;
+        let mut completions: Vec<String> = keys
+            .flatten()
+            .filter_map(|key| {
+                let key = key.as_str();
+                (key.starts_with(partial) && !key.contains(quote))
+                    .then(|| format!("{key}{quote}]"))
+            })
+            .collect();
+        completions.sort();
+        Some((bracket + 2, completions))
+    }
+
+    /// Inside a string literal, complete filesystem paths instead of
+    /// identifiers.
+    fn complete_path(&self, line: &str) -> Option<(usize, Vec<String>)> {
+        let content_start = open_string_start(line)?;
+        let partial = &line[content_start..];
+        let (dir, base) = match partial.rfind('/') {
+            Some(i) => partial.split_at(i + 1),
+            None => ("", partial),
+        };
+        let entries = std::fs::read_dir(if dir.is_empty() { "." } else { dir }).ok()?;
+        let mut completions = Vec::new();
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else { continue };
+            // only show dotfiles when one is being asked for
+            if !name.starts_with(base) || (name.starts_with('.') && !base.starts_with('.')) {
+                continue;
+            }
+            let mut completion = format!("{dir}{name}");
+            if entry.path().is_dir() {
+                completion.push('/');
+            }
+            completions.push(completion);
+        }
+        completions.sort();
+        Some((content_start, completions))
+    }
+
+    /// Resolve a dotted identifier chain against globals (falling back to
+    /// builtins) through plain attribute access only, so nothing gets called.
+    fn resolve_words(&self, words: &[String]) -> Option<PyObjectRef> {
+        let vm = self.vm;
+        let first = words.first()?;
+        let mut obj = match self.globals.get_item_opt(first.as_str(), vm).ok()? {
+            Some(obj) => obj,
+            None => vm.builtins.as_object().get_attr(first.as_str(), vm).ok()?,
+        };
+        for attr in &words[1..] {
+            let attr = vm.ctx.new_str(attr.as_str());
+            obj = obj.get_attr(&attr, vm).ok()?;
+        }
+        Some(obj)
+    }
+
+    /// The parameter names of a Python-level function that may be passed by
+    /// keyword, read off its code object.
+    fn keyword_params(&self, func: &PyObjectRef, skip_first: bool) -> Option<Vec<String>> {
+        let vm = self.vm;
+        let code = func.get_attr("__code__", vm).ok()?;
+        let count = |attr| {
+            code.get_attr(attr, vm)
+                .ok()?
+                .try_to_value::<usize>(vm)
+                .ok()
+        };
+        let argcount = count("co_argcount")?;
+        let kwonly = count("co_kwonlyargcount")?;
+        let posonly = count("co_posonlyargcount")?;
+        let varnames = code.get_attr("co_varnames", vm).ok()?;
+        let varnames = ArgIterable::<PyStrRef>::try_from_object(vm, varnames)
+            .ok()?
+            .iter(vm)
+            .ok()?;
+        let start = posonly.max(skip_first as usize);
+        Some(
+            varnames
+                .flatten()
+                .take(argcount + kwonly)
+                .skip(start)
+                .map(|s| s.as_str().to_owned())
+                .collect(),
+        )
+    }
+
+    /// Inside an open call, complete a partial argument name to the callable's
+    /// keyword parameters, with a trailing `=`: `func(x, ke<Tab>` offers
+    /// `key=`.
+    fn complete_kwargs(&self, line: &str) -> Option<(usize, Vec<String>)> {
+        let word_start = line
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + line[i..].chars().next().map_or(1, char::len_utf8));
+        let word = &line[word_start..];
+        if word.is_empty() {
+            return None;
+        }
+        // only directly after `(` or `,`; anywhere else the name is part of a
+        // larger expression (an attribute, an operand, ...)
+        if !line[..word_start].trim_end().ends_with(['(', ',']) {
+            return None;
+        }
+
+        let paren = open_call_paren(&line[..word_start])?;
+        let (_, words) = split_idents_on_dot(line[..paren].trim_end())?;
+        let obj = self.resolve_words(&words)?;
+        // unwrap bound methods so the receiver's parameter is hidden
+        let (func, skip_first) = match obj.get_attr("__func__", self.vm) {
+            Ok(func) => (func, true),
+            Err(_) => (obj, false),
+        };
+
+        let mut completions: Vec<String> = self
+            .keyword_params(&func, skip_first)?
+            .into_iter()
+            .filter(|name| name.starts_with(word))
+            .map(|name| format!("{name}="))
+            .collect();
+        completions.sort();
+        (!completions.is_empty()).then_some((word_start, completions))
+    }
+
+    /// Render the parameter list of a Python-level function from its code
+    /// object, e.g. `a, b, *args, key=, **kwargs` without the parentheses.
+    fn function_params(&self, func: &PyObjectRef, skip_first: bool) -> Option<String> {
+        const VARARGS: u32 = 0x04;
+        const VARKEYWORDS: u32 = 0x08;
+
+        let vm = self.vm;
+        let code = func.get_attr("__code__", vm).ok()?;
+        let argcount: usize = code
+            .get_attr("co_argcount", vm)
+            .ok()?
+            .try_to_value(vm)
+            .ok()?;
+        let kwonly: usize = code
+            .get_attr("co_kwonlyargcount", vm)
+            .ok()?
+            .try_to_value(vm)
+            .ok()?;
+        let flags: u32 = code.get_attr("co_flags", vm).ok()?.try_to_value(vm).ok()?;
+        let varnames = code.get_attr("co_varnames", vm).ok()?;
+        let varnames: Vec<String> = ArgIterable::<PyStrRef>::try_from_object(vm, varnames)
+            .ok()?
+            .iter(vm)
+            .ok()?
+            .flatten()
+            .map(|s| s.as_str().to_owned())
+            .collect();
+
+        let mut params = varnames.get(..argcount + kwonly)?.to_vec();
+        let mut next = argcount + kwonly;
+        if flags & VARARGS != 0 {
+            params.insert(argcount, format!("*{}", varnames.get(next)?));
+            next += 1;
+        } else if kwonly > 0 {
+            params.insert(argcount, "*".to_owned());
+        }
+        if flags & VARKEYWORDS != 0 {
+            params.push(format!("**{}", varnames.get(next)?));
+        }
+        Some(params.get(skip_first as usize..)?.join(", "))
+    }
+
+    /// A hint to show after a freshly-typed `(`: the callable's parameters
+    /// and the first line of its docstring.
+    fn signature_hint(&self, line: &str) -> Option<String> {
+        let vm = self.vm;
+        let (_, words) = split_idents_on_dot(line)?;
+        let obj = self.resolve_words(&words)?;
+
+        // unwrap bound methods so the receiver's parameter is hidden
+        let (func, skip_first) = match obj.get_attr("__func__", vm) {
+            Ok(func) => (func, true),
+            Err(_) => (obj, false),
+        };
+
+        let params = self.function_params(&func, skip_first);
+        let doc = func
+            .get_attr("__doc__", vm)
+            .ok()
+            .and_then(|doc| PyStrRef::try_from_object(vm, doc).ok())
+            .and_then(|doc| {
+                doc.as_str()
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(str::to_owned)
+            });
+
+        match (params, doc) {
+            (Some(params), Some(doc)) => Some(format!("{params})  # {doc}")),
+            (Some(params), None) => Some(format!("{params})")),
+            (None, Some(doc)) => Some(format!("  # {doc}")),
+            (None, None) => None,
+        }
+    }
+
+    /// Delegate to a completer registered through the `readline` module
+    /// (e.g. by `rlcompleter` or `cmd.Cmd`); when one is set it takes
+    /// precedence over the built-in completion.
+    fn complete_readline(&self, line: &str) -> Option<(usize, Vec<String>)> {
+        let vm = self.vm;
+        let state = &vm.state.readline_state;
+        let completer = state.completer.lock().clone()?;
+        let delims = state.completer_delims.lock().clone();
+        let word_start = line
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| delims.contains(c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+
+        // publish the word boundaries for get_line_buffer/get_begidx/get_endidx
+        *state.line_buffer.lock() = line.to_owned();
+        state.begidx.store(word_start);
+        state.endidx.store(line.len());
+
+        let word = &line[word_start..];
+        let mut completions = Vec::new();
+        // the completer protocol: called with increasing states until None
+        for state in 0..u16::MAX as usize {
+            let res = completer.call((word, state), vm).ok()?;
+            if vm.is_none(&res) {
+                break;
+            }
+            let res = PyStrRef::try_from_object(vm, res).ok()?;
+            completions.push(res.as_str().to_owned());
+        }
+        // a generic completer like rlcompleter knows nothing about dict keys,
+        // paths or import statements; when it comes up empty, fall through to
+        // the built-in completion instead of suppressing it
+        (!completions.is_empty()).then_some((word_start, completions))
+    }
+
     fn complete_opt(&self, line: &str) -> Option<(usize, Vec<String>)> {
+        if let Some(completions) = self.complete_readline(line) {
+            return Some(completions);
+        }
+        if let Some(completions) = self.complete_dict_key(line) {
+            return Some(completions);
+        }
+        if let Some(completions) = self.complete_path(line) {
+            return Some(completions);
+        }
+        if let Some(completions) = self.complete_import(line) {
+            return Some(completions);
+        }
+        if let Some(completions) = self.complete_kwargs(line) {
+            return Some(completions);
+        }
         let (startpos, words) = split_idents_on_dot(line)?;
 
         let (word_start, iter) = self.get_available_completions(&words)?;
@@ -146,11 +651,188 @@ impl<'vm> ShellHelper<'vm> {
     }
 }
 
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// A 1- or 2-letter run before a quote that makes it a prefixed string
+/// literal, e.g. `rb"..."`.
+fn is_string_prefix(word: &str) -> bool {
+    word.len() <= 2
+        && word
+            .chars()
+            .all(|c| matches!(c.to_ascii_lowercase(), 'r' | 'b' | 'f' | 'u'))
+}
+
+/// Scan a string literal starting at the opening quote `chars[i]`, returning
+/// the index just past the closing quote (or past the end/newline when
+/// unterminated).
+fn scan_string(chars: &[(usize, char)], i: usize) -> usize {
+    let quote = chars[i].1;
+    let at = |j: usize| chars.get(j).map(|&(_, c)| c);
+    let triple = at(i + 1) == Some(quote) && at(i + 2) == Some(quote);
+    let mut j = if triple { i + 3 } else { i + 1 };
+    while j < chars.len() {
+        match chars[j].1 {
+            '\\' => j += 2,
+            c if c == quote => {
+                if !triple {
+                    return j + 1;
+                } else if at(j + 1) == Some(quote) && at(j + 2) == Some(quote) {
+                    return j + 3;
+                }
+                j += 1;
+            }
+            '\n' if !triple => return j,
+            _ => j += 1,
+        }
+    }
+    j
+}
+
+/// Remove ANSI escape sequences (CSI and OSC), leaving only the characters
+/// that occupy terminal cells. The line editor measures the prompt it is
+/// handed to position the cursor, so a prompt that colors itself must be
+/// stripped first and re-applied for display via `highlight_prompt`.
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameter and intermediate bytes, then one final byte
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: terminated by BEL or ESC \
+            Some(']') => {
+                let mut esc = false;
+                for c in chars.by_ref() {
+                    if c == '\x07' || (esc && c == '\\') {
+                        break;
+                    }
+                    esc = c == '\x1b';
+                }
+            }
+            // an escape with intermediate bytes, e.g. ESC ( B
+            Some(c) if ('\x20'..='\x2f').contains(&c) => {
+                for c in chars.by_ref() {
+                    if !('\x20'..='\x2f').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // two-character escapes (or a bare trailing ESC)
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Rewrite a line of Python source with ANSI colors on keywords, strings,
+/// numbers and comments. Keeps every source character intact so the cursor
+/// position is unaffected.
+fn highlight_source(line: &str) -> String {
+    const RESET: &str = "\x1b[0m";
+    const KEYWORD: &str = "\x1b[1;34m";
+    const STRING: &str = "\x1b[32m";
+    const NUMBER: &str = "\x1b[33m";
+    const COMMENT: &str = "\x1b[2m";
+
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let byte_at = |j: usize| chars.get(j).map_or(line.len(), |&(pos, _)| pos);
+    let mut out = String::with_capacity(line.len() + 16);
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        match c {
+            '#' => {
+                let mut j = i;
+                while j < chars.len() && chars[j].1 != '\n' {
+                    j += 1;
+                }
+                out.push_str(COMMENT);
+                out.push_str(&line[start..byte_at(j)]);
+                out.push_str(RESET);
+                i = j;
+            }
+            '"' | '\'' => {
+                let j = scan_string(&chars, i);
+                out.push_str(STRING);
+                out.push_str(&line[start..byte_at(j)]);
+                out.push_str(RESET);
+                i = j;
+            }
+            c if c.is_ascii_digit() => {
+                let mut j = i + 1;
+                while j < chars.len() {
+                    let c = chars[j].1;
+                    if c.is_alphanumeric() || c == '_' || c == '.' {
+                        j += 1;
+                    } else if matches!(c, '+' | '-')
+                        && matches!(chars[j - 1].1, 'e' | 'E')
+                        && chars.get(j + 1).is_some_and(|&(_, c)| c.is_ascii_digit())
+                    {
+                        j += 1;
+                    } else {
+                        break;
+                    }
+                }
+                out.push_str(NUMBER);
+                out.push_str(&line[start..byte_at(j)]);
+                out.push_str(RESET);
+                i = j;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut j = i + 1;
+                while j < chars.len() && (chars[j].1.is_alphanumeric() || chars[j].1 == '_') {
+                    j += 1;
+                }
+                let word = &line[start..byte_at(j)];
+                if chars.get(j).is_some_and(|&(_, c)| matches!(c, '"' | '\''))
+                    && is_string_prefix(word)
+                {
+                    // a string literal with its prefix, e.g. rb"..."
+                    let j = scan_string(&chars, j);
+                    out.push_str(STRING);
+                    out.push_str(&line[start..byte_at(j)]);
+                    out.push_str(RESET);
+                    i = j;
+                } else {
+                    if KEYWORDS.contains(&word) {
+                        out.push_str(KEYWORD);
+                        out.push_str(word);
+                        out.push_str(RESET);
+                    } else {
+                        out.push_str(word);
+                    }
+                    i = j;
+                }
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
 cfg_if::cfg_if! {
     if #[cfg(not(target_arch = "wasm32"))] {
         use rustyline::{
-            completion::Completer, highlight::Highlighter, hint::Hinter, validate::Validator, Context,
-            Helper,
+            completion::Completer, highlight::{CmdKind, Highlighter}, hint::Hinter,
+            validate::Validator, Context, Helper,
         };
         impl Completer for ShellHelper<'_> {
             type Candidate = String;
@@ -171,8 +853,49 @@ cfg_if::cfg_if! {
 
         impl Hinter for ShellHelper<'_> {
             type Hint = String;
+
+            fn hint(&self, line: &str, pos: usize, _ctx: &Context) -> Option<String> {
+                if pos != line.len() || !line.ends_with('(') {
+                    return None;
+                }
+                self.signature_hint(&line[..line.len() - 1])
+            }
+        }
+        impl Highlighter for ShellHelper<'_> {
+            fn highlight_prompt<'b, 's: 'b, 'p: 'b>(
+                &'s self,
+                prompt: &'p str,
+                default: bool,
+            ) -> std::borrow::Cow<'b, str> {
+                if default {
+                    if let Some(colored) = self.prompt_display.borrow().as_ref() {
+                        return std::borrow::Cow::Owned(colored.clone());
+                    }
+                }
+                std::borrow::Cow::Borrowed(prompt)
+            }
+
+            fn highlight<'l>(&self, line: &'l str, _pos: usize) -> std::borrow::Cow<'l, str> {
+                if self.highlight {
+                    std::borrow::Cow::Owned(highlight_source(line))
+                } else {
+                    std::borrow::Cow::Borrowed(line)
+                }
+            }
+
+            fn highlight_hint<'h>(&self, hint: &'h str) -> std::borrow::Cow<'h, str> {
+                if self.highlight {
+                    // render signature hints dimmed, like comments
+                    std::borrow::Cow::Owned(format!("\x1b[2m{hint}\x1b[0m"))
+                } else {
+                    std::borrow::Cow::Borrowed(hint)
+                }
+            }
+
+            fn highlight_char(&self, line: &str, _pos: usize, _kind: CmdKind) -> bool {
+                self.highlight && !line.is_empty()
+            }
         }
-        impl Highlighter for ShellHelper<'_> {}
         impl Validator for ShellHelper<'_> {}
         impl Helper for ShellHelper<'_> {}
     }
@@ -84,7 +84,7 @@ mod array {
                     match c {
                         $($c => Ok(ArrayContentType::$n(Vec::new())),)*
                         _ => Err(
-                            "bad typecode (must be b, B, u, h, H, i, I, l, L, q, Q, f or d)".into()
+                            "bad typecode (must be b, B, u, w, h, H, i, I, l, L, q, Q, f or d)".into()
                         ),
                     }
                 }
@@ -490,6 +490,7 @@ mod array {
         (SignedByte, i8, 'b', "b"),
         (UnsignedByte, u8, 'B', "B"),
         (PyUnicode, WideChar, 'u', "u"),
+        (PyUnicodeUcs4, Ucs4Char, 'w', "w"),
         (SignedShort, raw::c_short, 'h', "h"),
         (UnsignedShort, raw::c_ushort, 'H', "H"),
         (SignedInt, raw::c_int, 'i', "i"),
@@ -505,6 +506,11 @@ mod array {
     #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
     pub struct WideChar(wchar_t);
 
+    /// Element of a `'w'` array: always a 4-byte UCS-4 unit, unlike `'u'`
+    /// whose width follows the platform `wchar_t`.
+    #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+    pub struct Ucs4Char(u32);
+
     trait ArrayElement: Sized {
         fn try_into_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self>;
         fn byteswap(self) -> Self;
@@ -591,6 +597,45 @@ mod array {
         }
     }
 
+    impl ArrayElement for Ucs4Char {
+        fn try_into_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+            PyStrRef::try_from_object(vm, obj)?
+                .as_str()
+                .chars()
+                .exactly_one()
+                .map(|ch| Self(ch as _))
+                .map_err(|_| vm.new_type_error("array item must be unicode character".into()))
+        }
+        fn byteswap(self) -> Self {
+            Self(self.0.swap_bytes())
+        }
+        fn to_object(self, _vm: &VirtualMachine) -> PyObjectRef {
+            unreachable!()
+        }
+    }
+
+    impl TryFrom<Ucs4Char> for CodePoint {
+        type Error = String;
+
+        fn try_from(ch: Ucs4Char) -> Result<Self, Self::Error> {
+            u32_to_char(ch.0)
+        }
+    }
+
+    impl ToPyResult for Ucs4Char {
+        fn to_pyresult(self, vm: &VirtualMachine) -> PyResult {
+            Ok(CodePoint::try_from(self)
+                .map_err(|e| vm.new_unicode_encode_error(e))?
+                .to_pyobject(vm))
+        }
+    }
+
+    impl fmt::Display for Ucs4Char {
+        fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            unreachable!("`repr(array('w'))` calls `PyStr::repr`")
+        }
+    }
+
     fn u32_to_char(ch: u32) -> Result<CodePoint, String> {
         CodePoint::from_u32(ch)
             .ok_or_else(|| format!("character U+{ch:4x} is not in range [U+0000; U+10ffff]"))
@@ -674,7 +719,7 @@ mod array {
                 if let Some(init) = init.payload::<PyArray>() {
                     match (spec, init.read().typecode()) {
                         (spec, ch) if spec == ch => array.frombytes(&init.get_bytes()),
-                        (spec, 'u') => {
+                        (spec, 'u' | 'w') => {
                             return Err(vm.new_type_error(format!(
                             "cannot use a unicode array to initialize an array with typecode '{spec}'"
                         )))
@@ -686,7 +731,7 @@ mod array {
                         }
                     }
                 } else if let Some(wtf8) = init.payload::<PyStr>() {
-                    if spec == 'u' {
+                    if matches!(spec, 'u' | 'w') {
                         let bytes = Self::_unicode_to_wchar_bytes(wtf8.as_wtf8(), array.itemsize());
                         array.frombytes_move(bytes);
                     } else {
@@ -831,7 +876,7 @@ mod array {
                     obj.class().name()
                 ))
             })?;
-            if zelf.read().typecode() != 'u' {
+            if !matches!(zelf.read().typecode(), 'u' | 'w') {
                 return Err(vm.new_value_error(
                     "fromunicode() may only be called on unicode type arrays".into(),
                 ));
@@ -845,7 +890,7 @@ mod array {
         #[pymethod]
         fn tounicode(&self, vm: &VirtualMachine) -> PyResult<Wtf8Buf> {
             let array = self.array.read();
-            if array.typecode() != 'u' {
+            if !matches!(array.typecode(), 'u' | 'w') {
                 return Err(vm.new_value_error(
                     "tounicode() may only be called on unicode type arrays".into(),
                 ));
@@ -1171,7 +1216,7 @@ mod array {
             let array = zelf.read();
             let cls = zelf.class().to_owned();
             let typecode = vm.ctx.new_str(array.typecode_str());
-            let values = if array.typecode() == 'u' {
+            let values = if matches!(array.typecode(), 'u' | 'w') {
                 let s = Self::_wchar_bytes_to_string(array.get_bytes(), array.itemsize(), vm)?;
                 s.code_points().map(|x| x.to_pyobject(vm)).collect()
             } else {
@@ -1277,13 +1322,19 @@ mod array {
         fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
             let class = zelf.class();
             let class_name = class.name();
-            if zelf.read().typecode() == 'u' {
+            let typecode = zelf.read().typecode();
+            if matches!(typecode, 'u' | 'w') {
                 if zelf.len() == 0 {
-                    return Ok(format!("{class_name}('u')"));
+                    return Ok(format!("{class_name}('{typecode}')"));
                 }
                 let to_unicode = zelf.tounicode(vm)?;
                 let escape = crate::vm::literal::escape::UnicodeEscape::new_repr(&to_unicode);
-                return Ok(format!("{}('u', {})", class_name, escape.str_repr()));
+                return Ok(format!(
+                    "{}('{}', {})",
+                    class_name,
+                    typecode,
+                    escape.str_repr()
+                ));
             }
             zelf.read().repr(&class_name, vm)
         }
@@ -1519,6 +1570,7 @@ mod array {
                         _ => None,
                     };
                 }
+                'w' => return Some(Self::Utf32 { big_endian }),
                 'f' => {
                     // Copied from CPython
                     const Y: f32 = 16711938.0;
@@ -0,0 +1,86 @@
+// spell-checker:ignore b85encode b85decode
+
+pub(crate) use _base85::make_module;
+
+#[pymodule]
+mod _base85 {
+    use crate::vm::{PyResult, VirtualMachine, function::ArgBytesLike};
+
+    /// The base85 alphabet used by git and Mercurial, matching
+    /// `base64._b85alphabet`.
+    const ALPHABET: &[u8; 85] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+        abcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+    const INVALID: u8 = 0xff;
+
+    const fn decode_table() -> [u8; 256] {
+        let mut table = [INVALID; 256];
+        let mut i = 0;
+        while i < ALPHABET.len() {
+            table[ALPHABET[i] as usize] = i as u8;
+            i += 1;
+        }
+        table
+    }
+
+    const DECODE: [u8; 256] = decode_table();
+
+    #[pyfunction]
+    fn b85encode(data: ArgBytesLike, pad: bool) -> Vec<u8> {
+        data.with_ref(|bytes| {
+            let padding = (4 - bytes.len() % 4) % 4;
+            let mut out = Vec::with_capacity((bytes.len() + padding) / 4 * 5);
+            for chunk in bytes.chunks(4) {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                let mut acc = u32::from_be_bytes(word);
+                let mut group = [0u8; 5];
+                for slot in group.iter_mut().rev() {
+                    *slot = ALPHABET[(acc % 85) as usize];
+                    acc /= 85;
+                }
+                out.extend_from_slice(&group);
+            }
+            if padding != 0 && !pad {
+                out.truncate(out.len() - padding);
+            }
+            out
+        })
+    }
+
+    #[pyfunction]
+    fn b85decode(data: ArgBytesLike, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
+        data.with_ref(|bytes| {
+            let padding = (5 - bytes.len() % 5) % 5;
+            let mut out = Vec::with_capacity((bytes.len() + padding) / 5 * 4);
+            for (i, chunk) in bytes.chunks(5).enumerate() {
+                let start = i * 5;
+                // short final chunks are implicitly padded with b'~', like
+                // the pure-python base64.b85decode
+                let mut group = [b'~'; 5];
+                group[..chunk.len()].copy_from_slice(chunk);
+                let mut acc: u32 = 0;
+                for (j, &c) in group.iter().enumerate() {
+                    let digit = DECODE[c as usize];
+                    if digit == INVALID {
+                        return Err(vm.new_value_error(format!(
+                            "bad base85 character at position {}",
+                            start + j
+                        )));
+                    }
+                    acc = acc
+                        .checked_mul(85)
+                        .and_then(|acc| acc.checked_add(digit.into()))
+                        .ok_or_else(|| {
+                            vm.new_value_error(format!(
+                                "base85 overflow in hunk starting at byte {start}"
+                            ))
+                        })?;
+                }
+                out.extend_from_slice(&acc.to_be_bytes());
+            }
+            out.truncate(out.len() - padding);
+            Ok(out)
+        })
+    }
+}
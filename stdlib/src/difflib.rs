@@ -0,0 +1,54 @@
+pub(crate) use _difflib::make_module;
+
+#[pymodule]
+mod _difflib {
+    use crate::vm::{
+        PyObjectRef, PyResult, VirtualMachine, builtins::PyDictRef, function::ArgIterable,
+    };
+    use std::collections::HashMap;
+
+    /// Core loop of `SequenceMatcher.find_longest_match`: find the longest
+    /// junk-free match in `a[alo:ahi]` and `b[blo:bhi]`, given the `b2j`
+    /// mapping the caller maintains (element -> sorted list of indices in
+    /// `b`, with junk and popular elements already removed). The junk
+    /// extension passes stay in Python; this only replaces the O(n*m)
+    /// inner loop. Returns `(i, j, size)`.
+    #[pyfunction]
+    fn find_longest_match(
+        a: ArgIterable,
+        b2j: PyDictRef,
+        alo: usize,
+        ahi: usize,
+        blo: usize,
+        bhi: usize,
+        vm: &VirtualMachine,
+    ) -> PyResult<(usize, usize, usize)> {
+        let a: Vec<PyObjectRef> = a.iter(vm)?.collect::<PyResult<_>>()?;
+        let (mut besti, mut bestj, mut bestsize) = (alo, blo, 0);
+        // during an iteration, j2len[j] = length of the longest junk-free
+        // match ending with a[i-1] and b[j]
+        let mut j2len: HashMap<usize, usize> = HashMap::new();
+        for (i, elem) in a.iter().enumerate().take(ahi).skip(alo) {
+            let mut new_j2len = HashMap::new();
+            if let Some(js) = b2j.get_item_opt(&**elem, vm)? {
+                let js: Vec<usize> = js.try_to_value(vm)?;
+                for j in js {
+                    // a[i] matches b[j]
+                    if j < blo {
+                        continue;
+                    }
+                    if j >= bhi {
+                        break;
+                    }
+                    let k = j2len.get(&j.wrapping_sub(1)).copied().unwrap_or(0) + 1;
+                    new_j2len.insert(j, k);
+                    if k > bestsize {
+                        (besti, bestj, bestsize) = (i + 1 - k, j + 1 - k, k);
+                    }
+                }
+            }
+            j2len = new_j2len;
+        }
+        Ok((besti, bestj, bestsize))
+    }
+}
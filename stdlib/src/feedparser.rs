@@ -0,0 +1,24 @@
+pub(crate) use _feedparser::make_module;
+
+#[pymodule]
+mod _feedparser {
+    use crate::vm::builtins::PyStrRef;
+
+    /// Check whether a line can start or continue an RFC 2822 header block,
+    /// equivalent to `email.feedparser.headerRE`: a unix-from line, a
+    /// continuation line, or a (possibly empty) run of field-name characters
+    /// followed by a colon.
+    #[pyfunction]
+    fn is_header_line(line: PyStrRef) -> bool {
+        let bytes = line.as_wtf8().as_bytes();
+        if bytes.starts_with(b"From ") || matches!(bytes.first(), Some(b'\t' | b' ')) {
+            return true;
+        }
+        // ftext is %d33-57 / %d59-126: any printable ASCII except ":"
+        let run = bytes
+            .iter()
+            .take_while(|&&c| matches!(c, 0x21..=0x39 | 0x3b..=0x7e))
+            .count();
+        bytes.get(run) == Some(&b':')
+    }
+}
@@ -0,0 +1,141 @@
+pub(crate) use _fnmatch::make_module;
+
+#[pymodule]
+mod _fnmatch {
+    use crate::common::wtf8::CodePoint;
+    use crate::vm::{
+        PyResult, VirtualMachine,
+        builtins::PyStrRef,
+        function::ArgIterable,
+    };
+
+    /// Match one `[...]` character class starting at `pat[start]` (which is
+    /// known to be `[`). Returns `None` if there is no closing `]`, in which
+    /// case the `[` must be treated as a literal character, otherwise
+    /// `(matched, index just past the class)`.
+    fn match_class(pat: &[CodePoint], start: usize, c: CodePoint) -> Option<(bool, usize)> {
+        let mut j = start + 1;
+        let negated = pat.get(j).is_some_and(|&p| p == '!');
+        if negated {
+            j += 1;
+        }
+        let mut k = j;
+        // a `]` right at the start of the set is a literal member
+        if pat.get(k).is_some_and(|&p| p == ']') {
+            k += 1;
+        }
+        while k < pat.len() && pat[k] != ']' {
+            k += 1;
+        }
+        if k >= pat.len() {
+            return None;
+        }
+        let set = &pat[j..k];
+        let c = c.to_u32();
+        let mut matched = false;
+        let mut i = 0;
+        while i < set.len() {
+            if i + 2 < set.len() && set[i + 1] == '-' {
+                // `a-b` range; a reversed range matches nothing
+                let (lo, hi) = (set[i].to_u32(), set[i + 2].to_u32());
+                if lo <= c && c <= hi {
+                    matched = true;
+                }
+                i += 3;
+            } else {
+                if set[i].to_u32() == c {
+                    matched = true;
+                }
+                i += 1;
+            }
+        }
+        Some((matched != negated, k + 1))
+    }
+
+    /// Shell-style pattern match over code points, without compiling the
+    /// pattern to a regex. Iterative with a single backtrack point per `*`,
+    /// so it runs in O(name * pattern) worst case and O(name + pattern)
+    /// for typical patterns.
+    fn matches(name: &[CodePoint], pat: &[CodePoint]) -> bool {
+        let (mut n, mut p) = (0, 0);
+        // position to resume from on mismatch: (pattern index after the
+        // most recent `*`, name index that `*` should next absorb)
+        let mut star: Option<(usize, usize)> = None;
+        while n < name.len() {
+            if p < pat.len() {
+                match pat[p].to_char() {
+                    Some('*') => {
+                        star = Some((p + 1, n));
+                        p += 1;
+                        continue;
+                    }
+                    Some('?') => {
+                        p += 1;
+                        n += 1;
+                        continue;
+                    }
+                    Some('[') => {
+                        if let Some((matched, next_p)) = match_class(pat, p, name[n]) {
+                            if matched {
+                                p = next_p;
+                                n += 1;
+                                continue;
+                            }
+                        } else if name[n] == '[' {
+                            // unterminated class: literal `[`
+                            p += 1;
+                            n += 1;
+                            continue;
+                        }
+                    }
+                    _ => {
+                        if pat[p] == name[n] {
+                            p += 1;
+                            n += 1;
+                            continue;
+                        }
+                    }
+                }
+            }
+            // mismatch: let the last `*` absorb one more character
+            match star {
+                Some((sp, sn)) if sn < name.len() => {
+                    p = sp;
+                    n = sn + 1;
+                    star = Some((sp, sn + 1));
+                }
+                _ => return false,
+            }
+        }
+        // trailing `*`s match the empty string
+        while p < pat.len() && pat[p] == '*' {
+            p += 1;
+        }
+        p == pat.len()
+    }
+
+    #[pyfunction]
+    fn fnmatchcase(name: PyStrRef, pat: PyStrRef) -> bool {
+        let name: Vec<CodePoint> = name.as_wtf8().code_points().collect();
+        let pat: Vec<CodePoint> = pat.as_wtf8().code_points().collect();
+        matches(&name, &pat)
+    }
+
+    #[pyfunction]
+    fn filter(
+        names: ArgIterable<PyStrRef>,
+        pat: PyStrRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<PyStrRef>> {
+        let pat: Vec<CodePoint> = pat.as_wtf8().code_points().collect();
+        let mut result = Vec::new();
+        for name in names.iter(vm)? {
+            let name = name?;
+            let chars: Vec<CodePoint> = name.as_wtf8().code_points().collect();
+            if matches(&chars, &pat) {
+                result.push(name);
+            }
+        }
+        Ok(result)
+    }
+}
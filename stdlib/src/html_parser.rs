@@ -0,0 +1,61 @@
+pub(crate) use _html_parser::make_module;
+
+#[pymodule]
+mod _html_parser {
+    use crate::vm::builtins::PyStrRef;
+
+    // whitespace as matched by `\s` against ASCII text
+    const fn is_space(c: u8) -> bool {
+        matches!(c, b'\t' | b'\n' | b'\x0b' | b'\x0c' | b'\r' | b' ' | b'\x1c'..=b'\x1f')
+    }
+
+    /// Find the position of the next token boundary in HTML source, starting
+    /// at `start`: the next `<` or `&` in normal mode, or the closing
+    /// `</cdata_elem>` tag (with optional interior whitespace, any case) when
+    /// inside a CDATA element. Returns -1 when there is no boundary, and None
+    /// for non-ASCII input so the caller can fall back to its regexes.
+    #[pyfunction]
+    fn find_interesting(
+        rawdata: PyStrRef,
+        start: usize,
+        cdata_elem: Option<PyStrRef>,
+    ) -> Option<isize> {
+        let bytes = rawdata.as_wtf8().as_bytes();
+        if !bytes.is_ascii() || start > bytes.len() {
+            return None;
+        }
+        let found = match cdata_elem {
+            None => bytes[start..]
+                .iter()
+                .position(|&c| c == b'<' || c == b'&')
+                .map(|p| start + p),
+            Some(elem) => {
+                let elem = elem.as_wtf8().as_bytes();
+                let mut i = start;
+                loop {
+                    let Some(p) = bytes[i..].windows(2).position(|w| w == b"</") else {
+                        break None;
+                    };
+                    let p = i + p;
+                    let mut k = p + 2;
+                    while bytes.get(k).is_some_and(|&c| is_space(c)) {
+                        k += 1;
+                    }
+                    let tag_matches = bytes[k..].len() >= elem.len()
+                        && bytes[k..k + elem.len()].eq_ignore_ascii_case(elem);
+                    if tag_matches {
+                        let mut m = k + elem.len();
+                        while bytes.get(m).is_some_and(|&c| is_space(c)) {
+                            m += 1;
+                        }
+                        if bytes.get(m) == Some(&b'>') {
+                            break Some(p);
+                        }
+                    }
+                    i = p + 1;
+                }
+            }
+        };
+        Some(found.map_or(-1, |p| p as isize))
+    }
+}
@@ -0,0 +1,47 @@
+pub(crate) use _http_client::make_module;
+
+#[pymodule]
+mod _http_client {
+    use crate::vm::{PyResult, VirtualMachine, builtins::PyBytesRef, function::ArgIterable};
+
+    /// Parse pre-read header lines into `(name, value)` pairs, decoded with
+    /// latin-1 like `email.parser` does. Returns None when the headers use
+    /// obs-fold continuation lines or are otherwise unusual, so the caller
+    /// can fall back to the full `email.parser` machinery.
+    #[pyfunction]
+    fn parse_header_lines(
+        lines: ArgIterable<PyBytesRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<Option<Vec<(String, String)>>> {
+        let latin1 = |bytes: &[u8]| bytes.iter().map(|&c| c as char).collect::<String>();
+        let mut headers = Vec::new();
+        for line in lines.iter(vm)? {
+            let line = line?;
+            let line = match line.as_bytes() {
+                [rest @ .., b'\r', b'\n'] | [rest @ .., b'\n'] => rest,
+                rest => rest,
+            };
+            if line.is_empty() {
+                // the terminating blank line
+                continue;
+            }
+            if line[0] == b' ' || line[0] == b'\t' {
+                // obs-fold continuation line
+                return Ok(None);
+            }
+            let Some(colon) = line.iter().position(|&c| c == b':') else {
+                return Ok(None);
+            };
+            let (name, value) = line.split_at(colon);
+            if name.is_empty() || !name.iter().all(|&c| (33..127).contains(&c)) {
+                return Ok(None);
+            }
+            let mut value = &value[1..];
+            while let [b' ' | b'\t', rest @ ..] = value {
+                value = rest;
+            }
+            headers.push((latin1(name), latin1(value)));
+        }
+        Ok(Some(headers))
+    }
+}
@@ -0,0 +1,39 @@
+pub(crate) use _ipaddress::make_module;
+
+#[pymodule]
+mod _ipaddress {
+    use crate::vm::builtins::PyStrRef;
+
+    /// Parse a dotted-quad IPv4 address with the same strict rules as
+    /// `ipaddress._BaseV4._ip_int_from_string`: exactly four decimal octets,
+    /// at most three digits each, no leading zeros, each in range 0..=255.
+    /// Returns None for unparseable input so the Python caller can fall back
+    /// to the pure-Python path for its detailed error messages.
+    #[pyfunction]
+    fn v4_int_from_string(ip_str: PyStrRef) -> Option<u32> {
+        let bytes = ip_str.as_wtf8().as_bytes();
+        let mut ip: u32 = 0;
+        let mut octets = 0;
+        for octet in bytes.split(|&c| c == b'.') {
+            if octets == 4 || octet.is_empty() || octet.len() > 3 {
+                return None;
+            }
+            if !octet.iter().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            // leading zeros are rejected, as strict as glibc's inet_pton()
+            if octet.len() > 1 && octet[0] == b'0' {
+                return None;
+            }
+            let value = octet
+                .iter()
+                .fold(0u32, |acc, &c| acc * 10 + u32::from(c - b'0'));
+            if value > 255 {
+                return None;
+            }
+            ip = (ip << 8) | value;
+            octets += 1;
+        }
+        (octets == 4).then_some(ip)
+    }
+}
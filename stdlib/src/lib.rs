@@ -8,13 +8,20 @@
 extern crate rustpython_derive;
 
 pub mod array;
+mod base85;
 mod binascii;
 mod bisect;
 mod cmath;
 mod contextvars;
 mod csv;
+mod difflib;
 mod dis;
+mod feedparser;
+mod fnmatch;
 mod gc;
+mod html_parser;
+mod http_client;
+mod ipaddress;
 
 mod bz2;
 mod compression; // internal module
@@ -36,11 +43,15 @@ mod locale;
 mod math;
 #[cfg(unix)]
 mod mmap;
+mod ndarray;
+mod pprint;
 mod pyexpat;
 mod pystruct;
 mod random;
+mod shlex;
 mod statistics;
 mod suggestions;
+mod textwrap;
 // TODO: maybe make this an extension module, if we ever get those
 // mod re;
 #[cfg(not(target_arch = "wasm32"))]
@@ -114,16 +125,23 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
         #[cfg(all())]
         {
             "array" => array::make_module,
+            "_base85" => base85::make_module,
             "binascii" => binascii::make_module,
             "_bisect" => bisect::make_module,
             "_bz2" => bz2::make_module,
             "cmath" => cmath::make_module,
             "_contextvars" => contextvars::make_module,
             "_csv" => csv::make_module,
+            "_difflib" => difflib::make_module,
             "_dis" => dis::make_module,
             "faulthandler" => faulthandler::make_module,
+            "_feedparser" => feedparser::make_module,
+            "_fnmatch" => fnmatch::make_module,
             "gc" => gc::make_module,
             "_hashlib" => hashlib::make_module,
+            "_html_parser" => html_parser::make_module,
+            "_http_client" => http_client::make_module,
+            "_ipaddress" => ipaddress::make_module,
             "_sha1" => sha1::make_module,
             "_sha3" => sha3::make_module,
             "_sha256" => sha256::make_module,
@@ -132,10 +150,14 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
             "_blake2" => blake2::make_module,
             "_json" => json::make_module,
             "math" => math::make_module,
+            "_ndarray" => ndarray::make_module,
+            "_pprint" => pprint::make_module,
             "pyexpat" => pyexpat::make_module,
             "_random" => random::make_module,
+            "_shlex" => shlex::make_module,
             "_statistics" => statistics::make_module,
             "_struct" => pystruct::make_module,
+            "_textwrap" => textwrap::make_module,
             "unicodedata" => unicodedata::make_module,
             "zlib" => zlib::make_module,
             "_statistics" => statistics::make_module,
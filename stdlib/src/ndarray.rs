@@ -0,0 +1,824 @@
+pub(crate) use _ndarray::make_module;
+
+/// A minimal N-dimensional typed array: C-contiguous storage, `float64` and
+/// `int64` dtypes, elementwise arithmetic, basic indexing/slicing and the
+/// buffer protocol. A stopgap for numeric users until real numpy support
+/// exists, and an interop target for embedders' data.
+#[pymodule]
+mod _ndarray {
+    use crate::{
+        common::{
+            atomic::{self, AtomicUsize},
+            lock::{
+                PyMappedRwLockReadGuard, PyMappedRwLockWriteGuard, PyRwLock, PyRwLockReadGuard,
+                PyRwLockWriteGuard,
+            },
+        },
+        vm::{
+            AsObject, Py, PyObject, PyObjectRef, PyPayload, PyResult, VirtualMachine,
+            atomic_func,
+            builtins::{PyFloat, PyInt, PyListRef, PyStrRef, PyTuple, PyTupleRef},
+            convert::TryFromObject,
+            function::{ArgIterable, OptionalArg},
+            protocol::{BufferDescriptor, BufferMethods, PyBuffer, PyMappingMethods},
+            sliceable::{SequenceIndex, SequenceIndexOp},
+            types::{AsBuffer, AsMapping, Representable},
+        },
+    };
+    use num_traits::ToPrimitive;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum Dtype {
+        Float64,
+        Int64,
+    }
+
+    impl Dtype {
+        /// Both dtypes are 8 bytes wide, which keeps all offset arithmetic
+        /// a plain element-index multiply.
+        const ITEMSIZE: usize = 8;
+
+        fn parse(name: &str, vm: &VirtualMachine) -> PyResult<Self> {
+            match name {
+                "float64" | "d" | "f8" => Ok(Dtype::Float64),
+                "int64" | "q" | "i8" => Ok(Dtype::Int64),
+                _ => Err(vm.new_value_error(format!("unsupported dtype: '{name}'"))),
+            }
+        }
+
+        fn name(self) -> &'static str {
+            match self {
+                Dtype::Float64 => "float64",
+                Dtype::Int64 => "int64",
+            }
+        }
+
+        /// The struct-module format character, used for the buffer protocol.
+        fn typecode(self) -> &'static str {
+            match self {
+                Dtype::Float64 => "d",
+                Dtype::Int64 => "q",
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    enum Scalar {
+        F64(f64),
+        I64(i64),
+    }
+
+    impl Scalar {
+        fn as_f64(self) -> f64 {
+            match self {
+                Scalar::F64(x) => x,
+                Scalar::I64(x) => x as f64,
+            }
+        }
+
+        fn to_pyobject(self, vm: &VirtualMachine) -> PyObjectRef {
+            match self {
+                Scalar::F64(x) => vm.ctx.new_float(x).into(),
+                Scalar::I64(x) => vm.ctx.new_int(x).into(),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct ArrayInner {
+        shape: Vec<usize>,
+        dtype: Dtype,
+        data: Vec<u8>,
+    }
+
+    impl ArrayInner {
+        fn zeros(shape: Vec<usize>, dtype: Dtype) -> Self {
+            let size: usize = shape.iter().product();
+            ArrayInner {
+                shape,
+                dtype,
+                data: vec![0; size * Dtype::ITEMSIZE],
+            }
+        }
+
+        fn size(&self) -> usize {
+            self.shape.iter().product()
+        }
+
+        /// Element (not byte) strides for each dimension, in C order.
+        fn elem_strides(&self) -> Vec<usize> {
+            let mut strides = vec![1; self.shape.len()];
+            for i in (0..self.shape.len().saturating_sub(1)).rev() {
+                strides[i] = strides[i + 1] * self.shape[i + 1];
+            }
+            strides
+        }
+
+        fn get(&self, i: usize) -> Scalar {
+            let chunk = self.data[i * Dtype::ITEMSIZE..][..Dtype::ITEMSIZE]
+                .try_into()
+                .unwrap();
+            match self.dtype {
+                Dtype::Float64 => Scalar::F64(f64::from_ne_bytes(chunk)),
+                Dtype::Int64 => Scalar::I64(i64::from_ne_bytes(chunk)),
+            }
+        }
+
+        fn set(&mut self, i: usize, value: Scalar) {
+            let bytes = match (self.dtype, value) {
+                (Dtype::Float64, value) => value.as_f64().to_ne_bytes(),
+                (Dtype::Int64, Scalar::I64(x)) => x.to_ne_bytes(),
+                (Dtype::Int64, Scalar::F64(x)) => (x as i64).to_ne_bytes(),
+            };
+            self.data[i * Dtype::ITEMSIZE..][..Dtype::ITEMSIZE].copy_from_slice(&bytes);
+        }
+
+        /// Convert a Python number to this array's dtype; integer arrays
+        /// reject floats the way `int` indices do, rather than truncating.
+        fn scalar_from_obj(&self, obj: &PyObject, vm: &VirtualMachine) -> PyResult<Scalar> {
+            match self.dtype {
+                Dtype::Float64 => Ok(Scalar::F64(obj.try_float(vm)?.to_f64())),
+                Dtype::Int64 => Ok(Scalar::I64(obj.try_index(vm)?.try_to_primitive(vm)?)),
+            }
+        }
+    }
+
+    /// One resolved dimension of an index expression: a single position
+    /// (which drops the dimension) or a list of positions (which keeps it).
+    enum Sel {
+        Int(usize),
+        Slice(Vec<usize>),
+    }
+
+    #[pyattr]
+    #[pyclass(name = "ndarray")]
+    #[derive(Debug, PyPayload)]
+    pub struct PyNdarray {
+        inner: PyRwLock<ArrayInner>,
+        exports: AtomicUsize,
+    }
+
+    impl From<ArrayInner> for PyNdarray {
+        fn from(inner: ArrayInner) -> Self {
+            PyNdarray {
+                inner: PyRwLock::new(inner),
+                exports: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl PyNdarray {
+        fn read(&self) -> PyRwLockReadGuard<'_, ArrayInner> {
+            self.inner.read()
+        }
+
+        fn get_bytes(&self) -> PyMappedRwLockReadGuard<'_, [u8]> {
+            PyRwLockReadGuard::map(self.inner.read(), |inner| inner.data.as_slice())
+        }
+
+        fn get_bytes_mut(&self) -> PyMappedRwLockWriteGuard<'_, [u8]> {
+            PyRwLockWriteGuard::map(self.inner.write(), |inner| inner.data.as_mut_slice())
+        }
+
+        /// Resolve `needle` (an int, slice or tuple thereof) against `shape`,
+        /// padding unmentioned trailing dimensions with full slices.
+        fn resolve_index(
+            shape: &[usize],
+            needle: &PyObject,
+            vm: &VirtualMachine,
+        ) -> PyResult<Vec<Sel>> {
+            let items: Vec<SequenceIndex> = match needle.downcast_ref::<PyTuple>() {
+                Some(tuple) => tuple
+                    .iter()
+                    .map(|elem| SequenceIndex::try_from_borrowed_object(vm, elem, "ndarray"))
+                    .collect::<PyResult<_>>()?,
+                None => vec![SequenceIndex::try_from_borrowed_object(vm, needle, "ndarray")?],
+            };
+            if items.len() > shape.len() {
+                return Err(vm.new_index_error(format!(
+                    "too many indices for array: array is {}-dimensional, but {} were indexed",
+                    shape.len(),
+                    items.len()
+                )));
+            }
+            let mut sels = Vec::with_capacity(shape.len());
+            for (axis, item) in items.into_iter().enumerate() {
+                let dim = shape[axis];
+                let sel = match item {
+                    SequenceIndex::Int(i) => Sel::Int(i.wrapped_at(dim).ok_or_else(|| {
+                        vm.new_index_error(format!(
+                            "index {i} is out of bounds for axis {axis} with size {dim}"
+                        ))
+                    })?),
+                    SequenceIndex::Slice(slice) => Sel::Slice(slice.iter(dim).collect()),
+                };
+                sels.push(sel);
+            }
+            for &dim in &shape[sels.len()..] {
+                sels.push(Sel::Slice((0..dim).collect()));
+            }
+            Ok(sels)
+        }
+
+        /// Visit the flat element index of every selected element, in C
+        /// order.
+        fn for_each_selected(
+            sels: &[Sel],
+            strides: &[usize],
+            f: &mut impl FnMut(usize),
+        ) {
+            fn rec(sels: &[Sel], strides: &[usize], dim: usize, base: usize, f: &mut impl FnMut(usize)) {
+                if dim == sels.len() {
+                    f(base);
+                    return;
+                }
+                match &sels[dim] {
+                    Sel::Int(i) => rec(sels, strides, dim + 1, base + i * strides[dim], f),
+                    Sel::Slice(indices) => {
+                        for &i in indices {
+                            rec(sels, strides, dim + 1, base + i * strides[dim], f);
+                        }
+                    }
+                }
+            }
+            rec(sels, strides, 0, 0, f);
+        }
+
+        fn binop(
+            &self,
+            other: &PyObject,
+            op: BinOp,
+            reflected: bool,
+            vm: &VirtualMachine,
+        ) -> PyResult {
+            let lhs = self.read();
+            // the right-hand side: another array of the same shape, or a
+            // scalar applied to every element (the only broadcasting we do)
+            enum Rhs {
+                Array(ArrayInner),
+                Scalar(Scalar),
+            }
+            let rhs = if let Some(other) = other.downcast_ref::<PyNdarray>() {
+                let other = other.read();
+                if other.shape != lhs.shape {
+                    return Err(vm.new_value_error(format!(
+                        "operands could not be broadcast together with shapes {:?} {:?}",
+                        lhs.shape, other.shape
+                    )));
+                }
+                Rhs::Array(other.clone())
+            } else if let Some(int) = other.downcast_ref::<PyInt>() {
+                match int.as_bigint().to_i64() {
+                    Some(x) => Rhs::Scalar(Scalar::I64(x)),
+                    None => return Err(vm.new_overflow_error("int too large for int64".to_owned())),
+                }
+            } else if let Some(float) = other.downcast_ref::<PyFloat>() {
+                Rhs::Scalar(Scalar::F64(float.to_f64()))
+            } else {
+                return Ok(vm.ctx.not_implemented());
+            };
+
+            let rhs_is_float = match &rhs {
+                Rhs::Array(a) => a.dtype == Dtype::Float64,
+                Rhs::Scalar(Scalar::F64(_)) => true,
+                Rhs::Scalar(Scalar::I64(_)) => false,
+            };
+            let dtype = if op == BinOp::Div || lhs.dtype == Dtype::Float64 || rhs_is_float {
+                Dtype::Float64
+            } else {
+                Dtype::Int64
+            };
+
+            let mut out = ArrayInner::zeros(lhs.shape.clone(), dtype);
+            for i in 0..lhs.size() {
+                let a = lhs.get(i);
+                let b = match &rhs {
+                    Rhs::Array(arr) => arr.get(i),
+                    Rhs::Scalar(s) => *s,
+                };
+                let (a, b) = if reflected { (b, a) } else { (a, b) };
+                let result = if dtype == Dtype::Float64 {
+                    let (a, b) = (a.as_f64(), b.as_f64());
+                    Scalar::F64(match op {
+                        BinOp::Add => a + b,
+                        BinOp::Sub => a - b,
+                        BinOp::Mul => a * b,
+                        BinOp::Div => a / b,
+                    })
+                } else {
+                    let (Scalar::I64(a), Scalar::I64(b)) = (a, b) else {
+                        unreachable!("int64 result implies int64 operands")
+                    };
+                    Scalar::I64(match op {
+                        BinOp::Add => a.wrapping_add(b),
+                        BinOp::Sub => a.wrapping_sub(b),
+                        BinOp::Mul => a.wrapping_mul(b),
+                        BinOp::Div => unreachable!("division always yields float64"),
+                    })
+                };
+                out.set(i, result);
+            }
+            Ok(PyNdarray::from(out).into_pyobject(vm))
+        }
+
+        fn tolist_inner(
+            inner: &ArrayInner,
+            dim: usize,
+            offset: usize,
+            vm: &VirtualMachine,
+        ) -> PyObjectRef {
+            if dim == inner.shape.len() {
+                return inner.get(offset).to_pyobject(vm);
+            }
+            let stride = inner.elem_strides()[dim];
+            let elements = (0..inner.shape[dim])
+                .map(|i| Self::tolist_inner(inner, dim + 1, offset + i * stride, vm))
+                .collect();
+            vm.ctx.new_list(elements).into()
+        }
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    enum BinOp {
+        Add,
+        Sub,
+        Mul,
+        Div,
+    }
+
+    #[pyclass(with(AsBuffer, AsMapping, Representable))]
+    impl PyNdarray {
+        #[pygetset]
+        fn shape(&self, vm: &VirtualMachine) -> PyTupleRef {
+            let shape = self.read().shape.clone();
+            vm.ctx
+                .new_tuple(shape.into_iter().map(|d| vm.ctx.new_int(d).into()).collect())
+        }
+
+        #[pygetset]
+        fn ndim(&self) -> usize {
+            self.read().shape.len()
+        }
+
+        #[pygetset]
+        fn size(&self) -> usize {
+            self.read().size()
+        }
+
+        #[pygetset]
+        fn itemsize(&self) -> usize {
+            Dtype::ITEMSIZE
+        }
+
+        #[pygetset]
+        fn nbytes(&self) -> usize {
+            self.read().data.len()
+        }
+
+        #[pygetset]
+        fn dtype(&self) -> &'static str {
+            self.read().dtype.name()
+        }
+
+        /// Byte strides for each dimension, C-contiguous.
+        #[pygetset]
+        fn strides(&self, vm: &VirtualMachine) -> PyTupleRef {
+            let strides = self.read().elem_strides();
+            vm.ctx.new_tuple(
+                strides
+                    .into_iter()
+                    .map(|s| vm.ctx.new_int(s * Dtype::ITEMSIZE).into())
+                    .collect(),
+            )
+        }
+
+        #[pymethod]
+        fn tolist(&self, vm: &VirtualMachine) -> PyObjectRef {
+            let inner = self.read();
+            Self::tolist_inner(&inner, 0, 0, vm)
+        }
+
+        #[pymethod]
+        fn copy(&self) -> Self {
+            PyNdarray::from(self.read().clone())
+        }
+
+        #[pymethod]
+        fn astype(&self, dtype: PyStrRef, vm: &VirtualMachine) -> PyResult<Self> {
+            let dtype = Dtype::parse(dtype.as_str(), vm)?;
+            let inner = self.read();
+            let mut out = ArrayInner::zeros(inner.shape.clone(), dtype);
+            for i in 0..inner.size() {
+                out.set(i, inner.get(i));
+            }
+            Ok(PyNdarray::from(out))
+        }
+
+        #[pymethod]
+        fn reshape(&self, shape: PyObjectRef, vm: &VirtualMachine) -> PyResult<Self> {
+            let shape = parse_shape(shape, vm)?;
+            let inner = self.read();
+            if shape.iter().product::<usize>() != inner.size() {
+                return Err(vm.new_value_error(format!(
+                    "cannot reshape array of size {} into shape {:?}",
+                    inner.size(),
+                    shape
+                )));
+            }
+            Ok(PyNdarray::from(ArrayInner {
+                shape,
+                dtype: inner.dtype,
+                data: inner.data.clone(),
+            }))
+        }
+
+        #[pymethod]
+        fn fill(&self, value: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+            let mut inner = self.inner.write();
+            let value = inner.scalar_from_obj(&value, vm)?;
+            for i in 0..inner.size() {
+                inner.set(i, value);
+            }
+            Ok(())
+        }
+
+        #[pymethod]
+        fn sum(&self, vm: &VirtualMachine) -> PyObjectRef {
+            let inner = self.read();
+            match inner.dtype {
+                Dtype::Float64 => {
+                    let sum: f64 = (0..inner.size()).map(|i| inner.get(i).as_f64()).sum();
+                    Scalar::F64(sum).to_pyobject(vm)
+                }
+                Dtype::Int64 => {
+                    let mut sum = 0i64;
+                    for i in 0..inner.size() {
+                        let Scalar::I64(x) = inner.get(i) else {
+                            unreachable!()
+                        };
+                        sum = sum.wrapping_add(x);
+                    }
+                    Scalar::I64(sum).to_pyobject(vm)
+                }
+            }
+        }
+
+        #[pymethod]
+        fn mean(&self, vm: &VirtualMachine) -> PyResult<f64> {
+            let inner = self.read();
+            if inner.size() == 0 {
+                return Err(vm.new_value_error("mean of an empty array".to_owned()));
+            }
+            let sum: f64 = (0..inner.size()).map(|i| inner.get(i).as_f64()).sum();
+            Ok(sum / inner.size() as f64)
+        }
+
+        #[pymethod(magic)]
+        fn len(&self, vm: &VirtualMachine) -> PyResult<usize> {
+            self.read()
+                .shape
+                .first()
+                .copied()
+                .ok_or_else(|| vm.new_type_error("len() of unsized object".to_owned()))
+        }
+
+        #[pymethod(magic)]
+        fn add(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Add, false, vm)
+        }
+
+        #[pymethod(magic)]
+        fn radd(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Add, true, vm)
+        }
+
+        #[pymethod(magic)]
+        fn sub(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Sub, false, vm)
+        }
+
+        #[pymethod(magic)]
+        fn rsub(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Sub, true, vm)
+        }
+
+        #[pymethod(magic)]
+        fn mul(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Mul, false, vm)
+        }
+
+        #[pymethod(magic)]
+        fn rmul(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Mul, true, vm)
+        }
+
+        #[pymethod(magic)]
+        fn truediv(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Div, false, vm)
+        }
+
+        #[pymethod(magic)]
+        fn rtruediv(&self, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            self.binop(&other, BinOp::Div, true, vm)
+        }
+
+        #[pymethod(magic)]
+        fn neg(&self, vm: &VirtualMachine) -> PyResult {
+            self.binop(vm.ctx.new_int(0).as_object(), BinOp::Sub, true, vm)
+        }
+
+        fn getitem(&self, needle: &PyObject, vm: &VirtualMachine) -> PyResult {
+            let inner = self.read();
+            let sels = Self::resolve_index(&inner.shape, needle, vm)?;
+            let strides = inner.elem_strides();
+
+            let out_shape: Vec<usize> = sels
+                .iter()
+                .filter_map(|sel| match sel {
+                    Sel::Int(_) => None,
+                    Sel::Slice(indices) => Some(indices.len()),
+                })
+                .collect();
+            // every dimension indexed by an integer: the result is a scalar
+            if out_shape.is_empty() {
+                let mut offset = 0;
+                Self::for_each_selected(&sels, &strides, &mut |i| offset = i);
+                return Ok(inner.get(offset).to_pyobject(vm));
+            }
+
+            let mut out = ArrayInner::zeros(out_shape, inner.dtype);
+            let mut n = 0;
+            Self::for_each_selected(&sels, &strides, &mut |i| {
+                let value = inner.get(i);
+                out.set(n, value);
+                n += 1;
+            });
+            Ok(PyNdarray::from(out).into_pyobject(vm))
+        }
+
+        fn setitem(&self, needle: &PyObject, value: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+            // resolve against a snapshot of the shape so `value` conversion
+            // (which can run Python code) happens before the write lock
+            let (sels, strides) = {
+                let inner = self.read();
+                let sels = Self::resolve_index(&inner.shape, needle, vm)?;
+                (sels, inner.elem_strides())
+            };
+
+            if let Some(value) = value.downcast_ref::<PyNdarray>() {
+                let value = value.read().clone();
+                let sel_shape: Vec<usize> = sels
+                    .iter()
+                    .filter_map(|sel| match sel {
+                        Sel::Int(_) => None,
+                        Sel::Slice(indices) => Some(indices.len()),
+                    })
+                    .collect();
+                if value.shape != sel_shape {
+                    return Err(vm.new_value_error(format!(
+                        "could not broadcast input array from shape {:?} into shape {:?}",
+                        value.shape, sel_shape
+                    )));
+                }
+                let mut inner = self.inner.write();
+                let mut n = 0;
+                Self::for_each_selected(&sels, &strides, &mut |i| {
+                    inner.set(i, value.get(n));
+                    n += 1;
+                });
+                return Ok(());
+            }
+
+            // a scalar fills every selected element
+            let scalar = self.read().scalar_from_obj(&value, vm)?;
+            let mut inner = self.inner.write();
+            Self::for_each_selected(&sels, &strides, &mut |i| inner.set(i, scalar));
+            Ok(())
+        }
+    }
+
+    impl AsMapping for PyNdarray {
+        fn as_mapping() -> &'static PyMappingMethods {
+            static AS_MAPPING: PyMappingMethods = PyMappingMethods {
+                length: atomic_func!(|mapping, vm| {
+                    PyNdarray::mapping_downcast(mapping).len(vm)
+                }),
+                subscript: atomic_func!(|mapping, needle, vm| {
+                    PyNdarray::mapping_downcast(mapping).getitem(needle, vm)
+                }),
+                ass_subscript: atomic_func!(|mapping, needle, value, vm| {
+                    let zelf = PyNdarray::mapping_downcast(mapping);
+                    match value {
+                        Some(value) => zelf.setitem(needle, value, vm),
+                        None => Err(vm.new_type_error(
+                            "cannot delete array elements".to_owned(),
+                        )),
+                    }
+                }),
+            };
+            &AS_MAPPING
+        }
+    }
+
+    impl AsBuffer for PyNdarray {
+        fn as_buffer(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<PyBuffer> {
+            let inner = zelf.read();
+            let dim_desc = inner
+                .shape
+                .iter()
+                .zip(inner.elem_strides())
+                .map(|(&dim, stride)| (dim, (stride * Dtype::ITEMSIZE) as isize, 0))
+                .collect();
+            let desc = BufferDescriptor {
+                len: inner.data.len(),
+                readonly: false,
+                itemsize: Dtype::ITEMSIZE,
+                format: inner.dtype.typecode().into(),
+                dim_desc,
+            };
+            Ok(PyBuffer::new(zelf.to_owned().into(), desc, &BUFFER_METHODS))
+        }
+    }
+
+    static BUFFER_METHODS: BufferMethods = BufferMethods {
+        obj_bytes: |buffer| buffer.obj_as::<PyNdarray>().get_bytes().into(),
+        obj_bytes_mut: |buffer| buffer.obj_as::<PyNdarray>().get_bytes_mut().into(),
+        release: |buffer| {
+            buffer
+                .obj_as::<PyNdarray>()
+                .exports
+                .fetch_sub(1, atomic::Ordering::Release);
+        },
+        retain: |buffer| {
+            buffer
+                .obj_as::<PyNdarray>()
+                .exports
+                .fetch_add(1, atomic::Ordering::Release);
+        },
+    };
+
+    impl Representable for PyNdarray {
+        #[inline]
+        fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
+            let list = zelf.tolist(vm);
+            let list_repr = list.repr(vm)?;
+            let dtype = zelf.read().dtype.name();
+            Ok(format!("ndarray({}, dtype='{}')", list_repr.as_str(), dtype))
+        }
+    }
+
+    /// A shape argument: a single non-negative int or an iterable of them.
+    fn parse_shape(shape: PyObjectRef, vm: &VirtualMachine) -> PyResult<Vec<usize>> {
+        if let Some(int) = shape.downcast_ref::<PyInt>() {
+            return Ok(vec![int.try_to_primitive(vm)?]);
+        }
+        ArgIterable::<usize>::try_from_object(vm, shape)?
+            .iter(vm)?
+            .collect()
+    }
+
+    fn dtype_arg(dtype: OptionalArg<PyStrRef>, default: Dtype, vm: &VirtualMachine) -> PyResult<Dtype> {
+        match dtype {
+            OptionalArg::Present(s) => Dtype::parse(s.as_str(), vm),
+            OptionalArg::Missing => Ok(default),
+        }
+    }
+
+    /// Walk a nested sequence: the first elements determine the shape, every
+    /// sibling is checked against it, and the leaves are collected in C
+    /// order.
+    fn collect_nested(
+        obj: &PyObject,
+        dim: usize,
+        shape: &mut Vec<usize>,
+        leaves: &mut Vec<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let as_list: Option<PyListRef> = obj.to_owned().downcast().ok();
+        let elements = match &as_list {
+            Some(list) => Some(list.borrow_vec().to_vec()),
+            None => obj
+                .to_owned()
+                .downcast::<PyTuple>()
+                .ok()
+                .map(|tuple| tuple.to_vec()),
+        };
+        let Some(elements) = elements else {
+            if dim != shape.len() {
+                return Err(vm.new_value_error(
+                    "inconsistent nesting depth in array initializer".to_owned(),
+                ));
+            }
+            leaves.push(obj.to_owned());
+            return Ok(());
+        };
+        if dim == shape.len() {
+            shape.push(elements.len());
+        } else if shape[dim] != elements.len() {
+            return Err(vm.new_value_error(format!(
+                "inconsistent sequence length at depth {}: expected {}, got {}",
+                dim,
+                shape[dim],
+                elements.len()
+            )));
+        }
+        for element in &elements {
+            collect_nested(element, dim + 1, shape, leaves, vm)?;
+        }
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn array(
+        object: PyObjectRef,
+        dtype: OptionalArg<PyStrRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyNdarray> {
+        if let Some(existing) = object.downcast_ref::<PyNdarray>() {
+            let dtype = dtype_arg(dtype, existing.read().dtype, vm)?;
+            return existing.astype(vm.ctx.new_str(dtype.name()), vm);
+        }
+
+        let mut shape = Vec::new();
+        let mut leaves = Vec::new();
+        collect_nested(&object, 0, &mut shape, &mut leaves, vm)?;
+
+        let dtype = match dtype {
+            OptionalArg::Present(s) => Dtype::parse(s.as_str(), vm)?,
+            // infer: all-int input makes an int64 array, anything else float64
+            OptionalArg::Missing => {
+                if leaves.iter().all(|leaf| leaf.downcast_ref::<PyInt>().is_some()) {
+                    Dtype::Int64
+                } else {
+                    Dtype::Float64
+                }
+            }
+        };
+
+        let mut inner = ArrayInner::zeros(shape, dtype);
+        debug_assert_eq!(inner.size(), leaves.len());
+        for (i, leaf) in leaves.iter().enumerate() {
+            let value = inner.scalar_from_obj(leaf, vm)?;
+            inner.set(i, value);
+        }
+        Ok(PyNdarray::from(inner))
+    }
+
+    #[pyfunction]
+    fn zeros(
+        shape: PyObjectRef,
+        dtype: OptionalArg<PyStrRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyNdarray> {
+        let shape = parse_shape(shape, vm)?;
+        let dtype = dtype_arg(dtype, Dtype::Float64, vm)?;
+        Ok(PyNdarray::from(ArrayInner::zeros(shape, dtype)))
+    }
+
+    #[pyfunction]
+    fn ones(
+        shape: PyObjectRef,
+        dtype: OptionalArg<PyStrRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyNdarray> {
+        let shape = parse_shape(shape, vm)?;
+        let dtype = dtype_arg(dtype, Dtype::Float64, vm)?;
+        let mut inner = ArrayInner::zeros(shape, dtype);
+        let one = match dtype {
+            Dtype::Float64 => Scalar::F64(1.0),
+            Dtype::Int64 => Scalar::I64(1),
+        };
+        for i in 0..inner.size() {
+            inner.set(i, one);
+        }
+        Ok(PyNdarray::from(inner))
+    }
+
+    #[pyfunction]
+    fn arange(
+        start: i64,
+        stop: OptionalArg<i64>,
+        step: OptionalArg<i64>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyNdarray> {
+        let (start, stop) = match stop {
+            OptionalArg::Present(stop) => (start, stop),
+            OptionalArg::Missing => (0, start),
+        };
+        let step = step.unwrap_or(1);
+        if step == 0 {
+            return Err(vm.new_value_error("arange: step must not be zero".to_owned()));
+        }
+        let mut values = Vec::new();
+        let mut x = start;
+        while (step > 0 && x < stop) || (step < 0 && x > stop) {
+            values.push(x);
+            x = x.wrapping_add(step);
+        }
+        let mut inner = ArrayInner::zeros(vec![values.len()], Dtype::Int64);
+        for (i, value) in values.into_iter().enumerate() {
+            inner.set(i, Scalar::I64(value));
+        }
+        Ok(PyNdarray::from(inner))
+    }
+}
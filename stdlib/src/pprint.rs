@@ -0,0 +1,188 @@
+pub(crate) use _pprint::make_module;
+
+#[pymodule]
+mod _pprint {
+    use crate::vm::{
+        PyObject, PyObjectRef, PyResult, VirtualMachine,
+        builtins::{PyDict, PyList, PyTuple},
+        types::PyComparisonOp,
+    };
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    // deep nesting raises RecursionError instead of overflowing the stack
+    const RECURSION_WHERE: &str = "while pretty-printing an object";
+
+    // the types in pprint._builtin_scalars, plus exact int
+    fn is_builtin_scalar(obj: &PyObject, vm: &VirtualMachine) -> bool {
+        let cls = obj.class();
+        let types = &vm.ctx.types;
+        cls.is(types.str_type)
+            || cls.is(types.bytes_type)
+            || cls.is(types.bytearray_type)
+            || cls.is(types.int_type)
+            || cls.is(types.float_type)
+            || cls.is(types.complex_type)
+            || cls.is(types.bool_type)
+            || cls.is(types.none_type)
+    }
+
+    // pprint._safe_key ordering: fall back to (type name, id) when the keys
+    // don't support comparison among themselves
+    fn safe_key_cmp(a: &PyObject, b: &PyObject, vm: &VirtualMachine) -> Ordering {
+        if let Ok(lt) = a.rich_compare_bool(b, PyComparisonOp::Lt, vm) {
+            if lt {
+                return Ordering::Less;
+            }
+            if let Ok(gt) = b.rich_compare_bool(a, PyComparisonOp::Lt, vm) {
+                return if gt { Ordering::Greater } else { Ordering::Equal };
+            }
+        }
+        let type_key = |o: &PyObject| (o.class().name().to_string(), o.get_id());
+        type_key(a).cmp(&type_key(b))
+    }
+
+    fn recursion_marker(obj: &PyObject) -> String {
+        format!("<Recursion on {} with id={}>", obj.class().name(), obj.get_id())
+    }
+
+    /// Port of `PrettyPrinter._safe_repr` for exact builtin containers.
+    /// Returns `Ok(None)` when the object graph contains a dict/list/tuple
+    /// subclass, whose elements must go through the Python implementation so
+    /// that overridden hooks are honored.
+    fn safe_repr_inner(
+        obj: &PyObject,
+        context: &mut HashSet<usize>,
+        maxlevels: usize,
+        level: usize,
+        sort_dicts: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<Option<(String, bool, bool)>> {
+        if is_builtin_scalar(obj, vm) {
+            return Ok(Some((obj.repr(vm)?.as_str().to_owned(), true, false)));
+        }
+        let cls = obj.class();
+        let types = &vm.ctx.types;
+
+        if cls.is(types.dict_type) {
+            let dict = obj.downcast_ref::<PyDict>().unwrap();
+            if dict.is_empty() {
+                return Ok(Some(("{}".to_owned(), true, false)));
+            }
+            let objid = obj.get_id();
+            if maxlevels != 0 && level >= maxlevels {
+                return Ok(Some(("{...}".to_owned(), false, context.contains(&objid))));
+            }
+            if !context.insert(objid) {
+                return Ok(Some((recursion_marker(obj), false, true)));
+            }
+            let mut items: Vec<_> = dict.into_iter().collect();
+            if sort_dicts {
+                items.sort_by(|a, b| safe_key_cmp(&a.0, &b.0, vm));
+            }
+            let mut readable = true;
+            let mut recursive = false;
+            let mut components = Vec::with_capacity(items.len());
+            for (key, value) in items {
+                let Some((krepr, kreadable, krecur)) = vm.with_recursion(RECURSION_WHERE, || {
+                    safe_repr_inner(&key, context, maxlevels, level + 1, sort_dicts, vm)
+                })?
+                else {
+                    return Ok(None);
+                };
+                let Some((vrepr, vreadable, vrecur)) = vm.with_recursion(RECURSION_WHERE, || {
+                    safe_repr_inner(&value, context, maxlevels, level + 1, sort_dicts, vm)
+                })?
+                else {
+                    return Ok(None);
+                };
+                components.push(format!("{krepr}: {vrepr}"));
+                readable = readable && kreadable && vreadable;
+                recursive = recursive || krecur || vrecur;
+            }
+            context.remove(&objid);
+            return Ok(Some((format!("{{{}}}", components.join(", ")), readable, recursive)));
+        }
+
+        let as_list = cls.is(types.list_type);
+        if as_list || cls.is(types.tuple_type) {
+            let elements: Vec<PyObjectRef> = if as_list {
+                obj.downcast_ref::<PyList>().unwrap().borrow_vec().to_vec()
+            } else {
+                obj.downcast_ref::<PyTuple>().unwrap().to_vec()
+            };
+            if elements.is_empty() {
+                let empty = if as_list { "[]" } else { "()" };
+                return Ok(Some((empty.to_owned(), true, false)));
+            }
+            let objid = obj.get_id();
+            if maxlevels != 0 && level >= maxlevels {
+                let rep = if as_list {
+                    "[...]"
+                } else if elements.len() == 1 {
+                    "(...,)"
+                } else {
+                    "(...)"
+                };
+                return Ok(Some((rep.to_owned(), false, context.contains(&objid))));
+            }
+            if !context.insert(objid) {
+                return Ok(Some((recursion_marker(obj), false, true)));
+            }
+            let mut readable = true;
+            let mut recursive = false;
+            let mut components = Vec::with_capacity(elements.len());
+            for elem in &elements {
+                let Some((erepr, ereadable, erecur)) = vm.with_recursion(RECURSION_WHERE, || {
+                    safe_repr_inner(elem, context, maxlevels, level + 1, sort_dicts, vm)
+                })?
+                else {
+                    return Ok(None);
+                };
+                components.push(erepr);
+                readable = readable && ereadable;
+                recursive = recursive || erecur;
+            }
+            context.remove(&objid);
+            let joined = components.join(", ");
+            let rep = if as_list {
+                format!("[{joined}]")
+            } else if elements.len() == 1 {
+                format!("({joined},)")
+            } else {
+                format!("({joined})")
+            };
+            return Ok(Some((rep, readable, recursive)));
+        }
+
+        // subclasses may carry overridden hooks (sorting, dispatch, format);
+        // leave the whole call to the Python implementation
+        if obj.fast_isinstance(types.dict_type)
+            || obj.fast_isinstance(types.list_type)
+            || obj.fast_isinstance(types.tuple_type)
+        {
+            return Ok(None);
+        }
+
+        let rep = obj.repr(vm)?.as_str().to_owned();
+        let readable = !rep.is_empty() && !rep.starts_with('<');
+        Ok(Some((rep, readable, false)))
+    }
+
+    /// Fast path for `PrettyPrinter._safe_repr`. `context` is the set of
+    /// object ids on the current formatting path, `maxlevels == 0` means no
+    /// depth limit. Returns `(repr, readable, recursive)`, or `None` if the
+    /// caller must use its fallback.
+    #[pyfunction]
+    fn safe_repr(
+        obj: PyObjectRef,
+        context: Vec<usize>,
+        maxlevels: usize,
+        level: usize,
+        sort_dicts: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<Option<(String, bool, bool)>> {
+        let mut context: HashSet<usize> = context.into_iter().collect();
+        safe_repr_inner(&obj, &mut context, maxlevels, level, sort_dicts, vm)
+    }
+}
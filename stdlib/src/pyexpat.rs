@@ -1,6 +1,7 @@
 /* Pyexpat builtin module
 *
-*
+* Backed by xml-rs rather than expat itself; implements the subset of the
+* pyexpat API that xml.etree.ElementTree and xml.dom rely on.
 */
 
 use crate::vm::{PyRef, VirtualMachine, builtins::PyModule, extend_module};
@@ -8,9 +9,14 @@ use crate::vm::{PyRef, VirtualMachine, builtins::PyModule, extend_module};
 pub fn make_module(vm: &VirtualMachine) -> PyRef<PyModule> {
     let module = _pyexpat::make_module(vm);
 
+    let expat_error = module
+        .get_attr("ExpatError", vm)
+        .expect("pyexpat module has ExpatError");
     extend_module!(vm, &module, {
          "errors" => _errors::make_module(vm),
          "model" => _model::make_module(vm),
+         // alias mandated by the pyexpat API
+         "error" => expat_error,
     });
 
     module
@@ -29,19 +35,47 @@ macro_rules! create_property {
     };
 }
 
+macro_rules! create_position_property {
+    ($ctx: expr, $attributes: expr, $name: expr, $class: expr, $element: ident, $index: tt) => {
+        let attr = $ctx.new_readonly_getset($name, $class, move |this: &PyExpatLikeXmlParser| {
+            this.$element.read().$index
+        });
+
+        $attributes.insert($ctx.intern_str($name), attr.into());
+    };
+}
+
 #[pymodule(name = "pyexpat")]
 mod _pyexpat {
     use crate::vm::{
-        Context, Py, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
-        builtins::{PyStr, PyStrRef, PyType},
+        AsObject, Context, Py, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject,
+        VirtualMachine,
+        builtins::{PyStr, PyStrRef, PyType, PyTypeRef},
         function::ArgBytesLike,
-        function::{IntoFuncArgs, OptionalArg},
+        function::{ArgStrOrBytesLike, IntoFuncArgs, OptionalArg},
     };
     use rustpython_common::lock::PyRwLock;
     use std::io::Cursor;
+    use xml::common::Position;
     use xml::reader::XmlEvent;
     type MutableObject = PyRwLock<PyObjectRef>;
 
+    #[pyattr]
+    const XML_PARAM_ENTITY_PARSING_NEVER: i32 = 0;
+    #[pyattr]
+    const XML_PARAM_ENTITY_PARSING_UNLESS_STANDALONE: i32 = 1;
+    #[pyattr]
+    const XML_PARAM_ENTITY_PARSING_ALWAYS: i32 = 2;
+
+    #[pyattr(name = "ExpatError", once)]
+    fn expat_error(vm: &VirtualMachine) -> PyTypeRef {
+        vm.ctx.new_exception_type(
+            "pyexpat",
+            "ExpatError",
+            Some(vec![vm.ctx.exceptions.exception_type.to_owned()]),
+        )
+    }
+
     #[pyattr]
     #[pyclass(name = "xmlparser", module = false, traverse)]
     #[derive(Debug, PyPayload)]
@@ -50,27 +84,85 @@ mod _pyexpat {
         end_element: MutableObject,
         character_data: MutableObject,
         entity_decl: MutableObject,
+        comment: MutableObject,
+        processing_instruction: MutableObject,
+        start_namespace_decl: MutableObject,
+        end_namespace_decl: MutableObject,
+        default_handler: MutableObject,
+        default_handler_expand: MutableObject,
+        start_doctype_decl: MutableObject,
+        end_doctype_decl: MutableObject,
+        start_cdata_section: MutableObject,
+        end_cdata_section: MutableObject,
+        notation_decl: MutableObject,
+        external_entity_ref: MutableObject,
+        xml_decl: MutableObject,
+        element_decl: MutableObject,
+        attlist_decl: MutableObject,
         buffer_text: MutableObject,
+        ordered_attributes: MutableObject,
+        specified_attributes: MutableObject,
+        intern: MutableObject,
+        #[pytraverse(skip)]
+        namespace_separator: Option<String>,
+        /// data fed via `Parse(data, False)`, parsed once the final chunk arrives
+        #[pytraverse(skip)]
+        buffer: PyRwLock<Vec<u8>>,
+        /// (line, column) pairs; lines are 1-based like expat's
+        #[pytraverse(skip)]
+        current_position: PyRwLock<(u64, u64)>,
+        #[pytraverse(skip)]
+        error_position: PyRwLock<(u64, u64)>,
     }
     type PyExpatLikeXmlParserRef = PyRef<PyExpatLikeXmlParser>;
 
     #[inline]
-    fn invoke_handler<T>(vm: &VirtualMachine, handler: &MutableObject, args: T)
+    fn invoke_handler<T>(vm: &VirtualMachine, handler: &MutableObject, args: T) -> PyResult<()>
     where
         T: IntoFuncArgs,
     {
-        handler.read().call(args, vm).ok();
+        let handler = handler.read().clone();
+        if !vm.is_none(&handler) {
+            handler.call(args, vm)?;
+        }
+        Ok(())
     }
 
     #[pyclass]
     impl PyExpatLikeXmlParser {
-        fn new(vm: &VirtualMachine) -> PyResult<PyExpatLikeXmlParserRef> {
+        fn new(
+            namespace_separator: Option<String>,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyExpatLikeXmlParserRef> {
+            let none = || MutableObject::new(vm.ctx.none());
             Ok(PyExpatLikeXmlParser {
-                start_element: MutableObject::new(vm.ctx.none()),
-                end_element: MutableObject::new(vm.ctx.none()),
-                character_data: MutableObject::new(vm.ctx.none()),
-                entity_decl: MutableObject::new(vm.ctx.none()),
+                start_element: none(),
+                end_element: none(),
+                character_data: none(),
+                entity_decl: none(),
+                comment: none(),
+                processing_instruction: none(),
+                start_namespace_decl: none(),
+                end_namespace_decl: none(),
+                default_handler: none(),
+                default_handler_expand: none(),
+                start_doctype_decl: none(),
+                end_doctype_decl: none(),
+                start_cdata_section: none(),
+                end_cdata_section: none(),
+                notation_decl: none(),
+                external_entity_ref: none(),
+                xml_decl: none(),
+                element_decl: none(),
+                attlist_decl: none(),
                 buffer_text: MutableObject::new(vm.ctx.new_bool(false).into()),
+                ordered_attributes: MutableObject::new(vm.ctx.new_bool(false).into()),
+                specified_attributes: MutableObject::new(vm.ctx.new_bool(false).into()),
+                intern: MutableObject::new(vm.ctx.new_dict().into()),
+                namespace_separator,
+                buffer: PyRwLock::new(Vec::new()),
+                current_position: PyRwLock::new((1, 0)),
+                error_position: PyRwLock::new((0, 0)),
             }
             .into_ref(&vm.ctx))
         }
@@ -89,70 +181,316 @@ mod _pyexpat {
                 character_data
             );
             create_property!(ctx, attributes, "EntityDeclHandler", class, entity_decl);
+            create_property!(ctx, attributes, "CommentHandler", class, comment);
+            create_property!(
+                ctx,
+                attributes,
+                "ProcessingInstructionHandler",
+                class,
+                processing_instruction
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "StartNamespaceDeclHandler",
+                class,
+                start_namespace_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "EndNamespaceDeclHandler",
+                class,
+                end_namespace_decl
+            );
+            create_property!(ctx, attributes, "DefaultHandler", class, default_handler);
+            create_property!(
+                ctx,
+                attributes,
+                "DefaultHandlerExpand",
+                class,
+                default_handler_expand
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "StartDoctypeDeclHandler",
+                class,
+                start_doctype_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "EndDoctypeDeclHandler",
+                class,
+                end_doctype_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "StartCdataSectionHandler",
+                class,
+                start_cdata_section
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "EndCdataSectionHandler",
+                class,
+                end_cdata_section
+            );
+            create_property!(ctx, attributes, "NotationDeclHandler", class, notation_decl);
+            create_property!(
+                ctx,
+                attributes,
+                "ExternalEntityRefHandler",
+                class,
+                external_entity_ref
+            );
+            create_property!(ctx, attributes, "XmlDeclHandler", class, xml_decl);
+            create_property!(ctx, attributes, "ElementDeclHandler", class, element_decl);
+            create_property!(ctx, attributes, "AttlistDeclHandler", class, attlist_decl);
             create_property!(ctx, attributes, "buffer_text", class, buffer_text);
+            create_property!(
+                ctx,
+                attributes,
+                "ordered_attributes",
+                class,
+                ordered_attributes
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "specified_attributes",
+                class,
+                specified_attributes
+            );
+            create_property!(ctx, attributes, "intern", class, intern);
+
+            create_position_property!(
+                ctx,
+                attributes,
+                "CurrentLineNumber",
+                class,
+                current_position,
+                0
+            );
+            create_position_property!(
+                ctx,
+                attributes,
+                "CurrentColumnNumber",
+                class,
+                current_position,
+                1
+            );
+            create_position_property!(
+                ctx,
+                attributes,
+                "ErrorLineNumber",
+                class,
+                error_position,
+                0
+            );
+            create_position_property!(
+                ctx,
+                attributes,
+                "ErrorColumnNumber",
+                class,
+                error_position,
+                1
+            );
         }
 
-        fn create_config(&self) -> xml::ParserConfig {
-            xml::ParserConfig::new()
-                .cdata_to_characters(true)
-                .coalesce_characters(false)
+        fn create_config(&self, vm: &VirtualMachine) -> PyResult<xml::ParserConfig> {
+            let coalesce = self.buffer_text.read().clone().try_to_bool(vm)?;
+            Ok(xml::ParserConfig::new()
+                .coalesce_characters(coalesce)
                 .whitespace_to_characters(true)
+                .ignore_comments(false))
         }
 
-        fn do_parse<T>(&self, vm: &VirtualMachine, parser: xml::EventReader<T>)
+        /// Expand a name the way expat does: `uri<sep>local` when the parser
+        /// was created with a namespace separator, the raw qualified name
+        /// otherwise.
+        fn qualify_name(&self, name: &xml::name::OwnedName) -> String {
+            if let Some(sep) = &self.namespace_separator {
+                if let Some(ns) = name.namespace.as_deref().filter(|ns| !ns.is_empty()) {
+                    return format!("{ns}{sep}{}", name.local_name);
+                }
+            } else if let Some(prefix) = &name.prefix {
+                return format!("{prefix}:{}", name.local_name);
+            }
+            name.local_name.clone()
+        }
+
+        fn do_parse<T>(&self, vm: &VirtualMachine, mut parser: xml::EventReader<T>) -> PyResult<()>
         where
             T: std::io::Read,
         {
-            for e in parser {
-                match e {
+            let ordered = self.ordered_attributes.read().clone().try_to_bool(vm)?;
+            // prefixes declared by each open element, for namespace events
+            let mut ns_scopes: Vec<Vec<String>> = Vec::new();
+            let mut ns_active = std::collections::HashMap::new();
+            loop {
+                let pos = parser.position();
+                *self.current_position.write() = (pos.row + 1, pos.column);
+                match parser.next() {
                     Ok(XmlEvent::StartElement {
-                        name, attributes, ..
+                        name,
+                        attributes,
+                        namespace,
                     }) => {
-                        let dict = vm.ctx.new_dict();
-                        for attribute in attributes {
-                            dict.set_item(
-                                attribute.name.local_name.as_str(),
-                                vm.ctx.new_str(attribute.value).into(),
-                                vm,
-                            )
-                            .unwrap();
+                        let mut declared = Vec::new();
+                        if self.namespace_separator.is_some() {
+                            for (prefix, uri) in namespace.0.iter() {
+                                if prefix == "xml" || prefix == "xmlns" || uri.is_empty() {
+                                    continue;
+                                }
+                                if ns_active.get(prefix) != Some(uri) {
+                                    let prefix_obj: PyObjectRef = if prefix.is_empty() {
+                                        vm.ctx.none()
+                                    } else {
+                                        vm.ctx.new_str(prefix.as_str()).into()
+                                    };
+                                    invoke_handler(
+                                        vm,
+                                        &self.start_namespace_decl,
+                                        (prefix_obj, uri.as_str().to_owned()),
+                                    )?;
+                                    ns_active.insert(prefix.clone(), uri.clone());
+                                    declared.push(prefix.clone());
+                                }
+                            }
                         }
+                        ns_scopes.push(declared);
 
-                        let name_str = PyStr::from(name.local_name).into_ref(&vm.ctx);
-                        invoke_handler(vm, &self.start_element, (name_str, dict));
+                        let name_str = vm.ctx.new_str(self.qualify_name(&name));
+                        if ordered {
+                            let mut attrs = Vec::with_capacity(attributes.len() * 2);
+                            for attribute in attributes {
+                                attrs.push(vm.ctx.new_str(self.qualify_name(&attribute.name)).into());
+                                attrs.push(vm.ctx.new_str(attribute.value).into());
+                            }
+                            let attrs = vm.ctx.new_list(attrs);
+                            invoke_handler(vm, &self.start_element, (name_str, attrs))?;
+                        } else {
+                            let dict = vm.ctx.new_dict();
+                            for attribute in attributes {
+                                dict.set_item(
+                                    self.qualify_name(&attribute.name).as_str(),
+                                    vm.ctx.new_str(attribute.value).into(),
+                                    vm,
+                                )?;
+                            }
+                            invoke_handler(vm, &self.start_element, (name_str, dict))?;
+                        }
                     }
                     Ok(XmlEvent::EndElement { name, .. }) => {
-                        let name_str = PyStr::from(name.local_name).into_ref(&vm.ctx);
-                        invoke_handler(vm, &self.end_element, (name_str,));
+                        let name_str = vm.ctx.new_str(self.qualify_name(&name));
+                        invoke_handler(vm, &self.end_element, (name_str,))?;
+                        for prefix in ns_scopes.pop().into_iter().flatten().rev() {
+                            ns_active.remove(&prefix);
+                            let prefix_obj: PyObjectRef = if prefix.is_empty() {
+                                vm.ctx.none()
+                            } else {
+                                vm.ctx.new_str(prefix).into()
+                            };
+                            invoke_handler(vm, &self.end_namespace_decl, (prefix_obj,))?;
+                        }
                     }
                     Ok(XmlEvent::Characters(chars)) => {
                         let str = PyStr::from(chars).into_ref(&vm.ctx);
-                        invoke_handler(vm, &self.character_data, (str,));
+                        invoke_handler(vm, &self.character_data, (str,))?;
+                    }
+                    Ok(XmlEvent::CData(chars)) => {
+                        invoke_handler(vm, &self.start_cdata_section, ())?;
+                        let str = PyStr::from(chars).into_ref(&vm.ctx);
+                        invoke_handler(vm, &self.character_data, (str,))?;
+                        invoke_handler(vm, &self.end_cdata_section, ())?;
+                    }
+                    Ok(XmlEvent::Comment(text)) => {
+                        invoke_handler(vm, &self.comment, (text,))?;
+                    }
+                    Ok(XmlEvent::ProcessingInstruction { name, data }) => {
+                        invoke_handler(
+                            vm,
+                            &self.processing_instruction,
+                            (name, data.unwrap_or_default()),
+                        )?;
+                    }
+                    Ok(XmlEvent::StartDocument {
+                        version,
+                        encoding,
+                        standalone,
+                    }) => {
+                        let standalone = match standalone {
+                            Some(true) => 1,
+                            Some(false) => 0,
+                            None => -1,
+                        };
+                        invoke_handler(
+                            vm,
+                            &self.xml_decl,
+                            (version.to_string(), encoding, standalone),
+                        )?;
+                    }
+                    Ok(XmlEvent::EndDocument) => break,
+                    Ok(_) => {}
+                    Err(e) => {
+                        let pos = e.position();
+                        *self.error_position.write() = (pos.row + 1, pos.column);
+                        let exc = vm.new_exception_msg(
+                            vm.class("pyexpat", "ExpatError"),
+                            e.msg().to_owned(),
+                        );
+                        let exc_obj = exc.as_object();
+                        exc_obj.set_attr("lineno", vm.ctx.new_int(pos.row + 1), vm)?;
+                        exc_obj.set_attr("offset", vm.ctx.new_int(pos.column), vm)?;
+                        exc_obj.set_attr("code", vm.ctx.new_int(2), vm)?;
+                        return Err(exc);
                     }
-                    _ => {}
                 }
             }
+            Ok(())
+        }
+
+        fn parse_final(&self, vm: &VirtualMachine) -> PyResult<()> {
+            let buf = std::mem::take(&mut *self.buffer.write());
+            let parser = self.create_config(vm)?.create_reader(Cursor::new(buf));
+            self.do_parse(vm, parser)
         }
 
+        /// Feed a chunk of data to the parser. Unlike expat this parser is
+        /// not truly incremental: chunks are buffered and the handlers all
+        /// run once the final chunk arrives.
         #[pymethod(name = "Parse")]
-        fn parse(&self, data: PyStrRef, _isfinal: OptionalArg<bool>, vm: &VirtualMachine) {
-            let reader = Cursor::<Vec<u8>>::new(data.as_bytes().to_vec());
-            let parser = self.create_config().create_reader(reader);
-            self.do_parse(vm, parser);
+        fn parse(
+            &self,
+            data: ArgStrOrBytesLike,
+            isfinal: OptionalArg<bool>,
+            vm: &VirtualMachine,
+        ) -> PyResult<u32> {
+            self.buffer.write().extend_from_slice(&data.borrow_bytes());
+            // a bare Parse(data) historically parsed right away; keep that
+            if isfinal.unwrap_or(true) {
+                self.parse_final(vm)?;
+            }
+            Ok(1)
         }
 
         #[pymethod(name = "ParseFile")]
-        fn parse_file(&self, file: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
-            // todo: read chunks at a time
+        fn parse_file(&self, file: PyObjectRef, vm: &VirtualMachine) -> PyResult<u32> {
             let read_res = vm.call_method(&file, "read", ())?;
             let bytes_like = ArgBytesLike::try_from_object(vm, read_res)?;
-            let buf = bytes_like.borrow_buf().to_vec();
-            let reader = Cursor::new(buf);
-            let parser = self.create_config().create_reader(reader);
-            self.do_parse(vm, parser);
+            self.buffer.write().extend_from_slice(&bytes_like.borrow_buf());
+            self.parse_final(vm)?;
+            Ok(1)
+        }
 
-            // todo: return value
-            Ok(())
+        #[pymethod(name = "SetParamEntityParsing")]
+        fn set_param_entity_parsing(&self, _flag: i32) -> i32 {
+            // accepted for compatibility; we never read external entities
+            1
         }
     }
 
@@ -160,19 +498,23 @@ mod _pyexpat {
     #[allow(dead_code)]
     struct ParserCreateArgs {
         #[pyarg(any, optional)]
-        encoding: OptionalArg<PyStrRef>,
+        encoding: OptionalArg<Option<PyStrRef>>,
         #[pyarg(any, optional)]
-        namespace_separator: OptionalArg<PyStrRef>,
+        namespace_separator: OptionalArg<Option<PyStrRef>>,
         #[pyarg(any, optional)]
         intern: OptionalArg<PyStrRef>,
     }
 
     #[pyfunction(name = "ParserCreate")]
     fn parser_create(
-        _args: ParserCreateArgs,
+        args: ParserCreateArgs,
         vm: &VirtualMachine,
     ) -> PyResult<PyExpatLikeXmlParserRef> {
-        PyExpatLikeXmlParser::new(vm)
+        let namespace_separator = args
+            .namespace_separator
+            .flatten()
+            .map(|sep| sep.as_str().to_owned());
+        PyExpatLikeXmlParser::new(namespace_separator, vm)
     }
 }
 
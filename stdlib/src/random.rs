@@ -60,6 +60,9 @@ mod _random {
                     let key = if key.is_empty() { &[0] } else { key.as_slice() };
                     MT19937::new_with_slice_seed(key)
                 }
+                // -X deterministic replaces time/OS-based seeding with a fixed
+                // seed so unseeded generators are reproducible run-to-run
+                None if vm.state.settings.deterministic => MT19937::new_with_slice_seed(&[0]),
                 None => MT19937::try_from_os_rng()
                     .map_err(|e| std::io::Error::from(e).to_pyexception(vm))?,
             };
@@ -0,0 +1,168 @@
+pub(crate) use _shlex::make_module;
+
+#[pymodule]
+mod _shlex {
+    use crate::vm::{
+        PyResult, VirtualMachine,
+        builtins::{PyStr, PyStrRef},
+        function::OptionalArg,
+    };
+    use rustpython_common::wtf8::Wtf8Buf;
+
+    // the lexer configuration shlex.split() uses: posix mode with
+    // whitespace_split on, quotes '\'' and '"', escape '\\' (only honored for
+    // '\\' and '"' inside double quotes), and '#' commenting out the rest of
+    // the line when comments=True
+    enum State {
+        Delim,
+        Word,
+        SingleQuote,
+        DoubleQuote,
+        Escape,
+        DoubleQuoteEscape,
+    }
+
+    const fn is_delim(b: u8) -> bool {
+        matches!(b, b' ' | b'\t' | b'\r' | b'\n')
+    }
+
+    /// Native fast path for `shlex.split(s, comments, posix=True)`.
+    #[pyfunction]
+    fn split(
+        s: PyStrRef,
+        comments: OptionalArg<bool>,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<PyStrRef>> {
+        let comments = comments.unwrap_or(false);
+        let wtf8 = s.as_wtf8();
+        let bytes = wtf8.as_bytes();
+
+        let mut out = Vec::new();
+        let mut token = Wtf8Buf::new();
+        // an empty token still counts once quotes appeared, so '' splits to ['']
+        let mut quoted = false;
+        let mut state = State::Delim;
+        // start of the pending literal run; every state change flushes the
+        // run, and all syntax characters are ASCII, so runs never split a
+        // multi-byte code point
+        let mut run = 0;
+
+        let mut emit = |token: &mut Wtf8Buf, quoted: &mut bool| {
+            if !token.is_empty() || *quoted {
+                out.push(PyStr::from(std::mem::take(token)).into_ref(&vm.ctx));
+            }
+            *quoted = false;
+        };
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            match state {
+                State::Delim => match b {
+                    _ if is_delim(b) => {}
+                    b'#' if comments => {
+                        while i < bytes.len() && bytes[i] != b'\n' {
+                            i += 1;
+                        }
+                    }
+                    b'\\' => state = State::Escape,
+                    b'\'' => {
+                        quoted = true;
+                        state = State::SingleQuote;
+                        run = i + 1;
+                    }
+                    b'"' => {
+                        quoted = true;
+                        state = State::DoubleQuote;
+                        run = i + 1;
+                    }
+                    _ => {
+                        state = State::Word;
+                        run = i;
+                    }
+                },
+                State::Word => match b {
+                    _ if is_delim(b) => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        emit(&mut token, &mut quoted);
+                        state = State::Delim;
+                    }
+                    b'#' if comments => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        emit(&mut token, &mut quoted);
+                        while i < bytes.len() && bytes[i] != b'\n' {
+                            i += 1;
+                        }
+                        state = State::Delim;
+                    }
+                    b'\\' => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        state = State::Escape;
+                    }
+                    b'\'' => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        quoted = true;
+                        state = State::SingleQuote;
+                        run = i + 1;
+                    }
+                    b'"' => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        quoted = true;
+                        state = State::DoubleQuote;
+                        run = i + 1;
+                    }
+                    _ => {}
+                },
+                State::SingleQuote => {
+                    if b == b'\'' {
+                        token.push_wtf8(&wtf8[run..i]);
+                        state = State::Word;
+                        run = i + 1;
+                    }
+                }
+                State::DoubleQuote => match b {
+                    b'"' => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        state = State::Word;
+                        run = i + 1;
+                    }
+                    b'\\' => {
+                        token.push_wtf8(&wtf8[run..i]);
+                        state = State::DoubleQuoteEscape;
+                    }
+                    _ => {}
+                },
+                State::Escape => {
+                    // outside quotes any character may be escaped
+                    state = State::Word;
+                    run = i;
+                }
+                State::DoubleQuoteEscape => {
+                    // in posix shells only the quote itself or the escape
+                    // character may be escaped within double quotes
+                    if b != b'"' && b != b'\\' {
+                        token.push_str("\\");
+                    }
+                    state = State::DoubleQuote;
+                    run = i;
+                }
+            }
+            i += 1;
+        }
+
+        match state {
+            State::Delim => {}
+            State::Word => {
+                token.push_wtf8(&wtf8[run..]);
+                emit(&mut token, &mut quoted);
+            }
+            State::SingleQuote | State::DoubleQuote => {
+                return Err(vm.new_value_error("No closing quotation".to_owned()));
+            }
+            State::Escape | State::DoubleQuoteEscape => {
+                return Err(vm.new_value_error("No escaped character".to_owned()));
+            }
+        }
+        Ok(out)
+    }
+}
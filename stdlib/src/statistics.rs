@@ -2,7 +2,10 @@ pub(crate) use _statistics::make_module;
 
 #[pymodule]
 mod _statistics {
-    use crate::vm::{PyResult, VirtualMachine, function::ArgIntoFloat};
+    use crate::vm::{
+        PyResult, VirtualMachine,
+        function::{ArgIntoFloat, ArgIterable},
+    };
 
     // See https://github.com/python/cpython/blob/6846d6712a0894f8e1a91716c11dd79f42864216/Modules/_statisticsmodule.c#L28-L120
     #[allow(clippy::excessive_precision)]
@@ -129,4 +132,28 @@ mod _statistics {
         normal_dist_inv_cdf(*p, *mu, *sigma)
             .ok_or_else(|| vm.new_value_error("inv_cdf undefined for these parameters".to_owned()))
     }
+
+    /// Mean of an iterable of numbers, summed with Neumaier compensation so
+    /// the result matches fsum()-based pure python implementation.
+    #[pyfunction]
+    fn _fmean(data: ArgIterable<ArgIntoFloat>, vm: &VirtualMachine) -> PyResult<f64> {
+        let mut total = 0.0f64;
+        let mut compensation = 0.0f64;
+        let mut count = 0usize;
+        for x in data.iter(vm)? {
+            let x = *x?;
+            let t = total + x;
+            if total.abs() >= x.abs() {
+                compensation += (total - t) + x;
+            } else {
+                compensation += (x - t) + total;
+            }
+            total = t;
+            count += 1;
+        }
+        if count == 0 {
+            return Err(vm.new_value_error("fmean requires at least one data point".to_owned()));
+        }
+        Ok((total + compensation) / count as f64)
+    }
 }
@@ -0,0 +1,149 @@
+pub(crate) use _textwrap::make_module;
+
+#[pymodule]
+mod _textwrap {
+    use crate::vm::{
+        VirtualMachine,
+        builtins::{PyStr, PyStrRef},
+    };
+    use rustpython_common::wtf8::Wtf8Buf;
+
+    // same set as textwrap._whitespace
+    fn is_space(b: u8) -> bool {
+        matches!(b, b'\t' | b'\n' | b'\x0b' | b'\x0c' | b'\r' | b' ')
+    }
+
+    /// Equivalent of `TextWrapper.wordsep_simple_re.split(text)` with empty
+    /// strings already filtered out: alternating runs of non-whitespace and
+    /// whitespace, in order. The whitespace set is ASCII-only, so scanning
+    /// bytes never splits inside a multi-byte code point.
+    #[pyfunction]
+    fn wordsep_simple(text: PyStrRef, vm: &VirtualMachine) -> Vec<PyStrRef> {
+        let wtf8 = text.as_wtf8();
+        let bytes = wtf8.as_bytes();
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        let mut in_space = bytes.first().copied().is_some_and(is_space);
+        for (i, &b) in bytes.iter().enumerate() {
+            if is_space(b) != in_space {
+                chunks.push(vm.ctx.new_str(&wtf8[start..i]));
+                start = i;
+                in_space = !in_space;
+            }
+        }
+        if start < bytes.len() {
+            chunks.push(vm.ctx.new_str(&wtf8[start..]));
+        }
+        chunks
+    }
+
+    /// Native `textwrap.dedent`. Lines are '\n'-delimited exactly like the
+    /// pure-Python regexes (which use `re.MULTILINE` anchors), the margin is
+    /// the longest common prefix of spaces and tabs over lines with content,
+    /// and whitespace-only lines are normalized to a bare newline.
+    #[pyfunction]
+    fn dedent(text: PyStrRef, vm: &VirtualMachine) -> PyStrRef {
+        let wtf8 = text.as_wtf8();
+        let bytes = wtf8.as_bytes();
+
+        // leading [ \t]* run of a line, or None for empty/whitespace-only lines
+        let indent_of = |line: &[u8]| -> Option<usize> {
+            let n = line
+                .iter()
+                .position(|&b| b != b' ' && b != b'\t')
+                .unwrap_or(line.len());
+            (n < line.len()).then_some(n)
+        };
+
+        let mut margin: Option<&[u8]> = None;
+        for line in bytes.split(|&b| b == b'\n') {
+            let Some(n) = indent_of(line) else { continue };
+            let indent = &line[..n];
+            margin = Some(match margin {
+                None => indent,
+                Some(m) => {
+                    let common = m
+                        .iter()
+                        .zip(indent)
+                        .position(|(a, b)| a != b)
+                        .unwrap_or(m.len().min(indent.len()));
+                    &m[..common]
+                }
+            });
+        }
+        let margin = margin.unwrap_or(b"");
+
+        let mut out = Wtf8Buf::with_capacity(bytes.len());
+        let mut start = 0;
+        for line in bytes.split(|&b| b == b'\n') {
+            let end = start + line.len();
+            if indent_of(line).is_some() {
+                // every content line starts with the margin by construction
+                out.push_wtf8(&wtf8[start + margin.len()..end]);
+            }
+            // whitespace-only lines contribute nothing but their newline
+            if end < bytes.len() {
+                out.push_str("\n");
+            }
+            start = end + 1;
+        }
+        PyStr::from(out).into_ref(&vm.ctx)
+    }
+
+    // `str.strip()` semantics: Unicode whitespace plus the ASCII separator
+    // control characters CPython's `str.isspace` also accepts
+    fn py_isspace(c: char) -> bool {
+        c.is_whitespace() || matches!(c, '\x1c'..='\x1f')
+    }
+
+    /// Native `textwrap.indent` for the default predicate: prepend `prefix` to
+    /// every line of `text` that contains a non-whitespace character. Line
+    /// boundaries follow `str.splitlines`.
+    #[pyfunction]
+    fn indent(text: PyStrRef, prefix: PyStrRef, vm: &VirtualMachine) -> PyStrRef {
+        let wtf8 = text.as_wtf8();
+        let bytes = wtf8.as_bytes();
+        let prefix = prefix.as_wtf8();
+
+        let mut out = Wtf8Buf::with_capacity(bytes.len());
+        let mut push_line = |start: usize, content_end: usize, end: usize| {
+            // surrogates decode lossily to U+FFFD, which is not whitespace,
+            // so the predicate still sees them as content
+            let has_content = String::from_utf8_lossy(&bytes[start..content_end])
+                .chars()
+                .any(|c| !py_isspace(c));
+            if has_content {
+                out.push_wtf8(prefix);
+            }
+            out.push_wtf8(&wtf8[start..end]);
+        };
+
+        let mut start = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            // the full `str.splitlines` break set; the multi-byte breaks are
+            // NEL, LINE SEPARATOR and PARAGRAPH SEPARATOR
+            let break_len = match bytes[i] {
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => 2,
+                b'\n' | b'\r' | b'\x0b' | b'\x0c' | b'\x1c' | b'\x1d' | b'\x1e' => 1,
+                0xc2 if bytes.get(i + 1) == Some(&0x85) => 2,
+                0xe2 if bytes[i + 1..].starts_with(b"\x80\xa8")
+                    || bytes[i + 1..].starts_with(b"\x80\xa9") =>
+                {
+                    3
+                }
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+            push_line(start, i, i + break_len);
+            i += break_len;
+            start = i;
+        }
+        if start < bytes.len() {
+            push_line(start, bytes.len(), bytes.len());
+        }
+        PyStr::from(out).into_ref(&vm.ctx)
+    }
+}
@@ -17,6 +17,7 @@ use crossbeam_utils::atomic::AtomicCell;
 pub struct PyAsyncGen {
     inner: Coro,
     running_async: AtomicCell<bool>,
+    hooks_inited: AtomicCell<bool>,
 }
 type PyAsyncGenRef = PyRef<PyAsyncGen>;
 
@@ -36,6 +37,7 @@ impl PyAsyncGen {
         PyAsyncGen {
             inner: Coro::new(frame, name),
             running_async: AtomicCell::new(false),
+            hooks_inited: AtomicCell::new(false),
         }
     }
 
@@ -72,6 +74,21 @@ impl PyAsyncGen {
     }
 }
 
+impl Py<PyAsyncGen> {
+    /// Call the `sys.set_asyncgen_hooks` firstiter hook the first time this
+    /// generator is iterated, so event loops can track it for shutdown.
+    fn init_hooks(&self, vm: &VirtualMachine) -> PyResult<()> {
+        if self.hooks_inited.swap(true) {
+            return Ok(());
+        }
+        let firstiter = crate::vm::thread::ASYNC_GEN_FIRSTITER.with(|cell| cell.borrow().clone());
+        if let Some(firstiter) = firstiter {
+            firstiter.call((self.to_owned(),), vm)?;
+        }
+        Ok(())
+    }
+}
+
 #[pyclass]
 impl PyRef<PyAsyncGen> {
     #[pymethod(magic)]
@@ -80,17 +97,18 @@ impl PyRef<PyAsyncGen> {
     }
 
     #[pymethod(magic)]
-    fn anext(self, vm: &VirtualMachine) -> PyAsyncGenASend {
+    fn anext(self, vm: &VirtualMachine) -> PyResult<PyAsyncGenASend> {
         Self::asend(self, vm.ctx.none(), vm)
     }
 
     #[pymethod]
-    fn asend(self, value: PyObjectRef, _vm: &VirtualMachine) -> PyAsyncGenASend {
-        PyAsyncGenASend {
+    fn asend(self, value: PyObjectRef, vm: &VirtualMachine) -> PyResult<PyAsyncGenASend> {
+        self.init_hooks(vm)?;
+        Ok(PyAsyncGenASend {
             ag: self,
             state: AtomicCell::new(AwaitableState::Init),
             value,
-        }
+        })
     }
 
     #[pymethod]
@@ -100,8 +118,9 @@ impl PyRef<PyAsyncGen> {
         exc_val: OptionalArg,
         exc_tb: OptionalArg,
         vm: &VirtualMachine,
-    ) -> PyAsyncGenAThrow {
-        PyAsyncGenAThrow {
+    ) -> PyResult<PyAsyncGenAThrow> {
+        self.init_hooks(vm)?;
+        Ok(PyAsyncGenAThrow {
             ag: self,
             aclose: false,
             state: AtomicCell::new(AwaitableState::Init),
@@ -110,12 +129,13 @@ impl PyRef<PyAsyncGen> {
                 exc_val.unwrap_or_none(vm),
                 exc_tb.unwrap_or_none(vm),
             ),
-        }
+        })
     }
 
     #[pymethod]
-    fn aclose(self, vm: &VirtualMachine) -> PyAsyncGenAThrow {
-        PyAsyncGenAThrow {
+    fn aclose(self, vm: &VirtualMachine) -> PyResult<PyAsyncGenAThrow> {
+        self.init_hooks(vm)?;
+        Ok(PyAsyncGenAThrow {
             ag: self,
             aclose: true,
             state: AtomicCell::new(AwaitableState::Init),
@@ -124,7 +144,7 @@ impl PyRef<PyAsyncGen> {
                 vm.ctx.none(),
                 vm.ctx.none(),
             ),
-        }
+        })
     }
 }
 
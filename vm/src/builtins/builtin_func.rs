@@ -69,7 +69,7 @@ impl Callable for PyNativeFunction {
         if let Some(z) = &zelf.zelf {
             args.prepend_arg(z.clone());
         }
-        (zelf.value.func)(vm, args)
+        crate::vm::catch_native_panic(vm, || (zelf.value.func)(vm, args))
     }
 }
 
@@ -107,10 +107,25 @@ impl PyNativeFunction {
     }
     #[pygetset(magic)]
     fn doc(zelf: NativeFunctionOrMethod) -> Option<&'static str> {
-        zelf.0.value.doc
+        let doc = zelf.0.value.doc?;
+        let doc = type_::get_doc_without_signature(zelf.0.value.name, doc);
+        (!doc.is_empty()).then_some(doc)
     }
     #[pygetset(name = "__self__")]
-    fn __self__(_zelf: PyObjectRef, vm: &VirtualMachine) -> PyObjectRef {
+    fn __self__(zelf: NativeFunctionOrMethod, vm: &VirtualMachine) -> PyObjectRef {
+        let zelf = zelf.0;
+        if let Some(bound) = zelf.get_self() {
+            return bound.clone();
+        }
+        // module-level function: report the owning module like CPython, so
+        // inspect.signature can drop the leading $module parameter
+        if let Some(module) = zelf.module {
+            if let Ok(modules) = vm.sys_module.get_attr("modules", vm) {
+                if let Ok(module) = modules.get_item(module.as_str(), vm) {
+                    return module;
+                }
+            }
+        }
         vm.ctx.none()
     }
     #[pymethod(magic)]
@@ -225,7 +240,7 @@ impl Callable for PyNativeMethod {
         if let Some(zelf) = &zelf.func.zelf {
             args.prepend_arg(zelf.clone());
         }
-        (zelf.func.value.func)(vm, args)
+        crate::vm::catch_native_panic(vm, || (zelf.func.value.func)(vm, args))
     }
 }
 
@@ -212,7 +212,8 @@ impl PyByteArray {
 
     #[pymethod(magic)]
     fn sizeof(&self) -> usize {
-        size_of::<Self>() + self.borrow_buf().len() * size_of::<u8>()
+        // report the allocated capacity, not just the occupied length
+        size_of::<Self>() + self.inner().capacity() * size_of::<u8>()
     }
 
     #[pymethod(magic)]
@@ -205,6 +205,26 @@ impl PyCode {
     pub fn new(code: CodeObject) -> PyCode {
         PyCode { code }
     }
+
+    /// Serialize into the versioned envelope of
+    /// [`rustpython_compiler_core::marshal::serialize_code_versioned`],
+    /// suitable for an external bytecode cache or for sending code to
+    /// another process.
+    pub fn serialize(&self) -> Vec<u8> {
+        rustpython_compiler_core::marshal::serialize_code_versioned(&self.code)
+    }
+
+    /// Rebuild a code object serialized by [`PyCode::serialize`]. Fails
+    /// cleanly when the data was written by an incompatible marshal format
+    /// version.
+    pub fn deserialize(
+        data: &[u8],
+        ctx: &Context,
+    ) -> Result<Self, rustpython_compiler_core::marshal::MarshalError> {
+        let code =
+            rustpython_compiler_core::marshal::deserialize_code_versioned(data, PyObjBag(ctx))?;
+        Ok(Self { code })
+    }
 }
 
 impl fmt::Debug for PyCode {
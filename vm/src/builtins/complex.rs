@@ -115,7 +115,25 @@ fn inner_div(v1: Complex64, v2: Complex64, vm: &VirtualMachine) -> PyResult<Comp
         return Err(vm.new_zero_division_error("complex division by zero".to_owned()));
     }
 
-    Ok(v1.fdiv(v2))
+    // Smith's 1962 algorithm, like CPython's _Py_c_quot: scale by the larger
+    // component of the divisor so huge/tiny components don't overflow or
+    // underflow to nan when the naive formula would.
+    let (re, im) = if v2.re.abs() >= v2.im.abs() {
+        let ratio = v2.im / v2.re;
+        let denom = v2.re + v2.im * ratio;
+        (
+            (v1.re + v1.im * ratio) / denom,
+            (v1.im - v1.re * ratio) / denom,
+        )
+    } else {
+        let ratio = v2.re / v2.im;
+        let denom = v2.re * ratio + v2.im;
+        (
+            (v1.re * ratio + v1.im) / denom,
+            (v1.im * ratio - v1.re) / denom,
+        )
+    };
+    Ok(Complex64::new(re, im))
 }
 
 fn inner_pow(v1: Complex64, v2: Complex64, vm: &VirtualMachine) -> PyResult<Complex64> {
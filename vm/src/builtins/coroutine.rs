@@ -1,12 +1,13 @@
-use super::{PyCode, PyStrRef, PyType};
+use super::{PyCode, PyStrRef, PyTupleRef, PyType};
 use crate::{
     AsObject, Context, Py, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
     class::PyClassImpl,
+    convert::ToPyObject,
     coroutine::Coro,
     frame::FrameRef,
     function::OptionalArg,
     protocol::PyIterReturn,
-    types::{IterNext, Iterable, Representable, SelfIter, Unconstructible},
+    types::{Destructor, IterNext, Iterable, Representable, SelfIter, Unconstructible},
 };
 
 #[pyclass(module = false, name = "coroutine")]
@@ -14,6 +15,10 @@ use crate::{
 // PyCoro_Type in CPython
 pub struct PyCoroutine {
     inner: Coro,
+    /// creation call stack captured when
+    /// `sys.set_coroutine_origin_tracking_depth` is in effect, outermost
+    /// frame last
+    origin: Option<Box<[(PyStrRef, usize, PyStrRef)]>>,
 }
 
 impl PyPayload for PyCoroutine {
@@ -22,15 +27,32 @@ impl PyPayload for PyCoroutine {
     }
 }
 
-#[pyclass(with(Py, Unconstructible, IterNext, Representable))]
+#[pyclass(with(Py, Unconstructible, IterNext, Representable, Destructor))]
 impl PyCoroutine {
     pub fn as_coro(&self) -> &Coro {
         &self.inner
     }
 
-    pub fn new(frame: FrameRef, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, name: PyStrRef, vm: &VirtualMachine) -> Self {
+        let depth = crate::vm::thread::COROUTINE_ORIGIN_TRACKING_DEPTH.with(|cell| cell.get());
+        let origin = (depth > 0).then(|| {
+            vm.frames
+                .borrow()
+                .iter()
+                .rev()
+                .take(depth as usize)
+                .map(|frame| {
+                    (
+                        frame.code.source_path.to_owned(),
+                        frame.current_location().row.get(),
+                        frame.code.obj_name.to_owned(),
+                    )
+                })
+                .collect()
+        });
         PyCoroutine {
             inner: Coro::new(frame, name),
+            origin,
         }
     }
 
@@ -65,11 +87,18 @@ impl PyCoroutine {
     fn cr_code(&self, _vm: &VirtualMachine) -> PyRef<PyCode> {
         self.inner.frame().code.clone()
     }
-    // TODO: coroutine origin tracking:
     // https://docs.python.org/3/library/sys.html#sys.set_coroutine_origin_tracking_depth
     #[pygetset]
-    fn cr_origin(&self, _vm: &VirtualMachine) -> Option<(PyStrRef, usize, PyStrRef)> {
-        None
+    fn cr_origin(&self, vm: &VirtualMachine) -> Option<PyTupleRef> {
+        self.origin.as_ref().map(|origin| {
+            let frames = origin
+                .iter()
+                .map(|(filename, lineno, name)| {
+                    (filename.clone(), *lineno, name.clone()).to_pyobject(vm)
+                })
+                .collect();
+            vm.ctx.new_tuple(frames)
+        })
     }
 }
 
@@ -105,6 +134,19 @@ impl Py<PyCoroutine> {
 
 impl Unconstructible for PyCoroutine {}
 
+impl Destructor for PyCoroutine {
+    fn del(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<()> {
+        // _PyErr_WarnUnawaitedCoroutine: the message and origin formatting
+        // live in warnings.py
+        if !zelf.inner.closed() && zelf.inner.frame().lasti() == 0 {
+            let warnings = vm.import("warnings", 0)?;
+            let warn = warnings.get_attr("_warn_unawaited_coroutine", vm)?;
+            warn.call((zelf.to_owned(),), vm)?;
+        }
+        Ok(())
+    }
+}
+
 impl Representable for PyCoroutine {
     #[inline]
     fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
@@ -119,7 +119,9 @@ impl PyMethodDescriptor {
     }
     #[pygetset(magic)]
     fn doc(&self) -> Option<&'static str> {
-        self.method.doc
+        let doc = self.method.doc?;
+        let doc = type_::get_doc_without_signature(self.method.name, doc);
+        (!doc.is_empty()).then_some(doc)
     }
     #[pygetset(magic)]
     fn text_signature(&self) -> Option<String> {
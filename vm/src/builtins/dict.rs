@@ -140,6 +140,27 @@ impl PyDict {
         self.entries.setdefault(vm, &*key, default)
     }
 
+    /// Move an existing key to either end of the insertion order.
+    /// Used by collections.OrderedDict.
+    pub(crate) fn inner_move_to_end<K: DictKey + ?Sized>(
+        &self,
+        key: &K,
+        last: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        self.entries.move_to_end(vm, key, last)
+    }
+
+    /// Retrieve and delete either the last or the first entry in insertion
+    /// order. Used by collections.OrderedDict.
+    pub(crate) fn inner_popitem(&self, last: bool) -> Option<(PyObjectRef, PyObjectRef)> {
+        if last {
+            self.entries.pop_back()
+        } else {
+            self.entries.pop_front()
+        }
+    }
+
     pub fn from_attributes(attrs: PyAttributes, vm: &VirtualMachine) -> PyResult<Self> {
         let entries = DictContentType::default();
 
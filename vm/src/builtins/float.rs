@@ -212,7 +212,10 @@ impl PyFloat {
     #[pymethod(magic)]
     fn format(&self, spec: PyStrRef, vm: &VirtualMachine) -> PyResult<String> {
         FormatSpec::parse(spec.as_str())
-            .and_then(|format_spec| format_spec.format_float(self.value))
+            .and_then(|format_spec| {
+                let locale = crate::format::numeric_locale(&format_spec);
+                format_spec.format_float_locale(self.value, &locale)
+            })
             .map_err(|err| err.into_pyexception(vm))
     }
 
@@ -429,7 +432,10 @@ impl PyFloat {
             vm.ctx.new_float(float).into()
         } else {
             let fract = self.value.fract();
-            let value = if (fract.abs() - 0.5).abs() < f64::EPSILON {
+            // floats are exact values, so only a true half is a tie; anything
+            // within an ulp of it must round like any other value
+            #[allow(clippy::float_cmp)]
+            let value = if fract.abs() == 0.5 {
                 if self.value.trunc() % 2.0 == 0.0 {
                     self.value - fract
                 } else {
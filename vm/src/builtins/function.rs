@@ -5,9 +5,7 @@ use super::{
     PyAsyncGen, PyCode, PyCoroutine, PyDictRef, PyGenerator, PyStr, PyStrRef, PyTupleRef, PyType,
     PyTypeRef, tuple::PyTupleTyped,
 };
-#[cfg(feature = "jit")]
-use crate::common::lock::OnceCell;
-use crate::common::lock::PyMutex;
+use crate::common::lock::{OnceCell, PyMutex};
 use crate::convert::ToPyObject;
 use crate::function::ArgMapping;
 use crate::object::{Traverse, TraverseFn};
@@ -38,6 +36,9 @@ pub struct PyFunction {
     type_params: PyMutex<PyTupleRef>,
     #[cfg(feature = "jit")]
     jitted_code: OnceCell<CompiledCode>,
+    // lazily-built map from parameter name to fastlocals slot, so keyword
+    // binding doesn't rescan `varnames` on every call
+    arg_index: OnceCell<std::collections::HashMap<&'static str, usize>>,
     annotations: PyMutex<PyDictRef>,
     module: PyMutex<PyObjectRef>,
     doc: PyMutex<PyObjectRef>,
@@ -76,12 +77,27 @@ impl PyFunction {
             type_params: PyMutex::new(type_params),
             #[cfg(feature = "jit")]
             jitted_code: OnceCell::new(),
+            arg_index: OnceCell::new(),
             annotations: PyMutex::new(annotations),
             module: PyMutex::new(module),
             doc: PyMutex::new(doc),
         }
     }
 
+    fn arg_index(&self) -> &std::collections::HashMap<&'static str, usize> {
+        self.arg_index.get_or_init(|| {
+            let code = &*self.code;
+            let total_args = code.arg_count as usize + code.kwonlyarg_count as usize;
+            code.varnames
+                .iter()
+                .copied()
+                .take(total_args)
+                .enumerate()
+                .map(|(i, s)| (s.as_str(), i))
+                .collect()
+        })
+    }
+
     fn fill_locals_from_args(
         &self,
         frame: &Frame,
@@ -141,40 +157,40 @@ impl PyFunction {
             None
         };
 
-        let arg_pos = |range: std::ops::Range<_>, name: &str| {
-            code.varnames
-                .iter()
-                .enumerate()
-                .skip(range.start)
-                .take(range.end - range.start)
-                .find(|(_, s)| s.as_str() == name)
-                .map(|(p, _)| p)
-        };
+        let arg_index = self.arg_index();
+        let posonly_count = code.posonlyarg_count as usize;
 
         let mut posonly_passed_as_kwarg = Vec::new();
         // Handle keyword arguments
         for (name, value) in func_args.kwargs {
             // Check if we have a parameter with this name:
-            if let Some(pos) = arg_pos(code.posonlyarg_count as usize..total_args, &name) {
-                let slot = &mut fastlocals[pos];
-                if slot.is_some() {
-                    return Err(vm.new_type_error(format!(
-                        "{}() got multiple values for argument '{}'",
-                        self.qualname(),
-                        name
-                    )));
+            let pos = arg_index.get(name.as_str()).copied();
+            match pos {
+                Some(pos) if pos >= posonly_count => {
+                    let slot = &mut fastlocals[pos];
+                    if slot.is_some() {
+                        return Err(vm.new_type_error(format!(
+                            "{}() got multiple values for argument '{}'",
+                            self.qualname(),
+                            name
+                        )));
+                    }
+                    *slot = Some(value);
+                }
+                _ => {
+                    if let Some(kwargs) = kwargs.as_ref() {
+                        // positional-only names are legitimate **kwargs keys
+                        kwargs.set_item(&name, value, vm)?;
+                    } else if pos.is_some() {
+                        posonly_passed_as_kwarg.push(name);
+                    } else {
+                        return Err(vm.new_type_error(format!(
+                            "{}() got an unexpected keyword argument '{}'",
+                            self.qualname(),
+                            name
+                        )));
+                    }
                 }
-                *slot = Some(value);
-            } else if let Some(kwargs) = kwargs.as_ref() {
-                kwargs.set_item(&name, value, vm)?;
-            } else if arg_pos(0..code.posonlyarg_count as usize, &name).is_some() {
-                posonly_passed_as_kwarg.push(name);
-            } else {
-                return Err(vm.new_type_error(format!(
-                    "{}() got an unexpected keyword argument '{}'",
-                    self.qualname(),
-                    name
-                )));
             }
         }
         if !posonly_passed_as_kwarg.is_empty() {
@@ -343,7 +359,7 @@ impl PyFunction {
         let is_coro = code.flags.contains(bytecode::CodeFlags::IS_COROUTINE);
         match (is_gen, is_coro) {
             (true, false) => Ok(PyGenerator::new(frame, self.name()).into_pyobject(vm)),
-            (false, true) => Ok(PyCoroutine::new(frame, self.name()).into_pyobject(vm)),
+            (false, true) => Ok(PyCoroutine::new(frame, self.name(), vm).into_pyobject(vm)),
             (true, true) => Ok(PyAsyncGen::new(frame, self.name()).into_pyobject(vm)),
             (false, false) => vm.run_frame(frame),
         }
@@ -578,7 +578,10 @@ impl PyInt {
     #[pymethod(magic)]
     fn format(&self, spec: PyStrRef, vm: &VirtualMachine) -> PyResult<String> {
         FormatSpec::parse(spec.as_str())
-            .and_then(|format_spec| format_spec.format_int(&self.value))
+            .and_then(|format_spec| {
+                let locale = crate::format::numeric_locale(&format_spec);
+                format_spec.format_int_locale(&self.value, &locale)
+            })
             .map_err(|err| err.into_pyexception(vm))
     }
 
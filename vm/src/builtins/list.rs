@@ -71,6 +71,7 @@ impl PyList {
     }
 
     pub fn borrow_vec_mut(&self) -> PyRwLockWriteGuard<'_, Vec<PyObjectRef>> {
+        crate::vm::tsan::trace_mutation(self, "list");
         self.elements.write()
     }
 
@@ -174,6 +174,11 @@ impl PyModule {
             .as_object()
             .dict()
             .ok_or_else(|| vm.new_value_error("module has no dict".to_owned()))?;
+        // PEP 562: a module-level __dir__ takes precedence over the default
+        if let Ok(dir_hook) = dict.get_item(identifier!(vm, __dir__), vm) {
+            let attrs = dir_hook.call((), vm)?;
+            return attrs.try_to_value(vm);
+        }
         let attrs = dict.into_iter().map(|(k, _v)| k).collect();
         Ok(attrs)
     }
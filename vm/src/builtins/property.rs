@@ -18,6 +18,9 @@ pub struct PyProperty {
     setter: PyRwLock<Option<PyObjectRef>>,
     deleter: PyRwLock<Option<PyObjectRef>>,
     doc: PyRwLock<Option<PyObjectRef>>,
+    /// whether doc was copied from the getter, so copies re-derive it
+    #[pytraverse(skip)]
+    getter_doc: PyRwLock<bool>,
     name: PyRwLock<Option<PyObjectRef>>,
 }
 
@@ -143,36 +146,54 @@ impl PyProperty {
 
     // Python builder functions
 
-    #[pymethod]
-    fn getter(
+    /// Copy the property, replacing the given accessors, like CPython's
+    /// property_copy. The docstring and name carry over, except that a
+    /// docstring inherited from the old getter is re-derived from the new one.
+    fn copy(
         zelf: PyRef<Self>,
         getter: Option<PyObjectRef>,
+        setter: Option<PyObjectRef>,
+        deleter: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
+        let getter = getter.or_else(|| zelf.fget());
+        let (doc, getter_doc) = if *zelf.getter_doc.read() {
+            let doc = getter
+                .as_ref()
+                .and_then(|getter| getter.get_attr(identifier!(vm, __doc__), vm).ok())
+                .filter(|doc| !vm.is_none(doc));
+            let getter_doc = doc.is_some();
+            (doc, getter_doc)
+        } else {
+            (zelf.doc_getter(), false)
+        };
         PyProperty {
-            getter: PyRwLock::new(getter.or_else(|| zelf.fget())),
-            setter: PyRwLock::new(zelf.fset()),
-            deleter: PyRwLock::new(zelf.fdel()),
-            doc: PyRwLock::new(None),
-            name: PyRwLock::new(None),
+            getter: PyRwLock::new(getter),
+            setter: PyRwLock::new(setter.or_else(|| zelf.fset())),
+            deleter: PyRwLock::new(deleter.or_else(|| zelf.fdel())),
+            doc: PyRwLock::new(doc),
+            getter_doc: PyRwLock::new(getter_doc),
+            name: PyRwLock::new(zelf.name.read().clone()),
         }
         .into_ref_with_type(vm, zelf.class().to_owned())
     }
 
+    #[pymethod]
+    fn getter(
+        zelf: PyRef<Self>,
+        getter: Option<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyRef<Self>> {
+        Self::copy(zelf, getter, None, None, vm)
+    }
+
     #[pymethod]
     fn setter(
         zelf: PyRef<Self>,
         setter: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
-        PyProperty {
-            getter: PyRwLock::new(zelf.fget()),
-            setter: PyRwLock::new(setter.or_else(|| zelf.fset())),
-            deleter: PyRwLock::new(zelf.fdel()),
-            doc: PyRwLock::new(None),
-            name: PyRwLock::new(None),
-        }
-        .into_ref_with_type(vm, zelf.class().to_owned())
+        Self::copy(zelf, None, setter, None, vm)
     }
 
     #[pymethod]
@@ -181,14 +202,7 @@ impl PyProperty {
         deleter: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
-        PyProperty {
-            getter: PyRwLock::new(zelf.fget()),
-            setter: PyRwLock::new(zelf.fset()),
-            deleter: PyRwLock::new(deleter.or_else(|| zelf.fdel())),
-            doc: PyRwLock::new(None),
-            name: PyRwLock::new(None),
-        }
-        .into_ref_with_type(vm, zelf.class().to_owned())
+        Self::copy(zelf, None, None, deleter, vm)
     }
 
     #[pygetset(magic)]
@@ -227,6 +241,7 @@ impl Constructor for PyProperty {
             setter: PyRwLock::new(None),
             deleter: PyRwLock::new(None),
             doc: PyRwLock::new(None),
+            getter_doc: PyRwLock::new(false),
             name: PyRwLock::new(None),
         }
         .into_ref_with_type(vm, cls)
@@ -237,11 +252,26 @@ impl Constructor for PyProperty {
 impl Initializer for PyProperty {
     type Args = PropertyArgs;
 
-    fn init(zelf: PyRef<Self>, args: Self::Args, _vm: &VirtualMachine) -> PyResult<()> {
+    fn init(zelf: PyRef<Self>, args: Self::Args, vm: &VirtualMachine) -> PyResult<()> {
+        // with no explicit doc, inherit the getter's docstring, like
+        // CPython's property_init
+        let (doc, getter_doc) = match (args.doc, &args.fget) {
+            (Some(doc), _) => (Some(doc), false),
+            (None, Some(fget)) => {
+                let doc = fget
+                    .get_attr(identifier!(vm, __doc__), vm)
+                    .ok()
+                    .filter(|doc| !vm.is_none(doc));
+                let getter_doc = doc.is_some();
+                (doc, getter_doc)
+            }
+            (None, None) => (None, false),
+        };
         *zelf.getter.write() = args.fget;
         *zelf.setter.write() = args.fset;
         *zelf.deleter.write() = args.fdel;
-        *zelf.doc.write() = args.doc;
+        *zelf.doc.write() = doc;
+        *zelf.getter_doc.write() = getter_doc;
         *zelf.name.write() = args.name.map(|a| a.as_object().to_owned());
 
         Ok(())
@@ -154,9 +154,12 @@ impl PySuper {
 impl GetAttr for PySuper {
     fn getattro(zelf: &Py<Self>, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
         let skip = |zelf: &Py<Self>, name| zelf.as_object().generic_getattr(name, vm);
-        let (obj, start_type): (PyObjectRef, PyTypeRef) = match &zelf.inner.read().obj {
-            Some(o) => o.clone(),
-            None => return skip(zelf, name),
+        let (obj, start_type, typ): (PyObjectRef, PyTypeRef, PyTypeRef) = {
+            let inner = zelf.inner.read();
+            match &inner.obj {
+                Some((obj, obj_type)) => (obj.clone(), obj_type.clone(), inner.typ.clone()),
+                None => return skip(zelf, name),
+            }
         };
         // We want __class__ to return the class of the super object
         // (i.e. super, or a subclass), not the class of su->obj.
@@ -167,13 +170,12 @@ impl GetAttr for PySuper {
 
         if let Some(name) = vm.ctx.interned_str(name) {
             // skip the classes in start_type.mro up to and including zelf.typ
-            let mro: Vec<PyRef<PyType>> = start_type.mro_map_collect(|x| x.to_owned());
-            let mro: Vec<_> = mro
+            let mro: Vec<PyTypeRef> = start_type.mro_map_collect(|x| x.to_owned());
+            let mro = mro
                 .iter()
-                .skip_while(|cls| !cls.is(&zelf.inner.read().typ))
-                .skip(1) // skip su->type (if any)
-                .collect();
-            for cls in mro.iter() {
+                .skip_while(|cls| !cls.is(&typ))
+                .skip(1); // skip su->type (if any)
+            for cls in mro {
                 if let Some(descr) = cls.get_direct_attr(name) {
                     return vm
                         .call_get_descriptor_specific(
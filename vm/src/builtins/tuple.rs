@@ -278,6 +278,11 @@ impl PyTuple {
         self.elements.is_empty()
     }
 
+    #[pymethod(magic)]
+    fn sizeof(&self) -> usize {
+        std::mem::size_of::<Self>() + self.elements.len() * std::mem::size_of::<PyObjectRef>()
+    }
+
     #[pymethod(name = "__rmul__")]
     #[pymethod(magic)]
     fn mul(zelf: PyRef<Self>, value: ArgSize, vm: &VirtualMachine) -> PyResult<PyRef<Self>> {
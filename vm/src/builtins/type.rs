@@ -1061,6 +1061,17 @@ pub(crate) fn get_text_signature_from_internal_doc<'a>(
     find_signature(name, internal_doc).and_then(get_signature)
 }
 
+/// Strip the embedded signature header (`name(args)\n--\n\n`) from an
+/// internal doc, like CPython's `_PyType_DocWithoutSignature`.
+pub(crate) fn get_doc_without_signature<'a>(name: &str, internal_doc: &'a str) -> &'a str {
+    if find_signature(name, internal_doc).is_some() {
+        if let Some(index) = internal_doc.find(SIGNATURE_END_MARKER) {
+            return &internal_doc[index + SIGNATURE_END_MARKER.len()..];
+        }
+    }
+    internal_doc
+}
+
 impl GetAttr for PyType {
     fn getattro(zelf: &Py<Self>, name_str: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
         #[cold]
@@ -1324,25 +1335,7 @@ pub(super) fn or_(zelf: PyObjectRef, other: PyObjectRef, vm: &VirtualMachine) ->
     union_::make_union(&tuple, vm)
 }
 
-fn take_next_base(bases: &mut [Vec<PyTypeRef>]) -> Option<PyTypeRef> {
-    for base in bases.iter() {
-        let head = base[0].clone();
-        if !bases.iter().any(|x| x[1..].iter().any(|x| x.is(&head))) {
-            // Remove from other heads.
-            for item in bases.iter_mut() {
-                if item[0].is(&head) {
-                    item.remove(0);
-                }
-            }
-
-            return Some(head);
-        }
-    }
-
-    None
-}
-
-fn linearise_mro(mut bases: Vec<Vec<PyTypeRef>>) -> Result<Vec<PyTypeRef>, String> {
+fn linearise_mro(bases: Vec<Vec<PyTypeRef>>) -> Result<Vec<PyTypeRef>, String> {
     vm_trace!("Linearise MRO: {:?}", bases);
     // Python requires that the class direct bases are kept in the same order.
     // This is called local precedence ordering.
@@ -1362,20 +1355,52 @@ fn linearise_mro(mut bases: Vec<Vec<PyTypeRef>>) -> Result<Vec<PyTypeRef>, Strin
         }
     }
 
+    // Merge with per-list head indices rather than removing merged classes
+    // from the fronts of the lists, so each step is a scan without shifting.
+    let mut heads = vec![0usize; bases.len()];
     let mut result = vec![];
-    while !bases.is_empty() {
-        let head = take_next_base(&mut bases).ok_or_else(|| {
-            // Take the head class of each class here. Now that we have reached the problematic bases.
-            // Because this failed, we assume the lists cannot be empty.
-            format!(
-                "Cannot create a consistent method resolution order (MRO) for bases {}",
-                bases.iter().map(|x| x.first().unwrap()).format(", ")
-            )
-        })?;
-
-        result.push(head);
-
-        bases.retain(|x| !x.is_empty());
+    loop {
+        let mut next = None;
+        let mut exhausted = true;
+        'candidates: for (base_mro, &start) in bases.iter().zip(&heads) {
+            let Some(candidate) = base_mro.get(start) else {
+                continue;
+            };
+            exhausted = false;
+            // a good head appears in no other list's tail
+            for (other_mro, &other_start) in bases.iter().zip(&heads) {
+                if other_mro.len() > other_start + 1
+                    && other_mro[other_start + 1..]
+                        .iter()
+                        .any(|cls| cls.is(candidate))
+                {
+                    continue 'candidates;
+                }
+            }
+            next = Some(candidate.clone());
+            break;
+        }
+        match next {
+            Some(head) => {
+                for (base_mro, start) in bases.iter().zip(&mut heads) {
+                    if base_mro.get(*start).is_some_and(|cls| cls.is(&head)) {
+                        *start += 1;
+                    }
+                }
+                result.push(head);
+            }
+            None if exhausted => break,
+            None => {
+                return Err(format!(
+                    "Cannot create a consistent method resolution order (MRO) for bases {}",
+                    bases
+                        .iter()
+                        .zip(&heads)
+                        .filter_map(|(base_mro, &start)| base_mro.get(start))
+                        .format(", ")
+                ));
+            }
+        }
     }
     Ok(result)
 }
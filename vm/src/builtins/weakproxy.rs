@@ -4,11 +4,11 @@ use crate::{
     class::PyClassImpl,
     common::hash::PyHash,
     function::{OptionalArg, PyComparisonValue, PySetterValue},
-    protocol::{PyIter, PyIterReturn, PyMappingMethods, PySequenceMethods},
+    protocol::{PyIter, PyIterReturn, PyMappingMethods, PyNumberMethods, PySequenceMethods},
     stdlib::builtins::reversed,
     types::{
-        AsMapping, AsSequence, Comparable, Constructor, GetAttr, Hashable, IterNext, Iterable,
-        PyComparisonOp, Representable, SetAttr,
+        AsMapping, AsNumber, AsSequence, Comparable, Constructor, GetAttr, Hashable, IterNext,
+        Iterable, PyComparisonOp, Representable, SetAttr,
     },
 };
 use std::sync::LazyLock;
@@ -64,6 +64,15 @@ crate::common::static_cell! {
     static WEAK_SUBCLASS: PyTypeRef;
 }
 
+/// Upgrade `obj` if it is a proxy, so that operators are forwarded to the
+/// referent no matter which side of the operation the proxy appears on.
+fn unproxy(obj: &PyObject, vm: &VirtualMachine) -> PyResult {
+    match obj.downcast_ref::<PyWeakProxy>() {
+        Some(proxy) => proxy.try_upgrade(vm),
+        None => Ok(obj.to_owned()),
+    }
+}
+
 #[pyclass(with(
     GetAttr,
     SetAttr,
@@ -71,6 +80,7 @@ crate::common::static_cell! {
     Comparable,
     AsSequence,
     AsMapping,
+    AsNumber,
     Representable,
     IterNext
 ))]
@@ -217,6 +227,71 @@ impl AsMapping for PyWeakProxy {
     }
 }
 
+impl AsNumber for PyWeakProxy {
+    fn as_number() -> &'static PyNumberMethods {
+        macro_rules! forward_binary {
+            ($op:ident) => {
+                Some(|a, b, vm| {
+                    let a = unproxy(a, vm)?;
+                    let b = unproxy(b, vm)?;
+                    vm.$op(&a, &b)
+                })
+            };
+        }
+        macro_rules! forward_unary {
+            ($op:ident) => {
+                Some(|num, vm| {
+                    let obj = unproxy(&num, vm)?;
+                    vm.$op(&obj)
+                })
+            };
+        }
+        static AS_NUMBER: PyNumberMethods = PyNumberMethods {
+            add: forward_binary!(_add),
+            subtract: forward_binary!(_sub),
+            multiply: forward_binary!(_mul),
+            remainder: forward_binary!(_mod),
+            divmod: forward_binary!(_divmod),
+            power: Some(|a, b, c, vm| {
+                let a = unproxy(a, vm)?;
+                let b = unproxy(b, vm)?;
+                let c = unproxy(c, vm)?;
+                vm._pow(&a, &b, &c)
+            }),
+            negative: forward_unary!(_neg),
+            positive: forward_unary!(_pos),
+            absolute: forward_unary!(_abs),
+            boolean: Some(|num, vm| unproxy(&num, vm)?.is_true(vm)),
+            invert: forward_unary!(_invert),
+            lshift: forward_binary!(_lshift),
+            rshift: forward_binary!(_rshift),
+            and: forward_binary!(_and),
+            xor: forward_binary!(_xor),
+            or: forward_binary!(_or),
+            int: Some(|num, vm| unproxy(&num, vm)?.try_int(vm).map(Into::into)),
+            float: Some(|num, vm| unproxy(&num, vm)?.try_float(vm).map(Into::into)),
+            inplace_add: forward_binary!(_iadd),
+            inplace_subtract: forward_binary!(_isub),
+            inplace_multiply: forward_binary!(_imul),
+            inplace_remainder: forward_binary!(_imod),
+            inplace_lshift: forward_binary!(_ilshift),
+            inplace_rshift: forward_binary!(_irshift),
+            inplace_and: forward_binary!(_iand),
+            inplace_xor: forward_binary!(_ixor),
+            inplace_or: forward_binary!(_ior),
+            floor_divide: forward_binary!(_floordiv),
+            true_divide: forward_binary!(_truediv),
+            inplace_floor_divide: forward_binary!(_ifloordiv),
+            inplace_true_divide: forward_binary!(_itruediv),
+            index: Some(|num, vm| unproxy(&num, vm)?.try_index(vm).map(Into::into)),
+            matrix_multiply: forward_binary!(_matmul),
+            inplace_matrix_multiply: forward_binary!(_imatmul),
+            ..PyNumberMethods::NOT_IMPLEMENTED
+        };
+        &AS_NUMBER
+    }
+}
+
 impl Representable for PyWeakProxy {
     #[inline]
     fn repr(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyStrRef> {
@@ -125,7 +125,9 @@ impl TryFromObject for std::time::Duration {
     fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
         use std::time::Duration;
         if let Some(float) = obj.payload::<PyFloat>() {
-            Ok(Duration::from_secs_f64(float.to_f64()))
+            // reject negative, NaN and overflowing values instead of panicking
+            Duration::try_from_secs_f64(float.to_f64())
+                .map_err(|_| vm.new_value_error("value out of range".to_owned()))
         } else if let Some(int) = obj.try_index_opt(vm) {
             let sec = int?
                 .as_bigint()
@@ -75,6 +75,10 @@ impl IndexEntry {
 struct DictInner<T> {
     used: usize,
     filled: usize,
+    /// incremented on every key insertion or removal (but not on value
+    /// replacement), so iterators can detect same-size mutations like
+    /// popitem() followed by an insert
+    version: u64,
     indices: Vec<IndexEntry>,
     entries: Vec<Option<DictEntry<T>>>,
 }
@@ -107,6 +111,7 @@ impl<T> Default for Dict<T> {
             inner: PyRwLock::new(DictInner {
                 used: 0,
                 filled: 0,
+                version: 0,
                 indices: vec![IndexEntry::FREE; 8],
                 entries: Vec::new(),
             }),
@@ -129,6 +134,7 @@ pub struct DictSize {
     pub entries_size: usize,
     pub used: usize,
     filled: usize,
+    version: u64,
 }
 
 struct GenIndexes {
@@ -214,6 +220,7 @@ impl<T> DictInner<T> {
             IndexEntry::from_index_unchecked(entry_index)
         };
         self.used += 1;
+        self.version += 1;
         if let IndexEntry::FREE = index_entry {
             self.filled += 1;
             if let Some(new_size) = self.should_resize() {
@@ -228,6 +235,7 @@ impl<T> DictInner<T> {
             entries_size: self.entries.len(),
             used: self.used,
             filled: self.filled,
+            version: self.version,
         }
     }
 
@@ -257,6 +265,9 @@ impl<T: Clone> Dict<T> {
     }
 
     fn write(&self) -> PyRwLockWriteGuard<'_, DictInner<T>> {
+        // `Dict` backs both dicts and sets, and is also every namespace; the
+        // sanitizer can't tell which from here
+        crate::vm::tsan::trace_mutation(self, "dict or set");
         self.inner.write()
     }
 
@@ -355,6 +366,7 @@ impl<T: Clone> Dict<T> {
             inner.indices.resize(8, IndexEntry::FREE);
             inner.used = 0;
             inner.filled = 0;
+            inner.version += 1;
             // defer dec rc
             std::mem::take(&mut inner.entries)
         };
@@ -646,6 +658,7 @@ impl<T: Clone> Dict<T> {
             inner.indices.get_unchecked_mut(index_index)
         } = IndexEntry::DUMMY;
         inner.used -= 1;
+        inner.version += 1;
         let removed = slot.take();
         Ok(ControlFlow::Break(removed))
     }
@@ -672,6 +685,7 @@ impl<T: Clone> Dict<T> {
             }
         };
         inner.used -= 1;
+        inner.version += 1;
         *unsafe {
             // entry.index always refers valid index
             inner.indices.get_unchecked_mut(entry.index)
@@ -679,6 +693,92 @@ impl<T: Clone> Dict<T> {
         Some((entry.key, entry.value))
     }
 
+    /// Retrieve and delete the first key in insertion order
+    pub fn pop_front(&self) -> Option<(PyObjectRef, T)> {
+        let inner = &mut *self.write();
+        let mut position = 0;
+        let entry = loop {
+            let slot = inner.entries.get_mut(position)?;
+            if let Some(entry) = slot.take() {
+                break entry;
+            }
+            position += 1;
+        };
+        inner.used -= 1;
+        inner.version += 1;
+        *unsafe {
+            // entry.index always refers valid index
+            inner.indices.get_unchecked_mut(entry.index)
+        } = IndexEntry::DUMMY;
+        Some((entry.key, entry.value))
+    }
+
+    /// Move an existing key to the end (or the front) of the insertion order.
+    pub fn move_to_end<K>(&self, vm: &VirtualMachine, key: &K, last: bool) -> PyResult<()>
+    where
+        K: DictKey + ?Sized,
+    {
+        let hash = key.key_hash(vm)?;
+        loop {
+            let (index_entry, index_index) = self.lookup(vm, key, hash, None)?;
+            let Some(entry_index) = index_entry.index() else {
+                return Err(vm.new_key_error(key.to_pyobject(vm)));
+            };
+            let inner = &mut *self.write();
+            let slot = match inner.entries.get_mut(entry_index) {
+                Some(slot) => slot,
+                // The dict was changed since we did lookup. Let's try again.
+                None => continue,
+            };
+            match slot {
+                Some(entry) if entry.index == index_index => {}
+                // The dict was changed since we did lookup. Let's try again.
+                _ => continue,
+            }
+            if last {
+                if entry_index + 1 == inner.entries.len() {
+                    // already at the end
+                    return Ok(());
+                }
+                let entry = slot.take().unwrap();
+                let new_index = inner.entries.len();
+                inner.entries.push(Some(entry));
+                *unsafe {
+                    // index_index is result of lookup
+                    inner.indices.get_unchecked_mut(index_index)
+                } = unsafe {
+                    // new_index is inner.entries.len() - 1
+                    IndexEntry::from_index_unchecked(new_index)
+                };
+            } else {
+                if inner.entries[..entry_index].iter().all(Option::is_none) {
+                    // already at the front
+                    return Ok(());
+                }
+                let entry = slot.take().unwrap();
+                inner.entries.retain(Option::is_some);
+                inner.entries.insert(0, Some(entry));
+                // every entry moved; remap the index table
+                let DictInner {
+                    indices, entries, ..
+                } = inner;
+                for (i, entry) in entries.iter().enumerate() {
+                    if let Some(entry) = entry {
+                        *unsafe {
+                            // entry.index always refers valid index
+                            indices.get_unchecked_mut(entry.index)
+                        } = unsafe {
+                            // i is a valid position in entries
+                            IndexEntry::from_index_unchecked(i)
+                        };
+                    }
+                }
+            }
+            inner.version += 1;
+            return Ok(());
+        }
+    }
+
     pub fn sizeof(&self) -> usize {
         let inner = self.read();
         size_of::<Self>()
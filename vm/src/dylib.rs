@@ -0,0 +1,125 @@
+//! Loading native extension modules compiled as Rust cdylibs.
+//!
+//! An extension crate declares its entry point with [`rustpython_module!`]
+//! and builds as a `cdylib`; the resulting library is discovered through
+//! `sys.path` like a `.so`/`.pyd` file (see `_imp.extension_suffixes`). The
+//! interface is the ordinary `rustpython-vm` API, not a C ABI, so an
+//! extension must be built against the same `rustpython-vm` version, with
+//! the same compiler, as the interpreter loading it — [`ABI_TAG`] is checked
+//! before the entry point runs to turn a mismatch into a clean `ImportError`.
+
+use crate::{PyRef, PyResult, VirtualMachine, builtins::PyModule};
+use std::{path::Path, sync::Mutex};
+
+/// Identifies the embedding interface a library was built against: the
+/// `rustpython-vm` version plus the exact rustc that compiled it (Rust has
+/// no stable ABI, so a different compiler build means a different ABI even
+/// for the same vm version). The loader refuses libraries whose tag differs
+/// from its own. `RUSTC_VERSION` is `rustc -V` output, captured by build.rs.
+pub const ABI_TAG: &str = concat!(
+    "rustpython-vm ",
+    env!("CARGO_PKG_VERSION"),
+    ", ",
+    env!("RUSTC_VERSION"),
+);
+
+/// [`ABI_TAG`] as a byte array, so `rustpython_module!` can export it as a
+/// symbol the loader reads without relying on `&str` layout.
+#[doc(hidden)]
+pub const fn abi_tag_bytes() -> [u8; ABI_TAG.len()] {
+    let src = ABI_TAG.as_bytes();
+    let mut out = [0u8; ABI_TAG.len()];
+    let mut i = 0;
+    while i < out.len() {
+        out[i] = src[i];
+        i += 1;
+    }
+    out
+}
+
+/// The filename suffix native extensions are discovered by. Deliberately
+/// distinct from CPython's plain `.so`/`.pyd` so C extensions on `sys.path`
+/// are never mistaken for RustPython-native ones.
+pub const EXTENSION_SUFFIX: &str = if cfg!(windows) {
+    ".rustpython.pyd"
+} else {
+    ".rustpython.so"
+};
+
+/// Signature of the entry point `rustpython_module!` generates.
+pub type ModuleInitFn = fn(&VirtualMachine) -> PyRef<PyModule>;
+
+/// Define the entry point of a native extension cdylib:
+///
+/// ```ignore
+/// rustpython_vm::rustpython_module!(my_module::make_module);
+/// ```
+///
+/// where the argument is a `fn(&VirtualMachine) -> PyRef<PyModule>`, e.g.
+/// the `make_module` a `#[pymodule]` generates.
+#[macro_export]
+macro_rules! rustpython_module {
+    ($make_module:path) => {
+        #[unsafe(no_mangle)]
+        pub static RUSTPYTHON_ABI_TAG_LEN: usize = $crate::dylib::ABI_TAG.len();
+
+        #[unsafe(no_mangle)]
+        pub static RUSTPYTHON_ABI_TAG: [u8; $crate::dylib::ABI_TAG.len()] =
+            $crate::dylib::abi_tag_bytes();
+
+        #[unsafe(no_mangle)]
+        pub fn rustpython_module_init(
+            vm: &$crate::VirtualMachine,
+        ) -> $crate::PyRef<$crate::builtins::PyModule> {
+            $make_module(vm)
+        }
+    };
+}
+
+/// Load the extension at `path` and run its entry point. The library is
+/// kept loaded for the rest of the process: module objects hand out
+/// function pointers into it, so it can never be safely unloaded.
+pub fn load_module(path: &Path, name: &str, vm: &VirtualMachine) -> PyResult<PyRef<PyModule>> {
+    static LOADED: Mutex<Vec<libloading::Library>> = Mutex::new(Vec::new());
+
+    let import_error = |msg: String| vm.new_import_error(msg, vm.ctx.new_str(name));
+
+    // SAFETY: loading a library runs arbitrary initializers, like dlopen
+    // anywhere else; the ABI tag is checked before any typed symbol is used
+    unsafe {
+        let lib = libloading::Library::new(path)
+            .map_err(|err| import_error(format!("cannot load {}: {err}", path.display())))?;
+        let not_an_extension = || {
+            import_error(format!(
+                "{} is not a RustPython extension module (missing rustpython_module! entry point)",
+                path.display()
+            ))
+        };
+        let tag_len = lib
+            .get::<*const usize>(b"RUSTPYTHON_ABI_TAG_LEN")
+            .map_err(|_| not_an_extension())?;
+        let tag_len = **tag_len;
+        let tag = lib
+            .get::<*const u8>(b"RUSTPYTHON_ABI_TAG")
+            .map_err(|_| not_an_extension())?;
+        // a corrupt library could report any length; don't read off into the
+        // weeds for the error message
+        let tag = (tag_len <= 512).then(|| std::slice::from_raw_parts(*tag, tag_len));
+        if tag != Some(ABI_TAG.as_bytes()) {
+            let theirs = tag
+                .map(|tag| String::from_utf8_lossy(tag).trim().to_owned())
+                .unwrap_or_else(|| "an unrecognized ABI".to_owned());
+            return Err(import_error(format!(
+                "{} was built against {theirs}, this interpreter is {}",
+                path.display(),
+                ABI_TAG.trim()
+            )));
+        }
+        let init = lib.get::<ModuleInitFn>(b"rustpython_module_init").map_err(|err| {
+            import_error(format!("{}: {err}", path.display()))
+        })?;
+        let init = *init;
+        LOADED.lock().unwrap().push(lib);
+        Ok(init(vm))
+    }
+}
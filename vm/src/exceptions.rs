@@ -18,9 +18,41 @@ use crossbeam_utils::atomic::AtomicCell;
 use itertools::Itertools;
 use std::{
     collections::HashSet,
-    io::{self, BufRead, BufReader},
+    io::{self, BufRead, BufReader, IsTerminal},
 };
 
+/// The ANSI palette CPython 3.13's `_colorize` module uses for tracebacks.
+mod ansi {
+    pub(super) const RESET: &str = "\x1b[0m";
+    pub(super) const MAGENTA: &str = "\x1b[35m";
+    pub(super) const BOLD_MAGENTA: &str = "\x1b[1;35m";
+    pub(super) const BOLD_RED: &str = "\x1b[1;31m";
+}
+
+/// Whether tracebacks written to stderr should use ANSI colors.
+///
+/// Follows the precedence CPython 3.13 gives the controlling environment
+/// variables: `PYTHON_COLORS` (`0` or `1`) overrides `FORCE_COLOR`, which
+/// overrides `NO_COLOR`; with none of those set, color is used only when
+/// stderr is a terminal that isn't `dumb`.
+pub(crate) fn should_colorize() -> bool {
+    match std::env::var_os("PYTHON_COLORS") {
+        Some(val) if val == "0" => return false,
+        Some(val) if val == "1" => return true,
+        _ => {}
+    }
+    if std::env::var_os("FORCE_COLOR").is_some() {
+        return true;
+    }
+    if std::env::var_os("NO_COLOR").is_some() {
+        return false;
+    }
+    if std::env::var_os("TERM").is_some_and(|term| term == "dumb") {
+        return false;
+    }
+    io::stderr().is_terminal()
+}
+
 unsafe impl Traverse for PyBaseException {
     fn traverse(&self, tracer_fn: &mut TraverseFn<'_>) {
         self.traceback.traverse(tracer_fn);
@@ -51,14 +83,16 @@ impl VirtualMachine {
     pub fn print_exception(&self, exc: PyBaseExceptionRef) {
         let vm = self;
         let write_fallback = |exc, errstr| {
+            let colorize = should_colorize();
             if let Ok(stderr) = sys::get_stderr(vm) {
                 let mut stderr = py_io::PyWriter(stderr, vm);
                 // if this fails stderr might be closed -- ignore it
                 let _ = writeln!(stderr, "{errstr}");
-                let _ = self.write_exception(&mut stderr, exc);
+                let _ = self.write_exception_colorized(&mut stderr, exc, colorize);
             } else {
                 eprintln!("{errstr}\nlost sys.stderr");
-                let _ = self.write_exception(&mut py_io::IoWriter(io::stderr()), exc);
+                let _ =
+                    self.write_exception_colorized(&mut py_io::IoWriter(io::stderr()), exc, colorize);
             }
         };
         if let Ok(excepthook) = vm.sys_module.get_attr("excepthook", vm) {
@@ -76,9 +110,22 @@ impl VirtualMachine {
         &self,
         output: &mut W,
         exc: &PyBaseExceptionRef,
+    ) -> Result<(), W::Error> {
+        self.write_exception_colorized(output, exc, false)
+    }
+
+    /// Like [`Self::write_exception`], but optionally rendering the traceback
+    /// with ANSI colors. Callers writing anywhere other than a terminal must
+    /// pass `colorize: false`; the stderr printing paths decide via
+    /// [`should_colorize`].
+    pub fn write_exception_colorized<W: Write>(
+        &self,
+        output: &mut W,
+        exc: &PyBaseExceptionRef,
+        colorize: bool,
     ) -> Result<(), W::Error> {
         let seen = &mut HashSet::<usize>::new();
-        self.write_exception_recursive(output, exc, seen)
+        self.write_exception_recursive(output, exc, seen, colorize)
     }
 
     fn write_exception_recursive<W: Write>(
@@ -86,6 +133,7 @@ impl VirtualMachine {
         output: &mut W,
         exc: &PyBaseExceptionRef,
         seen: &mut HashSet<usize>,
+        colorize: bool,
     ) -> Result<(), W::Error> {
         // This function should not be called directly,
         // use `wite_exception` as a public interface.
@@ -114,14 +162,14 @@ impl VirtualMachine {
             None
         } {
             if !seen.contains(&cause_or_context.get_id()) {
-                self.write_exception_recursive(output, &cause_or_context, seen)?;
+                self.write_exception_recursive(output, &cause_or_context, seen, colorize)?;
                 writeln!(output, "{msg}")?;
             } else {
                 seen.insert(cause_or_context.get_id());
             }
         }
 
-        self.write_exception_inner(output, exc)
+        self.write_exception_inner(output, exc, colorize)
     }
 
     /// Print exception with traceback
@@ -129,12 +177,13 @@ impl VirtualMachine {
         &self,
         output: &mut W,
         exc: &PyBaseExceptionRef,
+        colorize: bool,
     ) -> Result<(), W::Error> {
         let vm = self;
         if let Some(tb) = exc.traceback.read().clone() {
             writeln!(output, "Traceback (most recent call last):")?;
             for tb in tb.iter() {
-                write_traceback_entry(output, &tb)?;
+                write_traceback_entry(output, &tb, colorize, vm)?;
             }
         }
 
@@ -144,18 +193,31 @@ impl VirtualMachine {
         let exc_class = exc.class();
 
         if exc_class.fast_issubclass(vm.ctx.exceptions.syntax_error) {
-            return self.write_syntaxerror(output, exc, exc_class, &args_repr);
+            return self.write_syntaxerror(output, exc, exc_class, &args_repr, colorize);
         }
 
         let exc_name = exc_class.name();
+        let (type_color, msg_color, reset) = if colorize {
+            (ansi::BOLD_MAGENTA, ansi::MAGENTA, ansi::RESET)
+        } else {
+            ("", "", "")
+        };
         match args_repr.len() {
-            0 => write!(output, "{exc_name}"),
-            1 => write!(output, "{}: {}", exc_name, args_repr[0]),
+            0 => write!(output, "{type_color}{exc_name}{reset}"),
+            1 => write!(
+                output,
+                "{}{}{}: {}{}{}",
+                type_color, exc_name, reset, msg_color, args_repr[0], reset
+            ),
             _ => write!(
                 output,
-                "{}: ({})",
+                "{}{}{}: ({}{}{})",
+                type_color,
                 exc_name,
+                reset,
+                msg_color,
                 args_repr.into_iter().format(", "),
+                reset,
             ),
         }?;
 
@@ -176,9 +238,21 @@ impl VirtualMachine {
         exc: &PyBaseExceptionRef,
         exc_type: &Py<PyType>,
         args_repr: &[PyRef<PyStr>],
+        colorize: bool,
     ) -> Result<(), W::Error> {
         let vm = self;
         debug_assert!(exc_type.fast_issubclass(vm.ctx.exceptions.syntax_error));
+        let (loc_color, type_color, msg_color, caret_color, reset) = if colorize {
+            (
+                ansi::MAGENTA,
+                ansi::BOLD_MAGENTA,
+                ansi::MAGENTA,
+                ansi::BOLD_RED,
+                ansi::RESET,
+            )
+        } else {
+            ("", "", "", "", "")
+        };
 
         let getattr = |attr: &'static str| exc.as_object().get_attr(attr, vm).ok();
 
@@ -198,12 +272,16 @@ impl VirtualMachine {
         if let Some(lineno) = maybe_lineno {
             writeln!(
                 output,
-                r##"  File "{}", line {}"##,
+                r##"  File {}"{}"{}, line {}{}{}"##,
+                loc_color,
                 maybe_filename
                     .as_ref()
                     .map(|s| s.as_str())
                     .unwrap_or("<string>"),
-                lineno
+                reset,
+                loc_color,
+                lineno,
+                reset,
             )?;
         } else if let Some(filename) = maybe_filename {
             filename_suffix = format!(" ({filename})");
@@ -249,9 +327,11 @@ impl VirtualMachine {
 
                     writeln!(
                         output,
-                        "    {}{}",
+                        "    {}{}{}{}",
                         caret_space,
-                        "^".repeat(error_width as usize)
+                        caret_color,
+                        "^".repeat(error_width as usize),
+                        reset,
                     )?;
                 }
             }
@@ -260,13 +340,21 @@ impl VirtualMachine {
         let exc_name = exc_type.name();
 
         match args_repr.len() {
-            0 => write!(output, "{exc_name}{filename_suffix}"),
-            1 => write!(output, "{}: {}{}", exc_name, args_repr[0], filename_suffix),
+            0 => write!(output, "{type_color}{exc_name}{reset}{filename_suffix}"),
+            1 => write!(
+                output,
+                "{}{}{}: {}{}{}{}",
+                type_color, exc_name, reset, msg_color, args_repr[0], reset, filename_suffix
+            ),
             _ => write!(
                 output,
-                "{}: ({}){}",
+                "{}{}{}: ({}{}{}){}",
+                type_color,
                 exc_name,
+                reset,
+                msg_color,
                 args_repr.iter().format(", "),
+                reset,
                 filename_suffix
             ),
         }?;
@@ -343,7 +431,16 @@ fn print_source_line<W: Write>(
     output: &mut W,
     filename: &str,
     lineno: usize,
+    vm: &VirtualMachine,
 ) -> Result<(), W::Error> {
+    // sources compiled from pseudo-files (<stdin>, <string>, ...) are kept
+    // in the vm-level registry rather than on disk
+    if let Some(source) = vm.state.source_registry.lock().get(filename) {
+        if let Some(line) = source.lines().nth(lineno - 1) {
+            writeln!(output, "    {}", line.trim_start())?;
+        }
+        return Ok(());
+    }
     // TODO: use io.open() method instead, when available, according to https://github.com/python/cpython/blob/main/Python/traceback.c#L393
     // TODO: support different encodings
     let file = match std::fs::File::open(filename) {
@@ -369,16 +466,29 @@ fn print_source_line<W: Write>(
 fn write_traceback_entry<W: Write>(
     output: &mut W,
     tb_entry: &PyTracebackRef,
+    colorize: bool,
+    vm: &VirtualMachine,
 ) -> Result<(), W::Error> {
+    let (color, reset) = if colorize {
+        (ansi::MAGENTA, ansi::RESET)
+    } else {
+        ("", "")
+    };
     let filename = tb_entry.frame.code.source_path.as_str();
     writeln!(
         output,
-        r##"  File "{}", line {}, in {}"##,
+        r##"  File {}"{}"{}, line {}{}{}, in {}{}{}"##,
+        color,
         filename.trim_start_matches(r"\\?\"),
+        reset,
+        color,
         tb_entry.lineno,
-        tb_entry.frame.code.obj_name
+        reset,
+        color,
+        tb_entry.frame.code.obj_name,
+        reset,
     )?;
-    print_source_line(output, filename, tb_entry.lineno.get())?;
+    print_source_line(output, filename, tb_entry.lineno.get(), vm)?;
 
     Ok(())
 }
@@ -59,6 +59,60 @@ impl ToPyException for FormatParseError {
     }
 }
 
+/// The `LC_NUMERIC` data backing the `'n'` format type: a `localeconv`
+/// snapshot when `spec` asks for one, the (default) C locale otherwise.
+pub(crate) fn numeric_locale(spec: &FormatSpec) -> NumericLocale {
+    if spec.is_locale_aware() {
+        current_numeric_locale()
+    } else {
+        NumericLocale::default()
+    }
+}
+
+#[cfg(unix)]
+fn current_numeric_locale() -> NumericLocale {
+    unsafe {
+        let lc = libc::localeconv();
+        if lc.is_null() {
+            return NumericLocale::default();
+        }
+        let string_at = |ptr: *const libc::c_char, default: &str| {
+            if ptr.is_null() {
+                default.to_owned()
+            } else {
+                std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        };
+        let mut grouping = Vec::new();
+        let mut group_ptr = (*lc).grouping as *const libc::c_char;
+        if !group_ptr.is_null() {
+            while *group_ptr != 0 {
+                if *group_ptr == libc::c_char::MAX {
+                    grouping.push(usize::MAX);
+                    break;
+                }
+                grouping.push(*group_ptr as usize);
+                group_ptr = group_ptr.add(1);
+            }
+        }
+        let decimal_point = string_at((*lc).decimal_point, ".");
+        NumericLocale {
+            decimal_point: if decimal_point.is_empty() {
+                ".".to_owned()
+            } else {
+                decimal_point
+            },
+            thousands_sep: string_at((*lc).thousands_sep, ""),
+            grouping,
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn current_numeric_locale() -> NumericLocale {
+    NumericLocale::default()
+}
+
 fn format_internal(
     vm: &VirtualMachine,
     format: &FormatString,
@@ -3,7 +3,8 @@ use crate::protocol::PyMapping;
 use crate::{
     AsObject, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
     builtins::{
-        PyBaseExceptionRef, PyCode, PyCoroutine, PyDict, PyDictRef, PyGenerator, PyList, PySet,
+        PyBaseExceptionRef, PyBoundMethod, PyCode, PyCoroutine, PyDict, PyDictRef, PyGenerator,
+        PyList, PySet,
         PySlice, PyStr, PyStrInterned, PyStrRef, PyTraceback, PyType,
         asyncgenerator::PyAsyncGenWrappedValue,
         function::{PyCell, PyCellRef, PyFunction},
@@ -382,11 +383,11 @@ impl ExecutingFrame<'_> {
                         // 2. Add new entry with current execution position (filename, lineno, code_object) to traceback.
                         // 3. Unwind block stack till appropriate handler is found.
 
-                        let loc = frame.code.locations[idx].clone();
+                        let lineno = frame.code.locations[idx].row;
                         let next = exception.traceback();
                         let new_traceback =
-                            PyTraceback::new(next, frame.object.to_owned(), frame.lasti(), loc.row);
-                        vm_trace!("Adding to traceback: {:?} {:?}", new_traceback, loc.row);
+                            PyTraceback::new(next, frame.object.to_owned(), frame.lasti(), lineno);
+                        vm_trace!("Adding to traceback: {:?} {:?}", new_traceback, lineno);
                         exception.set_traceback(Some(new_traceback.into_ref(&vm.ctx)));
 
                         vm.contextualize_exception(&exception);
@@ -399,7 +400,14 @@ impl ExecutingFrame<'_> {
                         Ok(Some(result)) => break Ok(result),
                         // TODO: append line number to traceback?
                         // traceback.append();
-                        Err(exception) => break Err(exception),
+                        Err(exception) => {
+                            // the exception's traceback keeps this frame
+                            // alive, so drop the operand stack now: any
+                            // temporaries on it (an open file, a held lock)
+                            // must not live as long as the traceback does
+                            self.state.stack.clear();
+                            break Err(exception);
+                        }
                     }
                 }
             }
@@ -913,24 +921,13 @@ impl ExecutingFrame<'_> {
             }
             bytecode::Instruction::SetupWith { end } => {
                 let context_manager = self.pop_value();
-                let error_string = || -> String {
-                    format!(
-                        "'{:.200}' object does not support the context manager protocol",
-                        context_manager.class().name(),
-                    )
-                };
-                let enter_res = vm
-                    .get_special_method(&context_manager, identifier!(vm, __enter__))?
-                    .ok_or_else(|| vm.new_type_error(error_string()))?
-                    .invoke((), vm)?;
-
-                let exit = context_manager
-                    .get_attr(identifier!(vm, __exit__), vm)
-                    .map_err(|_exc| {
-                        vm.new_type_error({
-                            format!("'{} (missed __exit__ method)", error_string())
-                        })
-                    })?;
+                let (exit, enter_res) = self.setup_context_manager(
+                    vm,
+                    context_manager,
+                    identifier!(vm, __enter__),
+                    identifier!(vm, __exit__),
+                    "context manager",
+                )?;
                 self.push_value(exit);
                 self.push_block(BlockType::Finally {
                     handler: end.get(arg),
@@ -940,24 +937,13 @@ impl ExecutingFrame<'_> {
             }
             bytecode::Instruction::BeforeAsyncWith => {
                 let mgr = self.pop_value();
-                let error_string = || -> String {
-                    format!(
-                        "'{:.200}' object does not support the asynchronous context manager protocol",
-                        mgr.class().name(),
-                    )
-                };
-
-                let aenter_res = vm
-                    .get_special_method(&mgr, identifier!(vm, __aenter__))?
-                    .ok_or_else(|| vm.new_type_error(error_string()))?
-                    .invoke((), vm)?;
-                let aexit = mgr
-                    .get_attr(identifier!(vm, __aexit__), vm)
-                    .map_err(|_exc| {
-                        vm.new_type_error({
-                            format!("'{} (missed __aexit__ method)", error_string())
-                        })
-                    })?;
+                let (aexit, aenter_res) = self.setup_context_manager(
+                    vm,
+                    mgr,
+                    identifier!(vm, __aenter__),
+                    identifier!(vm, __aexit__),
+                    "asynchronous context manager",
+                )?;
                 self.push_value(aexit);
                 self.push_value(aenter_res);
 
@@ -1275,6 +1261,19 @@ impl ExecutingFrame<'_> {
                 self.push_value(type_var_tuple);
                 Ok(None)
             }
+            bytecode::Instruction::CheckEgMatch => {
+                let match_type = self.pop_value();
+                let exc = self.pop_value();
+                let (rest, matched) = self.exception_group_match(exc, match_type, vm)?;
+                self.push_value(rest);
+                self.push_value(matched);
+                Ok(None)
+            }
+            bytecode::Instruction::PrepReraiseStar => {
+                let rest = self.pop_value();
+                let excs = self.pop_value();
+                self.prep_reraise_star(rest, excs, vm)
+            }
             bytecode::Instruction::MatchMapping => {
                 // Pop the subject from stack
                 let subject = self.pop_value();
@@ -1372,19 +1371,58 @@ impl ExecutingFrame<'_> {
     fn import_from(&mut self, vm: &VirtualMachine, idx: bytecode::NameIdx) -> PyResult {
         let module = self.top_value();
         let name = self.code.names[idx as usize];
-        let err = || vm.new_import_error(format!("cannot import name '{name}'"), name.to_owned());
         // Load attribute, and transform any error into import error.
         if let Some(obj) = vm.get_attribute_opt(module.to_owned(), name)? {
             return Ok(obj);
         }
-        // fallback to importing '{module.__name__}.{name}' from sys.modules
         let mod_name = module
             .get_attr(identifier!(vm, __name__), vm)
-            .map_err(|_| err())?;
-        let mod_name = mod_name.downcast::<PyStr>().map_err(|_| err())?;
-        let full_mod_name = format!("{mod_name}.{name}");
-        let sys_modules = vm.sys_module.get_attr("modules", vm).map_err(|_| err())?;
-        sys_modules.get_item(&full_mod_name, vm).map_err(|_| err())
+            .ok()
+            .and_then(|name| name.downcast::<PyStr>().ok());
+        if let Some(mod_name) = &mod_name {
+            // fallback to importing '{module.__name__}.{name}' from sys.modules
+            let full_mod_name = format!("{mod_name}.{name}");
+            if let Ok(sys_modules) = vm.sys_module.get_attr("modules", vm) {
+                if let Ok(obj) = sys_modules.get_item(&full_mod_name, vm) {
+                    return Ok(obj);
+                }
+            }
+        }
+        // match CPython's import_from() error messages, including the
+        // circular-import diagnostic based on __spec__._initializing
+        let msg = match &mod_name {
+            Some(mod_name) => {
+                let initializing = vm
+                    .get_attribute_opt(module.to_owned(), "__spec__")
+                    .ok()
+                    .flatten()
+                    .filter(|spec| !vm.is_none(spec))
+                    .and_then(|spec| vm.get_attribute_opt(spec, "_initializing").ok().flatten())
+                    .is_some_and(|initializing| initializing.try_to_bool(vm).unwrap_or(false));
+                if initializing {
+                    format!(
+                        "cannot import name '{name}' from partially initialized module \
+                         '{mod_name}' (most likely due to a circular import)"
+                    )
+                } else {
+                    let pkgpath = vm
+                        .get_attribute_opt(module.to_owned(), "__file__")
+                        .ok()
+                        .flatten()
+                        .and_then(|file| file.downcast::<PyStr>().ok());
+                    match pkgpath {
+                        Some(path) => {
+                            format!("cannot import name '{name}' from '{mod_name}' ({path})")
+                        }
+                        None => format!(
+                            "cannot import name '{name}' from '{mod_name}' (unknown location)"
+                        ),
+                    }
+                }
+            }
+            None => format!("cannot import name '{name}'"),
+        };
+        Err(vm.new_import_error(msg, name.to_owned()))
     }
 
     #[cfg_attr(feature = "flame-it", flame("Frame"))]
@@ -1414,6 +1452,45 @@ impl ExecutingFrame<'_> {
         Ok(())
     }
 
+    /// Set up a `with` or `async with` block: resolve the enter/exit pair on
+    /// the manager's type in one pass, like CPython's BEFORE_WITH, so the
+    /// instance dict and any custom `__getattribute__` are never consulted.
+    /// Both slots are looked up before `__enter__` is invoked, so a missing
+    /// `__exit__` is reported without having entered the manager.
+    /// Returns the bound exit callable and the result of calling enter.
+    fn setup_context_manager(
+        &mut self,
+        vm: &VirtualMachine,
+        mgr: PyObjectRef,
+        enter_name: &'static PyStrInterned,
+        exit_name: &'static PyStrInterned,
+        protocol: &str,
+    ) -> PyResult<(PyObjectRef, PyObjectRef)> {
+        let error_string = |suffix: &str| {
+            format!(
+                "'{:.200}' object does not support the {} protocol{}",
+                mgr.class().name(),
+                protocol,
+                suffix,
+            )
+        };
+        let enter = vm
+            .get_special_method(&mgr, enter_name)?
+            .ok_or_else(|| vm.new_type_error(error_string("")))?;
+        let exit = vm
+            .get_special_method(&mgr, exit_name)?
+            .ok_or_else(|| {
+                vm.new_type_error(error_string(&format!(" (missed {} method)", exit_name.as_str())))
+            })?;
+        let exit = match exit {
+            PyMethod::Function { target, func } => {
+                PyBoundMethod::new_ref(target, func, &vm.ctx).into()
+            }
+            PyMethod::Attribute(attr) => attr,
+        };
+        Ok((exit, enter.invoke((), vm)?))
+    }
+
     /// Unwind blocks.
     /// The reason for unwinding gives a hint on what to do when
     /// unwinding a block.
@@ -2099,6 +2176,95 @@ impl ExecutingFrame<'_> {
         Ok(None)
     }
 
+    /// Split an exception into the subgroup matching `match_type` and the
+    /// non-matching rest, implementing the matching logic of `except*`.
+    /// Returns `(rest, matched)`; either side may be None.
+    fn exception_group_match(
+        &self,
+        exc: PyObjectRef,
+        match_type: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<(PyObjectRef, PyObjectRef)> {
+        // the Python-level BaseExceptionGroup shim replaces the builtin type,
+        // so resolve it through the builtins module
+        let eg_type = vm.builtins.get_attr("BaseExceptionGroup", vm)?;
+        let check_catchable = |cls: &PyObject| -> PyResult<()> {
+            if !cls.is_subclass(vm.ctx.exceptions.base_exception_type.into(), vm)? {
+                return Err(vm.new_type_error(
+                    "catching classes that do not inherit from BaseException is not allowed"
+                        .to_owned(),
+                ));
+            }
+            if cls.is_subclass(&eg_type, vm)? {
+                return Err(vm.new_type_error(
+                    "catching ExceptionGroup with except* is not allowed. Use except instead."
+                        .to_owned(),
+                ));
+            }
+            Ok(())
+        };
+        if let Some(types) = match_type.downcast_ref::<PyTuple>() {
+            for typ in types.iter() {
+                check_catchable(typ)?;
+            }
+        } else {
+            check_catchable(&match_type)?;
+        }
+        if vm.is_none(&exc) {
+            // the whole exception was consumed by a previous clause
+            return Ok((vm.ctx.none(), vm.ctx.none()));
+        }
+        if exc.is_instance(&eg_type, vm)? {
+            let split = vm.call_method(&exc, "split", (match_type,))?;
+            let split: PyTupleRef = split
+                .downcast()
+                .map_err(|_| vm.new_type_error("split() must return a 2-tuple".to_owned()))?;
+            if split.len() != 2 {
+                return Err(vm.new_type_error("split() must return a 2-tuple".to_owned()));
+            }
+            let split = split.as_slice();
+            Ok((split[1].clone(), split[0].clone()))
+        } else if exc.is_instance(&match_type, vm)? {
+            // a naked exception is wrapped so handlers always see a group
+            let excs = vm.ctx.new_tuple(vec![exc]);
+            let wrapped = eg_type.call((vm.ctx.new_str(""), excs), vm)?;
+            Ok((vm.ctx.none(), wrapped))
+        } else {
+            Ok((exc, vm.ctx.none()))
+        }
+    }
+
+    /// Reraise whatever is left over after the `except*` clauses ran: the
+    /// unhandled rest of the original exception plus any exceptions raised
+    /// by the handlers, merged into a group if there is more than one.
+    fn prep_reraise_star(
+        &self,
+        rest: PyObjectRef,
+        excs: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> FrameResult {
+        let excs: PyRef<PyList> = excs
+            .downcast()
+            .unwrap_or_else(|_| unreachable!("PrepReraiseStar expects a list"));
+        let mut all = Vec::with_capacity(excs.borrow_vec().len() + 1);
+        if !vm.is_none(&rest) {
+            all.push(rest);
+        }
+        all.extend(excs.borrow_vec().iter().cloned());
+        let exc = match all.len() {
+            0 => return Ok(None),
+            1 => all.pop().unwrap(),
+            _ => {
+                let eg_type = vm.builtins.get_attr("BaseExceptionGroup", vm)?;
+                eg_type.call((vm.ctx.new_str(""), vm.ctx.new_tuple(all)), vm)?
+            }
+        };
+        let exc = exc
+            .downcast()
+            .map_err(|_| vm.new_type_error("exceptions must derive from BaseException".to_owned()))?;
+        Err(exc)
+    }
+
     #[cfg_attr(feature = "flame-it", flame("Frame"))]
     fn execute_compare(
         &mut self,
@@ -52,6 +52,11 @@ pub mod convert;
 mod coroutine;
 mod dict_inner;
 #[cfg(feature = "rustpython-compiler")]
+#[cfg(all(
+    any(target_os = "linux", target_os = "macos", target_os = "windows"),
+    not(any(target_env = "musl", target_env = "sgx"))
+))]
+pub mod dylib;
 pub mod eval;
 pub mod exceptions;
 pub mod format;
@@ -90,7 +95,7 @@ pub use self::object::{
     AsObject, Py, PyAtomicRef, PyExact, PyObject, PyObjectRef, PyPayload, PyRef, PyRefExact,
     PyResult, PyWeakRef,
 };
-pub use self::vm::{Context, Interpreter, Settings, VirtualMachine};
+pub use self::vm::{Context, Interpreter, PanicPolicy, Settings, VirtualMachine};
 
 pub use rustpython_common as common;
 pub use rustpython_compiler_core::{bytecode, frozen};
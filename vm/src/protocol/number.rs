@@ -61,20 +61,6 @@ impl PyObject {
             Ok(i.to_owned())
         } else if let Some(i) = self.to_number().int(vm).or_else(|| self.try_index_opt(vm)) {
             i
-        } else if let Ok(Some(f)) = vm.get_special_method(self, identifier!(vm, __trunc__)) {
-            warnings::warn(
-                vm.ctx.exceptions.deprecation_warning,
-                "The delegation of int() to __trunc__ is deprecated.".to_owned(),
-                1,
-                vm,
-            )?;
-            let ret = f.invoke((), vm)?;
-            ret.try_index(vm).map_err(|_| {
-                vm.new_type_error(format!(
-                    "__trunc__ returned non-Integral (type {})",
-                    ret.class()
-                ))
-            })
         } else if let Some(s) = self.payload::<PyStr>() {
             try_convert(self, s.as_wtf8().trim().as_bytes(), vm)
         } else if let Some(bytes) = self.payload::<PyBytes>() {
@@ -540,7 +540,9 @@ impl PyObject {
             .mro_find_map(|cls| cls.slots.hash.load())
             .unwrap();
 
-        hash(self, vm)
+        // nested containers (tuples of tuples, ...) hash recursively through
+        // native slots; raise RecursionError instead of overflowing the stack
+        vm.with_recursion("while getting the hash of an object", || hash(self, vm))
     }
 
     // type protocol
@@ -16,6 +16,9 @@ pub enum ReadlineResult {
     Other(OtherError),
 }
 
+/// How many history entries are kept (and written out) by default.
+pub const DEFAULT_MAX_HISTORY_SIZE: usize = 1000;
+
 #[allow(unused)]
 mod basic_readline {
     use super::*;
@@ -28,7 +31,7 @@ mod basic_readline {
     }
 
     impl<H: Helper> Readline<H> {
-        pub fn new(helper: H) -> Self {
+        pub fn new(helper: H, _max_history_size: usize) -> Self {
             Readline { helper }
         }
 
@@ -44,6 +47,10 @@ mod basic_readline {
             Ok(())
         }
 
+        pub fn helper(&self) -> Option<&H> {
+            Some(&self.helper)
+        }
+
         pub fn readline(&mut self, prompt: &str) -> ReadlineResult {
             use std::io::prelude::*;
             print!("{prompt}");
@@ -59,6 +66,11 @@ mod basic_readline {
                 Some(Err(e)) => ReadlineResult::Io(e),
             }
         }
+
+        pub fn readline_with_initial(&mut self, prompt: &str, _initial: &str) -> ReadlineResult {
+            // no line editing here, so the initial text can't be made editable
+            self.readline(prompt)
+        }
     }
 }
 
@@ -75,13 +87,18 @@ mod rustyline_readline {
     }
 
     impl<H: Helper> Readline<H> {
-        pub fn new(helper: H) -> Self {
+        pub fn new(helper: H, max_history_size: usize) -> Self {
             use rustyline::*;
             let mut repl = Editor::with_config(
                 Config::builder()
                     .completion_type(CompletionType::List)
                     .tab_stop(8)
-                    .bracketed_paste(false) // multi-line paste
+                    .max_history_size(max_history_size)
+                    .expect("history size is valid")
+                    // with bracketed paste, a pasted block (blank lines and
+                    // all) is buffered into a single multi-line entry instead
+                    // of being fed to the shell line by line
+                    .bracketed_paste(true)
                     .build(),
             )
             .expect("failed to initialize line editor");
@@ -109,10 +126,18 @@ mod rustyline_readline {
             Ok(())
         }
 
+        pub fn helper(&self) -> Option<&H> {
+            self.repl.helper()
+        }
+
         pub fn readline(&mut self, prompt: &str) -> ReadlineResult {
+            self.readline_with_initial(prompt, "")
+        }
+
+        pub fn readline_with_initial(&mut self, prompt: &str, initial: &str) -> ReadlineResult {
             use rustyline::error::ReadlineError;
             loop {
-                break match self.repl.readline(prompt) {
+                break match self.repl.readline_with_initial(prompt, (initial, "")) {
                     Ok(line) => ReadlineResult::Line(line),
                     Err(ReadlineError::Interrupted) => ReadlineResult::Interrupt,
                     Err(ReadlineError::Eof) => ReadlineResult::Eof,
@@ -136,7 +161,12 @@ pub struct Readline<H: Helper>(readline_inner::Readline<H>);
 
 impl<H: Helper> Readline<H> {
     pub fn new(helper: H) -> Self {
-        Readline(readline_inner::Readline::new(helper))
+        Self::with_max_history_size(helper, DEFAULT_MAX_HISTORY_SIZE)
+    }
+    /// Like [`Readline::new`], but capping how many history entries are kept;
+    /// dropping the oldest ones once the cap is reached (and on save).
+    pub fn with_max_history_size(helper: H, max_history_size: usize) -> Self {
+        Readline(readline_inner::Readline::new(helper, max_history_size))
     }
     pub fn load_history(&mut self, path: &Path) -> OtherResult<()> {
         self.0.load_history(path)
@@ -147,7 +177,15 @@ impl<H: Helper> Readline<H> {
     pub fn add_history_entry(&mut self, entry: &str) -> OtherResult<()> {
         self.0.add_history_entry(entry)
     }
+    /// Access the helper installed at construction, e.g. to feed it state
+    /// that changes between prompts.
+    pub fn helper(&self) -> Option<&H> {
+        self.0.helper()
+    }
     pub fn readline(&mut self, prompt: &str) -> ReadlineResult {
         self.0.readline(prompt)
     }
+    pub fn readline_with_initial(&mut self, prompt: &str, initial: &str) -> ReadlineResult {
+        self.0.readline_with_initial(prompt, initial)
+    }
 }
@@ -34,6 +34,38 @@ impl Scope {
         Scope::new(locals, globals)
     }
 
+    /// A scope for one unit of work (e.g. one request in a web embedding),
+    /// layered over this one: name lookups fall through to this scope's
+    /// globals, while assignments land in a private dict that lives only as
+    /// long as the returned scope. Objects are reference counted, so
+    /// dropping the request scope bulk-frees everything the request
+    /// allocated that didn't escape into a longer-lived object — no GC pass
+    /// needed (reference cycles created by the request are still the gc
+    /// module's problem). Escape explicitly with [`Scope::promote`], or from
+    /// the request code itself with a `global` declaration.
+    pub fn new_request_scope(&self, vm: &VirtualMachine) -> Scope {
+        Scope::new(
+            Some(ArgMapping::from_dict_exact(vm.ctx.new_dict())),
+            self.globals.clone(),
+        )
+    }
+
+    /// Copy `names` out of `request`'s private namespace into this scope's
+    /// globals, keeping those objects alive past the request teardown.
+    /// Raises `KeyError` for a name the request never assigned.
+    pub fn promote<'a>(
+        &self,
+        request: &Scope,
+        names: impl IntoIterator<Item = &'a str>,
+        vm: &VirtualMachine,
+    ) -> crate::PyResult<()> {
+        for name in names {
+            let value = request.locals.get_item(name, vm)?;
+            self.globals.set_item(name, value, vm)?;
+        }
+        Ok(())
+    }
+
     // pub fn get_locals(&self) -> &PyDictRef {
     //     match self.locals.first() {
     //         Some(dict) => dict,
@@ -297,6 +297,7 @@ pub const PY_COMPILE_FLAG_AST_ONLY: i32 = 0x0400;
 // Caveat emptor: These flags are undocumented on purpose and depending
 // on their effect outside the standard library is **unsupported**.
 pub const PY_CF_DONT_IMPLY_DEDENT: i32 = 0x200;
+pub const PY_CF_ALLOW_TOP_LEVEL_AWAIT: i32 = 0x2000;
 pub const PY_CF_ALLOW_INCOMPLETE_INPUT: i32 = 0x4000;
 
 // __future__ flags - sync with Lib/__future__.py
@@ -318,6 +319,7 @@ const CO_FUTURE_ANNOTATIONS: i32 = 0x1000000;
 // Used by builtins::compile() - the summary of all flags
 pub const PY_COMPILE_FLAGS_MASK: i32 = PY_COMPILE_FLAG_AST_ONLY
     | PY_CF_DONT_IMPLY_DEDENT
+    | PY_CF_ALLOW_TOP_LEVEL_AWAIT
     | PY_CF_ALLOW_INCOMPLETE_INPUT
     | CO_NESTED
     | CO_GENERATOR_ALLOWED
@@ -1,4 +1,4 @@
-use super::PY_COMPILE_FLAG_AST_ONLY;
+use super::{PY_CF_ALLOW_TOP_LEVEL_AWAIT, PY_COMPILE_FLAG_AST_ONLY};
 
 #[pymodule]
 pub(crate) mod _ast {
@@ -54,4 +54,7 @@ pub(crate) mod _ast {
 
     #[pyattr(name = "PyCF_ONLY_AST")]
     use super::PY_COMPILE_FLAG_AST_ONLY;
+
+    #[pyattr(name = "PyCF_ALLOW_TOP_LEVEL_AWAIT")]
+    use super::PY_CF_ALLOW_TOP_LEVEL_AWAIT;
 }
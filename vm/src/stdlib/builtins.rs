@@ -201,6 +201,8 @@ mod builtins {
 
                         let mut opts = vm.compile_opts();
                         opts.optimize = optimize;
+                        opts.allow_top_level_await =
+                            !(flags & ast::PY_CF_ALLOW_TOP_LEVEL_AWAIT).is_zero();
 
                         let code = vm
                             .compile_with_opts(
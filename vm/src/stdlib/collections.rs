@@ -7,7 +7,7 @@ mod _collections {
         atomic_func,
         builtins::{
             IterStatus::{Active, Exhausted},
-            PositionIterInternal, PyGenericAlias, PyInt, PyTypeRef,
+            PositionIterInternal, PyDict, PyGenericAlias, PyInt, PyTypeRef,
         },
         common::lock::{PyMutex, PyRwLock, PyRwLockReadGuard, PyRwLockWriteGuard},
         function::{KwArgs, OptionalArg, PyComparisonValue},
@@ -354,6 +354,12 @@ mod _collections {
             self.borrow_deque().len()
         }
 
+        #[pymethod(magic)]
+        fn sizeof(&self) -> usize {
+            std::mem::size_of::<Self>()
+                + self.borrow_deque().capacity() * std::mem::size_of::<PyObjectRef>()
+        }
+
         #[pymethod(magic)]
         fn bool(&self) -> bool {
             !self.borrow_deque().is_empty()
@@ -729,4 +735,176 @@ mod _collections {
             })
         }
     }
+
+    #[pyattr]
+    #[pyclass(name = "OrderedDict", base = "PyDict", module = "collections")]
+    struct PyOrderedDict;
+
+    #[derive(FromArgs)]
+    struct MoveToEndArgs {
+        #[pyarg(positional)]
+        key: PyObjectRef,
+        #[pyarg(any, default = true)]
+        last: bool,
+    }
+
+    #[derive(FromArgs)]
+    struct PopItemArgs {
+        #[pyarg(any, default = true)]
+        last: bool,
+    }
+
+    /// The builtin dict already preserves insertion order, so OrderedDict only
+    /// adds the order-manipulating methods and order-sensitive equality on top
+    /// of it.
+    #[pyclass(flags(BASETYPE))]
+    impl PyOrderedDict {
+        fn as_dict<'a>(zelf: &'a PyObject, vm: &VirtualMachine) -> PyResult<&'a Py<PyDict>> {
+            zelf.downcast_ref::<PyDict>().ok_or_else(|| {
+                vm.new_type_error(format!(
+                    "descriptor requires an 'OrderedDict' object but received a '{}'",
+                    zelf.class().name()
+                ))
+            })
+        }
+
+        #[pymethod]
+        fn move_to_end(zelf: PyObjectRef, args: MoveToEndArgs, vm: &VirtualMachine) -> PyResult<()> {
+            let dict = Self::as_dict(&zelf, vm)?;
+            dict.inner_move_to_end(&*args.key, args.last, vm)
+        }
+
+        #[pymethod]
+        fn popitem(
+            zelf: PyObjectRef,
+            args: PopItemArgs,
+            vm: &VirtualMachine,
+        ) -> PyResult<(PyObjectRef, PyObjectRef)> {
+            let dict = Self::as_dict(&zelf, vm)?;
+            dict.inner_popitem(args.last).ok_or_else(|| {
+                let err_msg = vm.ctx.new_str("dictionary is empty").into();
+                vm.new_key_error(err_msg)
+            })
+        }
+
+        #[pymethod]
+        fn copy(zelf: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            Self::as_dict(&zelf, vm)?;
+            // self.__class__(self), like CPython: the copy of a subclass
+            // stays an instance of that subclass, not a plain dict
+            let cls: PyObjectRef = zelf.class().to_owned().into();
+            cls.call((zelf,), vm)
+        }
+
+        #[pymethod(name = "__or__")]
+        fn or_(zelf: PyObjectRef, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            if other.downcast_ref::<PyDict>().is_none() {
+                return Ok(vm.ctx.not_implemented());
+            }
+            let new = Self::copy(zelf, vm)?;
+            Self::as_dict(&new, vm)?.merge_object(other, vm)?;
+            Ok(new)
+        }
+
+        #[pymethod(name = "__ror__")]
+        fn ror(zelf: PyObjectRef, other: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            if other.downcast_ref::<PyDict>().is_none() {
+                return Ok(vm.ctx.not_implemented());
+            }
+            // other's items first, then ours; the result keeps our class
+            let cls: PyObjectRef = zelf.class().to_owned().into();
+            let new = cls.call((other,), vm)?;
+            Self::as_dict(&new, vm)?.merge_object(zelf, vm)?;
+            Ok(new)
+        }
+
+        #[pymethod(magic)]
+        fn reduce(zelf: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            let dict = Self::as_dict(&zelf, vm)?;
+            let cls: PyObjectRef = zelf.class().to_owned().into();
+            let items: Vec<PyObjectRef> = dict
+                .into_iter()
+                .map(|(key, value)| vm.ctx.new_tuple(vec![key, value]).into())
+                .collect();
+            let items: PyObjectRef = vm.ctx.new_list(items).into();
+            let items = items.get_iter(vm)?;
+            let state = match zelf.dict() {
+                Some(d) if !d.is_empty() => d.into(),
+                _ => vm.ctx.none(),
+            };
+            Ok(vm
+                .ctx
+                .new_tuple(vec![
+                    cls,
+                    vm.ctx.empty_tuple.clone().into(),
+                    state,
+                    vm.ctx.none(),
+                    items.into(),
+                ])
+                .into())
+        }
+
+        #[pymethod(magic)]
+        fn repr(zelf: PyObjectRef, vm: &VirtualMachine) -> PyResult<String> {
+            let dict = Self::as_dict(&zelf, vm)?;
+            let class_name = zelf.class().name().to_owned();
+            let s = if dict.is_empty() {
+                format!("{class_name}()")
+            } else if let Some(_guard) = ReprGuard::enter(vm, &zelf) {
+                let mut parts = Vec::with_capacity(dict.len());
+                for (key, value) in dict {
+                    parts.push(format!("{}: {}", key.repr(vm)?, value.repr(vm)?));
+                }
+                format!("{class_name}({{{}}})", parts.join(", "))
+            } else {
+                "...".to_owned()
+            };
+            Ok(s)
+        }
+
+        #[pymethod(magic)]
+        fn eq(
+            zelf: PyObjectRef,
+            other: PyObjectRef,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyComparisonValue> {
+            let ordered_dict_cls = vm.class("_collections", "OrderedDict");
+            if !other.fast_isinstance(&ordered_dict_cls) {
+                // comparison with a regular dict is order insensitive
+                return Ok(PyComparisonValue::NotImplemented);
+            }
+            let (zelf, other) = (Self::as_dict(&zelf, vm)?, Self::as_dict(&other, vm)?);
+            if zelf.len() != other.len() {
+                return Ok(PyComparisonValue::Implemented(false));
+            }
+            let mut zelf_iter = zelf.into_iter();
+            let mut other_iter = other.into_iter();
+            loop {
+                match (zelf_iter.next(), other_iter.next()) {
+                    (Some((key1, value1)), Some((key2, value2))) => {
+                        if !vm.identical_or_equal(&key1, &key2)?
+                            || !vm.identical_or_equal(&value1, &value2)?
+                        {
+                            return Ok(PyComparisonValue::Implemented(false));
+                        }
+                    }
+                    (None, None) => return Ok(PyComparisonValue::Implemented(true)),
+                    // lengths matched above, but the dict changed underneath us
+                    _ => return Ok(PyComparisonValue::Implemented(false)),
+                }
+            }
+        }
+
+        #[pymethod(magic)]
+        fn ne(
+            zelf: PyObjectRef,
+            other: PyObjectRef,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyComparisonValue> {
+            Ok(match Self::eq(zelf, other, vm)? {
+                PyComparisonValue::Implemented(eq) => PyComparisonValue::Implemented(!eq),
+                PyComparisonValue::NotImplemented => PyComparisonValue::NotImplemented,
+            })
+        }
+    }
 }
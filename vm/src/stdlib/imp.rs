@@ -1,6 +1,7 @@
 use crate::frozen::FrozenModule;
 use crate::{VirtualMachine, builtins::PyBaseExceptionRef};
 pub(crate) use _imp::make_module;
+pub(crate) use lock::reinit_lock_after_fork;
 
 #[cfg(feature = "threading")]
 #[pymodule(sub)]
@@ -28,6 +29,14 @@ mod lock {
     fn lock_held(_vm: &VirtualMachine) -> bool {
         IMP_LOCK.is_locked()
     }
+
+    /// Called in the child after fork(): the thread that held the import lock
+    /// may not exist anymore, so force-release it instead of deadlocking.
+    pub(crate) fn reinit_lock_after_fork() {
+        if IMP_LOCK.is_locked() {
+            unsafe { IMP_LOCK.unlock() };
+        }
+    }
 }
 
 #[cfg(not(feature = "threading"))]
@@ -42,6 +51,8 @@ mod lock {
     pub(super) fn lock_held(_vm: &VirtualMachine) -> bool {
         false
     }
+
+    pub(crate) fn reinit_lock_after_fork() {}
 }
 
 #[allow(dead_code)]
@@ -95,8 +106,19 @@ mod _imp {
     }
 
     #[pyfunction]
-    fn extension_suffixes() -> PyResult<Vec<PyObjectRef>> {
-        Ok(Vec::new())
+    fn extension_suffixes(vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+        #[cfg(all(
+            any(target_os = "linux", target_os = "macos", target_os = "windows"),
+            not(any(target_env = "musl", target_env = "sgx"))
+        ))]
+        return Ok(vec![
+            vm.ctx.new_str(crate::dylib::EXTENSION_SUFFIX).into(),
+        ]);
+        #[allow(unreachable_code)]
+        {
+            let _ = vm;
+            Ok(Vec::new())
+        }
     }
 
     #[pyfunction]
@@ -130,6 +152,35 @@ mod _imp {
         0
     }
 
+    // the loader protocol for ExtensionFileLoader: the spec's origin names a
+    // cdylib built with `rustpython_module!` (see crate::dylib)
+    #[pyfunction]
+    fn create_dynamic(spec: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let name: PyStrRef = spec.get_attr("name", vm)?.try_into_value(vm)?;
+        #[cfg(all(
+            any(target_os = "linux", target_os = "macos", target_os = "windows"),
+            not(any(target_env = "musl", target_env = "sgx"))
+        ))]
+        {
+            let origin: PyStrRef = spec.get_attr("origin", vm)?.try_into_value(vm)?;
+            let path = std::path::Path::new(origin.as_str());
+            crate::dylib::load_module(path, name.as_str(), vm).map(Into::into)
+        }
+        #[cfg(not(all(
+            any(target_os = "linux", target_os = "macos", target_os = "windows"),
+            not(any(target_env = "musl", target_env = "sgx"))
+        )))]
+        Err(vm.new_import_error(
+            "extension modules are not supported on this platform".to_owned(),
+            name,
+        ))
+    }
+
+    #[pyfunction]
+    fn exec_dynamic(_mod: PyObjectRef) -> i32 {
+        0
+    }
+
     #[pyfunction]
     fn get_frozen_object(name: PyStrRef, vm: &VirtualMachine) -> PyResult<PyRef<PyCode>> {
         import::make_frozen(vm, name.as_str())
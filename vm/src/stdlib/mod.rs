@@ -46,6 +46,7 @@ mod ctypes;
 pub(crate) mod msvcrt;
 #[cfg(all(unix, not(any(target_os = "android", target_os = "redox"))))]
 mod pwd;
+pub mod readline;
 pub(crate) mod signal;
 pub mod sys;
 #[cfg(windows)]
@@ -87,6 +88,7 @@ pub fn get_module_inits() -> StdlibMap {
             "_io" => io::make_module,
             "marshal" => marshal::make_module,
             "_operator" => operator::make_module,
+            "readline" => readline::make_module,
             "_signal" => signal::make_module,
             "_sre" => sre::make_module,
             "_string" => string::make_module,
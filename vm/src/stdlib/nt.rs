@@ -98,8 +98,19 @@ pub(crate) mod module {
     fn environ(vm: &VirtualMachine) -> PyDictRef {
         let environ = vm.ctx.new_dict();
 
-        for (key, value) in env::vars() {
-            environ.set_item(&key, vm.new_pyobj(value), vm).unwrap();
+        for (key, value) in env::vars_os() {
+            // entries that aren't valid unicode can't round-trip through the
+            // str-keyed mapping, and names starting with '=' are cmd.exe's
+            // hidden per-drive working directories; skip both like CPython
+            let (Some(key), Some(value)) = (key.to_str(), value.to_str()) else {
+                continue;
+            };
+            if key.is_empty() || key.starts_with('=') {
+                continue;
+            }
+            environ
+                .set_item(key, vm.new_pyobj(value.to_owned()), vm)
+                .unwrap();
         }
         environ
     }
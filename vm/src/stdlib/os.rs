@@ -955,6 +955,14 @@ pub(super) mod _os {
         vm.ctx.new_int(cpu_count).into()
     }
 
+    #[pyfunction]
+    fn process_cpu_count(vm: &VirtualMachine) -> PyObjectRef {
+        // logical CPUs usable by the current process; num_cpus honors
+        // affinity masks where the platform exposes them
+        let cpu_count = num_cpus::get();
+        vm.ctx.new_int(cpu_count).into()
+    }
+
     #[pyfunction]
     fn _exit(code: i32) {
         std::process::exit(code)
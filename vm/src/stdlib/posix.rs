@@ -70,6 +70,9 @@ pub mod module {
     #[cfg(not(target_os = "redox"))]
     #[pyattr]
     use libc::{O_NDELAY, O_NOCTTY};
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[pyattr]
+    use libc::O_TMPFILE;
 
     #[pyattr]
     use libc::{RTLD_GLOBAL, RTLD_LAZY, RTLD_LOCAL, RTLD_NOW};
@@ -538,28 +541,57 @@ pub mod module {
     }
 
     fn py_os_after_fork_child(vm: &VirtualMachine) {
+        // the forking thread is the only one that survives into the child;
+        // reinitialize interpreter-wide locks and thread bookkeeping before
+        // any user callbacks run
+        crate::stdlib::imp::reinit_lock_after_fork();
+        reinit_threading_after_fork(vm);
         let after_forkers_child: Vec<PyObjectRef> = vm.state.after_forkers_child.lock().clone();
         run_at_forkers(after_forkers_child, false, vm);
     }
 
+    /// Call `threading._after_fork()` in the child, like CPython's
+    /// `PyOS_AfterFork_Child`, so the threading module forgets about threads
+    /// that do not exist in this process. Only runs when threading was
+    /// actually imported.
+    fn reinit_threading_after_fork(vm: &VirtualMachine) {
+        let result = (|| -> PyResult<()> {
+            let modules = vm.sys_module.get_attr("modules", vm)?;
+            let Ok(threading) = modules.get_item("threading", vm) else {
+                return Ok(());
+            };
+            threading.get_attr("_after_fork", vm)?.call((), vm)?;
+            Ok(())
+        })();
+        if let Err(exc) = result {
+            vm.run_unraisable(
+                exc,
+                Some("Exception ignored in threading._after_fork".to_owned()),
+                vm.ctx.none(),
+            );
+        }
+    }
+
     fn py_os_after_fork_parent(vm: &VirtualMachine) {
         let after_forkers_parent: Vec<PyObjectRef> = vm.state.after_forkers_parent.lock().clone();
         run_at_forkers(after_forkers_parent, false, vm);
     }
 
     #[pyfunction]
-    fn fork(vm: &VirtualMachine) -> i32 {
-        let pid: i32;
+    fn fork(vm: &VirtualMachine) -> PyResult<i32> {
         py_os_before_fork(vm);
-        unsafe {
-            pid = libc::fork();
-        }
-        if pid == 0 {
-            py_os_after_fork_child(vm);
-        } else {
-            py_os_after_fork_parent(vm);
+        let pid = unsafe { libc::fork() };
+        match pid {
+            -1 => Err(errno_err(vm)),
+            0 => {
+                py_os_after_fork_child(vm);
+                Ok(pid)
+            }
+            _ => {
+                py_os_after_fork_parent(vm);
+                Ok(pid)
+            }
         }
-        pid
     }
 
     #[cfg(not(target_os = "redox"))]
@@ -658,6 +690,58 @@ pub mod module {
         nix::sched::sched_yield().map_err(|e| e.into_pyexception(vm))
     }
 
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[pyfunction]
+    fn sched_getaffinity(
+        pid: libc::pid_t,
+        vm: &VirtualMachine,
+    ) -> PyResult<crate::PyRef<crate::builtins::PySet>> {
+        let mut cpuset = std::mem::MaybeUninit::<libc::cpu_set_t>::uninit();
+        let ret = unsafe {
+            libc::sched_getaffinity(
+                pid,
+                std::mem::size_of::<libc::cpu_set_t>(),
+                cpuset.as_mut_ptr(),
+            )
+        };
+        if ret < 0 {
+            return Err(errno_err(vm));
+        }
+        let cpuset = unsafe { cpuset.assume_init() };
+        let set = crate::builtins::PySet::new_ref(&vm.ctx);
+        for cpu in 0..libc::CPU_SETSIZE as usize {
+            if unsafe { libc::CPU_ISSET(cpu, &cpuset) } {
+                set.add(vm.ctx.new_int(cpu).into(), vm)?;
+            }
+        }
+        Ok(set)
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[pyfunction]
+    fn sched_setaffinity(
+        pid: libc::pid_t,
+        mask: crate::function::ArgIterable<usize>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let mut cpuset = unsafe { std::mem::zeroed::<libc::cpu_set_t>() };
+        unsafe { libc::CPU_ZERO(&mut cpuset) };
+        for cpu in mask.iter(vm)? {
+            let cpu = cpu?;
+            if cpu >= libc::CPU_SETSIZE as usize {
+                return Err(vm.new_value_error(format!("iterable_of_ints: CPU number {cpu} too large")));
+            }
+            unsafe { libc::CPU_SET(cpu, &mut cpuset) };
+        }
+        let ret = unsafe {
+            libc::sched_setaffinity(pid, std::mem::size_of::<libc::cpu_set_t>(), &cpuset)
+        };
+        if ret < 0 {
+            return Err(errno_err(vm));
+        }
+        Ok(())
+    }
+
     #[pyattr]
     #[pyclass(name = "sched_param")]
     #[derive(Debug, PyPayload)]
@@ -0,0 +1,240 @@
+//! The `readline` module, backed by the same state the CLI shell's line
+//! editor consults. Key bindings belong to the editor, so `parse_and_bind` is
+//! accepted but ignored; history, the completer and the startup hook are real
+//! and shared with the REPL.
+
+pub(crate) use readline::make_module;
+
+use crate::{PyObjectRef, common::lock::PyMutex};
+use crossbeam_utils::atomic::AtomicCell;
+
+/// Process-wide state behind the `readline` module, stored in
+/// [`PyGlobalState`](crate::vm::PyGlobalState) so the shell and Python code
+/// observe the same history and completer.
+pub struct ReadlineState {
+    pub history: PyMutex<Vec<String>>,
+    /// Maximum saved history length; negative means unlimited, like GNU
+    /// readline.
+    pub history_length: AtomicCell<isize>,
+    /// Whether lines read by the shell are added to the history
+    /// automatically.
+    pub auto_history: AtomicCell<bool>,
+    pub completer: PyMutex<Option<PyObjectRef>>,
+    pub completer_delims: PyMutex<String>,
+    pub startup_hook: PyMutex<Option<PyObjectRef>>,
+    /// The line being completed and the word boundaries within it, populated
+    /// by the shell before it invokes the completer.
+    pub line_buffer: PyMutex<String>,
+    pub begidx: AtomicCell<usize>,
+    pub endidx: AtomicCell<usize>,
+}
+
+impl Default for ReadlineState {
+    fn default() -> Self {
+        ReadlineState {
+            history: PyMutex::default(),
+            history_length: AtomicCell::new(-1),
+            auto_history: AtomicCell::new(true),
+            completer: PyMutex::default(),
+            // GNU readline's default word-breaking characters
+            completer_delims: PyMutex::new(" \t\n`~!@#$%^&*()-=+[{]}\\|;:'\",<>/?".to_owned()),
+            startup_hook: PyMutex::default(),
+            line_buffer: PyMutex::default(),
+            begidx: AtomicCell::new(0),
+            endidx: AtomicCell::new(0),
+        }
+    }
+}
+
+impl ReadlineState {
+    /// Append an entry, dropping the oldest ones to honor the configured
+    /// history length.
+    pub fn push_history(&self, entry: &str) {
+        let mut history = self.history.lock();
+        history.push(entry.to_owned());
+        let length = self.history_length.load();
+        if length >= 0 {
+            let excess = history.len().saturating_sub(length as usize);
+            history.drain(..excess);
+        }
+    }
+}
+
+#[pymodule]
+mod readline {
+    use super::ReadlineState;
+    use crate::{
+        PyObjectRef, PyResult, VirtualMachine,
+        builtins::PyStrRef,
+        function::OptionalOption,
+        ospath::{IOErrorBuilder, OsPath},
+    };
+    use std::{fs, path::PathBuf};
+
+    fn state(vm: &VirtualMachine) -> &ReadlineState {
+        &vm.state.readline_state
+    }
+
+    /// The file `read_history_file`/`write_history_file` use when no name is
+    /// given; GNU readline's default.
+    fn default_history_path(vm: &VirtualMachine) -> PyResult<PathBuf> {
+        let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+        let home = std::env::var_os(var)
+            .ok_or_else(|| vm.new_os_error("could not determine home directory".to_owned()))?;
+        Ok(PathBuf::from(home).join(".history"))
+    }
+
+    #[pyfunction]
+    fn add_history(line: PyStrRef, vm: &VirtualMachine) {
+        state(vm).push_history(line.as_str());
+    }
+
+    #[pyfunction]
+    fn get_current_history_length(vm: &VirtualMachine) -> usize {
+        state(vm).history.lock().len()
+    }
+
+    #[pyfunction]
+    fn get_history_length(vm: &VirtualMachine) -> isize {
+        state(vm).history_length.load()
+    }
+
+    #[pyfunction]
+    fn set_history_length(length: isize, vm: &VirtualMachine) {
+        state(vm).history_length.store(length);
+    }
+
+    /// History items are indexed from 1, and an out-of-range index yields
+    /// None rather than an error, matching GNU readline.
+    #[pyfunction]
+    fn get_history_item(index: isize, vm: &VirtualMachine) -> Option<String> {
+        let history = state(vm).history.lock();
+        let index = usize::try_from(index).ok()?.checked_sub(1)?;
+        history.get(index).cloned()
+    }
+
+    #[pyfunction]
+    fn remove_history_item(pos: isize, vm: &VirtualMachine) -> PyResult<()> {
+        let mut history = state(vm).history.lock();
+        match usize::try_from(pos) {
+            Ok(pos) if pos < history.len() => {
+                history.remove(pos);
+                Ok(())
+            }
+            _ => Err(vm.new_index_error(format!("No history item at position {pos}"))),
+        }
+    }
+
+    #[pyfunction]
+    fn replace_history_item(pos: isize, line: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        let mut history = state(vm).history.lock();
+        match usize::try_from(pos) {
+            Ok(pos) if pos < history.len() => {
+                history[pos] = line.as_str().to_owned();
+                Ok(())
+            }
+            _ => Err(vm.new_index_error(format!("No history item at position {pos}"))),
+        }
+    }
+
+    #[pyfunction]
+    fn clear_history(vm: &VirtualMachine) {
+        state(vm).history.lock().clear();
+    }
+
+    #[pyfunction]
+    fn read_history_file(filename: OptionalOption<OsPath>, vm: &VirtualMachine) -> PyResult<()> {
+        let path = match filename.flatten() {
+            Some(path) => path,
+            None => OsPath::new_str(default_history_path(vm)?),
+        };
+        let contents = fs::read_to_string(path.as_path())
+            .map_err(|err| IOErrorBuilder::with_filename(&err, path.clone(), vm))?;
+        let state = state(vm);
+        for line in contents.lines() {
+            state.push_history(line);
+        }
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn write_history_file(filename: OptionalOption<OsPath>, vm: &VirtualMachine) -> PyResult<()> {
+        let path = match filename.flatten() {
+            Some(path) => path,
+            None => OsPath::new_str(default_history_path(vm)?),
+        };
+        let history = state(vm).history.lock();
+        let mut contents = String::new();
+        for entry in history.iter() {
+            contents.push_str(entry);
+            contents.push('\n');
+        }
+        drop(history);
+        fs::write(path.as_path(), contents)
+            .map_err(|err| IOErrorBuilder::with_filename(&err, path.clone(), vm))
+    }
+
+    #[pyfunction]
+    fn set_completer(completer: OptionalOption<PyObjectRef>, vm: &VirtualMachine) {
+        *state(vm).completer.lock() = completer.flatten();
+    }
+
+    #[pyfunction]
+    fn get_completer(vm: &VirtualMachine) -> Option<PyObjectRef> {
+        state(vm).completer.lock().clone()
+    }
+
+    #[pyfunction]
+    fn get_completer_delims(vm: &VirtualMachine) -> String {
+        state(vm).completer_delims.lock().clone()
+    }
+
+    #[pyfunction]
+    fn set_completer_delims(delims: PyStrRef, vm: &VirtualMachine) {
+        *state(vm).completer_delims.lock() = delims.as_str().to_owned();
+    }
+
+    #[pyfunction]
+    fn set_startup_hook(hook: OptionalOption<PyObjectRef>, vm: &VirtualMachine) {
+        *state(vm).startup_hook.lock() = hook.flatten();
+    }
+
+    #[pyfunction]
+    fn set_auto_history(enabled: bool, vm: &VirtualMachine) {
+        state(vm).auto_history.store(enabled);
+    }
+
+    #[pyfunction]
+    fn get_line_buffer(vm: &VirtualMachine) -> String {
+        state(vm).line_buffer.lock().clone()
+    }
+
+    #[pyfunction]
+    fn get_begidx(vm: &VirtualMachine) -> usize {
+        state(vm).begidx.load()
+    }
+
+    #[pyfunction]
+    fn get_endidx(vm: &VirtualMachine) -> usize {
+        state(vm).endidx.load()
+    }
+
+    /// Key bindings are fixed by the line editor, so init strings are
+    /// accepted for compatibility and otherwise ignored.
+    #[pyfunction]
+    fn parse_and_bind(_string: PyStrRef) {}
+
+    #[pyfunction]
+    fn read_init_file(_filename: OptionalOption<OsPath>) {}
+
+    /// Text insertion at the prompt is owned by the line editor; rlcompleter
+    /// calls this for blank-line tabs, so accept and ignore it.
+    #[pyfunction]
+    fn insert_text(_string: PyStrRef) {}
+
+    #[pyfunction]
+    fn redisplay() {}
+
+    #[pyattr]
+    const _READLINE_LIBRARY_VERSION: &str = "rustyline";
+}
@@ -91,6 +91,17 @@ pub(crate) mod _signal {
     #[pyattr]
     pub use libc::{SIGABRT, SIGFPE, SIGILL, SIGINT, SIGSEGV, SIGTERM};
 
+    // matches CPython's signalmodule.c on Windows
+    #[cfg(windows)]
+    #[pyattr]
+    pub const SIGBREAK: i32 = 21;
+    #[cfg(windows)]
+    #[pyattr]
+    pub const CTRL_C_EVENT: i32 = windows_sys::Win32::System::Console::CTRL_C_EVENT as i32;
+    #[cfg(windows)]
+    #[pyattr]
+    pub const CTRL_BREAK_EVENT: i32 = windows_sys::Win32::System::Console::CTRL_BREAK_EVENT as i32;
+
     #[cfg(unix)]
     #[pyattr]
     use libc::{
@@ -71,6 +71,156 @@ mod _string {
         Ok(result.into())
     }
 
+    /// A placeholder recognized by `string.Template`'s default pattern.
+    /// Ranges are byte offsets of the identifier within the template.
+    enum TemplateToken {
+        Literal(usize, usize),
+        Escaped,
+        Named(usize, usize),
+        Braced(usize, usize),
+        Invalid,
+    }
+
+    const fn is_id_start(c: u8) -> bool {
+        c == b'_' || c.is_ascii_alphabetic()
+    }
+
+    const fn is_id_continue(c: u8) -> bool {
+        c == b'_' || c.is_ascii_alphanumeric()
+    }
+
+    /// Tokenize `$$`, `$identifier`, `${identifier}` and bare `$`s the way
+    /// `string.Template`'s default pattern does. `$` is ASCII, so scanning
+    /// the raw wtf-8 bytes is safe.
+    fn tokenize_template(bytes: &[u8]) -> Vec<TemplateToken> {
+        let mut tokens = Vec::new();
+        let mut lit_start = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] != b'$' {
+                i += 1;
+                continue;
+            }
+            if lit_start < i {
+                tokens.push(TemplateToken::Literal(lit_start, i));
+            }
+            match bytes.get(i + 1) {
+                Some(b'$') => {
+                    tokens.push(TemplateToken::Escaped);
+                    i += 2;
+                }
+                Some(b'{') => {
+                    let start = i + 2;
+                    let mut end = start;
+                    if bytes.get(end).copied().is_some_and(is_id_start) {
+                        end += 1;
+                        while bytes.get(end).copied().is_some_and(is_id_continue) {
+                            end += 1;
+                        }
+                    }
+                    if end > start && bytes.get(end) == Some(&b'}') {
+                        tokens.push(TemplateToken::Braced(start, end));
+                        i = end + 1;
+                    } else {
+                        tokens.push(TemplateToken::Invalid);
+                        i += 1;
+                    }
+                }
+                Some(&c) if is_id_start(c) => {
+                    let start = i + 1;
+                    let mut end = start + 1;
+                    while bytes.get(end).copied().is_some_and(is_id_continue) {
+                        end += 1;
+                    }
+                    tokens.push(TemplateToken::Named(start, end));
+                    i = end;
+                }
+                _ => {
+                    tokens.push(TemplateToken::Invalid);
+                    i += 1;
+                }
+            }
+            lit_start = i;
+        }
+        if lit_start < bytes.len() {
+            tokens.push(TemplateToken::Literal(lit_start, bytes.len()));
+        }
+        tokens
+    }
+
+    /// Substitute a `string.Template` with the default pattern. Returns None
+    /// when the template contains an ill-formed placeholder and `safe` is
+    /// false, so the caller can fall back to the regex path for its
+    /// detailed error message.
+    #[pyfunction]
+    fn template_substitute(
+        template: PyStrRef,
+        mapping: PyObjectRef,
+        safe: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<Option<Wtf8Buf>> {
+        let s = template.as_wtf8();
+        let bytes = s.as_bytes();
+        let mut out = Wtf8Buf::with_capacity(bytes.len());
+        for token in tokenize_template(bytes) {
+            let (start, end, original) = match token {
+                TemplateToken::Literal(start, end) => {
+                    out.push_wtf8(&s[start..end]);
+                    continue;
+                }
+                TemplateToken::Escaped => {
+                    out.push_str("$");
+                    continue;
+                }
+                TemplateToken::Invalid => {
+                    if !safe {
+                        return Ok(None);
+                    }
+                    out.push_str("$");
+                    continue;
+                }
+                // the original text, for safe substitution of missing keys
+                TemplateToken::Named(start, end) => (start, end, &s[start - 1..end]),
+                TemplateToken::Braced(start, end) => (start, end, &s[start - 2..end + 1]),
+            };
+            let name = std::str::from_utf8(&bytes[start..end]).expect("identifier is ascii");
+            match mapping.get_item(name, vm) {
+                Ok(value) => out.push_wtf8(value.str(vm)?.as_wtf8()),
+                Err(e) if safe && e.fast_isinstance(vm.ctx.exceptions.key_error) => {
+                    out.push_wtf8(original)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Some(out))
+    }
+
+    /// `string.Template.is_valid` for the default pattern.
+    #[pyfunction]
+    fn template_is_valid(template: PyStrRef) -> bool {
+        !tokenize_template(template.as_wtf8().as_bytes())
+            .iter()
+            .any(|token| matches!(token, TemplateToken::Invalid))
+    }
+
+    /// `string.Template.get_identifiers` for the default pattern.
+    #[pyfunction]
+    fn template_identifiers(template: PyStrRef) -> Vec<String> {
+        let bytes = template.as_wtf8().as_bytes();
+        let mut ids: Vec<String> = Vec::new();
+        for token in tokenize_template(bytes) {
+            if let TemplateToken::Named(start, end) | TemplateToken::Braced(start, end) = token {
+                let name = std::str::from_utf8(&bytes[start..end])
+                    .expect("identifier is ascii")
+                    .to_owned();
+                if !ids.contains(&name) {
+                    ids.push(name);
+                }
+            }
+        }
+        ids
+    }
+
     #[pyfunction]
     fn formatter_field_name_split(
         text: PyStrRef,
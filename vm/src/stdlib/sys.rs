@@ -7,7 +7,8 @@ mod sys {
     use crate::{
         AsObject, PyObject, PyObjectRef, PyRef, PyRefExact, PyResult,
         builtins::{
-            PyBaseExceptionRef, PyDictRef, PyNamespace, PyStr, PyStrRef, PyTupleRef, PyTypeRef,
+            PyBaseExceptionRef, PyDictRef, PyFrozenSet, PyNamespace, PyStr, PyStrRef, PyTupleRef,
+            PyTypeRef,
         },
         common::{
             ascii,
@@ -146,6 +147,207 @@ mod sys {
         )
     }
 
+    /// The top-level names of the pure-Python part of the standard library, as
+    /// shipped in pylib's Lib directory. Kept as a static list (like CPython's
+    /// generated `Python/stdlib_module_names.h`) because the default build
+    /// loads these modules from the filesystem rather than freezing them in,
+    /// so they are invisible to `module_inits` and `frozen`. As with CPython,
+    /// a name being listed doesn't guarantee the module is importable.
+    const PYLIB_MODULE_NAMES: &[&str] = &[
+        "__future__",
+        "_aix_support",
+        "_android_support",
+        "_apple_support",
+        "_collections_abc",
+        "_colorize",
+        "_compat_pickle",
+        "_compression",
+        "_frozen_importlib",
+        "_frozen_importlib_external",
+        "_ios_support",
+        "_markupbase",
+        "_osx_support",
+        "_py_abc",
+        "_pycodecs",
+        "_pydatetime",
+        "_pydecimal",
+        "_pyio",
+        "_pylong",
+        "_pyrepl",
+        "_sitebuiltins",
+        "_strptime",
+        "_thread",
+        "_threading_local",
+        "_weakrefset",
+        "abc",
+        "argparse",
+        "ast",
+        "asyncio",
+        "base64",
+        "bdb",
+        "bisect",
+        "bz2",
+        "calendar",
+        "cmd",
+        "code",
+        "codecs",
+        "codeop",
+        "collections",
+        "colorsys",
+        "compileall",
+        "concurrent",
+        "configparser",
+        "contextlib",
+        "contextvars",
+        "copy",
+        "copyreg",
+        "csv",
+        "ctypes",
+        "dataclasses",
+        "datetime",
+        "dbm",
+        "decimal",
+        "difflib",
+        "dis",
+        "doctest",
+        "email",
+        "encodings",
+        "ensurepip",
+        "enum",
+        "filecmp",
+        "fileinput",
+        "fnmatch",
+        "fractions",
+        "ftplib",
+        "functools",
+        "genericpath",
+        "getopt",
+        "getpass",
+        "gettext",
+        "glob",
+        "graphlib",
+        "gzip",
+        "hashlib",
+        "heapq",
+        "hmac",
+        "html",
+        "http",
+        "importlib",
+        "inspect",
+        "io",
+        "ipaddress",
+        "json",
+        "keyword",
+        "linecache",
+        "locale",
+        "logging",
+        "lzma",
+        "mailbox",
+        "mimetypes",
+        "multiprocessing",
+        "netrc",
+        "ntpath",
+        "nturl2path",
+        "numbers",
+        "opcode",
+        "operator",
+        "optparse",
+        "os",
+        "pathlib",
+        "pdb",
+        "pickle",
+        "pickletools",
+        "pkgutil",
+        "platform",
+        "plistlib",
+        "posixpath",
+        "pprint",
+        "pty",
+        "py_compile",
+        "pydoc",
+        "queue",
+        "quopri",
+        "random",
+        "re",
+        "reprlib",
+        "rlcompleter",
+        "runpy",
+        "sched",
+        "secrets",
+        "selectors",
+        "shelve",
+        "shlex",
+        "shutil",
+        "signal",
+        "site",
+        "smtplib",
+        "socket",
+        "socketserver",
+        "sqlite3",
+        "sre_compile",
+        "sre_constants",
+        "sre_parse",
+        "ssl",
+        "stat",
+        "statistics",
+        "string",
+        "stringprep",
+        "struct",
+        "subprocess",
+        "sysconfig",
+        "tabnanny",
+        "tarfile",
+        "tempfile",
+        "textwrap",
+        "this",
+        "threading",
+        "timeit",
+        "token",
+        "tokenize",
+        "tomllib",
+        "trace",
+        "traceback",
+        "tty",
+        "types",
+        "typing",
+        "unittest",
+        "urllib",
+        "uuid",
+        "venv",
+        "warnings",
+        "wave",
+        "weakref",
+        "webbrowser",
+        "wsgiref",
+        "xml",
+        "xmlrpc",
+        "zipapp",
+        "zipfile",
+        "zipimport",
+        "zoneinfo",
+    ];
+
+    #[pyattr]
+    fn stdlib_module_names(vm: &VirtualMachine) -> PyRef<PyFrozenSet> {
+        // every standard module this interpreter ships: the native modules,
+        // whatever was frozen in, and the pure-Python library (which the
+        // default build loads from the filesystem, so it has to be listed
+        // statically)
+        let module_names: Vec<PyObjectRef> = vm
+            .state
+            .module_inits
+            .keys()
+            .map(|name| name.as_ref())
+            .chain(vm.state.frozen.keys().copied())
+            .chain(PYLIB_MODULE_NAMES.iter().copied())
+            .chain(["sys", "builtins"])
+            .map(|name| vm.ctx.new_str(name).into())
+            .collect();
+        PyFrozenSet::from_iter(vm, module_names)
+            .unwrap()
+            .into_ref(&vm.ctx)
+    }
+
     #[pyattr]
     fn byteorder(vm: &VirtualMachine) -> PyStrRef {
         // https://doc.rust-lang.org/reference/conditional-compilation.html#target_endian
@@ -244,7 +446,13 @@ mod sys {
 
     #[pyattr]
     fn orig_argv(vm: &VirtualMachine) -> Vec<PyObjectRef> {
-        env::args().map(|arg| vm.ctx.new_str(arg).into()).collect()
+        env::args_os()
+            .map(|arg| {
+                vm.ctx
+                    .new_str(crate::common::os::fs_decode_wtf8(arg))
+                    .into()
+            })
+            .collect()
     }
 
     #[pyattr]
@@ -365,21 +573,45 @@ mod sys {
     ) -> PyResult<()> {
         let exc = vm.normalize_exception(exc_type, exc_val, exc_tb)?;
         let stderr = super::get_stderr(vm)?;
-        vm.write_exception(&mut crate::py_io::PyWriter(stderr, vm), &exc)
+        vm.write_exception_colorized(
+            &mut crate::py_io::PyWriter(stderr, vm),
+            &exc,
+            crate::exceptions::should_colorize(),
+        )
+    }
+
+    // perf trampoline stubs: RustPython has no perf map support, matching
+    // CPython builds without --enable-perf-trampoline
+    #[pyfunction]
+    fn activate_stack_trampoline(_backend: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        Err(vm.new_value_error("perf trampoline not available".to_owned()))
+    }
+
+    #[pyfunction]
+    fn deactivate_stack_trampoline() {}
+
+    #[pyfunction]
+    fn is_stack_trampoline_active() -> bool {
+        false
     }
 
     #[pyfunction(name = "__breakpointhook__")]
     #[pyfunction]
     pub fn breakpointhook(args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        let env_var = std::env::var("PYTHONBREAKPOINT")
-            .and_then(|env_var| {
-                if env_var.is_empty() {
-                    Err(VarError::NotPresent)
-                } else {
-                    Ok(env_var)
-                }
-            })
-            .unwrap_or_else(|_| "pdb.set_trace".to_owned());
+        // -E/-I ignore PYTHONBREAKPOINT like every other PYTHON* variable
+        let env_var = if vm.state.settings.ignore_environment {
+            Err(VarError::NotPresent)
+        } else {
+            std::env::var("PYTHONBREAKPOINT")
+        }
+        .and_then(|env_var| {
+            if env_var.is_empty() {
+                Err(VarError::NotPresent)
+            } else {
+                Ok(env_var)
+            }
+        })
+        .unwrap_or_else(|_| "pdb.set_trace".to_owned());
 
         if env_var.eq("0") {
             return Ok(vm.ctx.none());
@@ -499,6 +731,17 @@ mod sys {
         Ok(frame.clone())
     }
 
+    /// Source text recorded for a pseudo-filename (`<stdin>`, `<string>`, ...)
+    /// when it was compiled, or None. Used by linecache.
+    #[pyfunction]
+    fn _get_registered_source(filename: PyStrRef, vm: &VirtualMachine) -> Option<String> {
+        vm.state
+            .source_registry
+            .lock()
+            .get(filename.as_str())
+            .cloned()
+    }
+
     #[pyfunction]
     fn gettrace(vm: &VirtualMachine) -> PyObjectRef {
         vm.trace_func.borrow().clone()
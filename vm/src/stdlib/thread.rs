@@ -50,6 +50,9 @@ pub(crate) mod _thread {
         vm.ctx.exceptions.runtime_error.to_owned()
     }
 
+    /// How long a blocking acquire may wait before polling for signals.
+    const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
     #[derive(FromArgs)]
     struct AcquireArgs {
         #[pyarg(any, default = true)]
@@ -67,7 +70,11 @@ pub(crate) mod _thread {
             };
             match args.blocking {
                 true if timeout == -1.0 => {
-                    mu.lock();
+                    // wake up periodically so a pending SIGINT can interrupt
+                    // the wait (PEP 475) instead of blocking until release
+                    while !mu.try_lock_for(SIGNAL_CHECK_INTERVAL) {
+                        vm.check_signals()?;
+                    }
                     Ok(true)
                 }
                 true if timeout < 0.0 => {
@@ -83,7 +90,18 @@ pub(crate) mod _thread {
                         ));
                     }
 
-                    Ok(mu.try_lock_for(Duration::from_secs_f64(timeout)))
+                    let deadline = std::time::Instant::now() + Duration::from_secs_f64(timeout);
+                    loop {
+                        let remaining =
+                            deadline.saturating_duration_since(std::time::Instant::now());
+                        if remaining.is_zero() {
+                            break Ok(false);
+                        }
+                        if mu.try_lock_for(remaining.min(SIGNAL_CHECK_INTERVAL)) {
+                            break Ok(true);
+                        }
+                        vm.check_signals()?;
+                    }
                 }
                 false if timeout != -1.0 => Err(vm
                     .new_value_error("can't specify a timeout for a non-blocking call".to_owned())),
@@ -366,11 +384,16 @@ pub(crate) mod _thread {
         lock
     }
 
+    // same floor as CPython's THREAD_MIN_STACKSIZE
+    const MIN_STACKSIZE: usize = 0x8000;
+
     #[pyfunction]
-    fn stack_size(size: OptionalArg<usize>, vm: &VirtualMachine) -> usize {
+    fn stack_size(size: OptionalArg<usize>, vm: &VirtualMachine) -> PyResult<usize> {
         let size = size.unwrap_or(0);
-        // TODO: do validation on this to make sure it's not too small
-        vm.state.stacksize.swap(size)
+        if size != 0 && size < MIN_STACKSIZE {
+            return Err(vm.new_value_error(format!("size not valid: {size} bytes")));
+        }
+        Ok(vm.state.stacksize.swap(size))
     }
 
     #[pyfunction]
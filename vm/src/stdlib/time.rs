@@ -149,13 +149,30 @@ mod decl {
         Ok(get_monotonic_time(vm)?.as_nanos())
     }
 
+    /// -X deterministic: a synthetic clock that advances by a fixed quantum
+    /// on every read, so timing-dependent code behaves identically run-to-run
+    /// while the counter stays strictly monotonic.
+    fn deterministic_perf_time() -> Duration {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        const QUANTUM_NS: u64 = 1_000;
+        static TICKS: AtomicU64 = AtomicU64::new(0);
+        let ticks = TICKS.fetch_add(1, Ordering::Relaxed) + 1;
+        Duration::from_nanos(ticks * QUANTUM_NS)
+    }
+
     #[pyfunction]
     fn perf_counter(vm: &VirtualMachine) -> PyResult<f64> {
+        if vm.state.settings.deterministic {
+            return Ok(deterministic_perf_time().as_secs_f64());
+        }
         Ok(get_perf_time(vm)?.as_secs_f64())
     }
 
     #[pyfunction]
     fn perf_counter_ns(vm: &VirtualMachine) -> PyResult<u128> {
+        if vm.state.settings.deterministic {
+            return Ok(deterministic_perf_time().as_nanos());
+        }
         Ok(get_perf_time(vm)?.as_nanos())
     }
 
@@ -697,12 +714,21 @@ mod platform {
     fn sleep(dur: Duration, vm: &VirtualMachine) -> PyResult<()> {
         // this is basically std::thread::sleep, but that catches interrupts and we don't want to;
 
-        let ts = TimeSpec::from(dur);
-        let res = unsafe { libc::nanosleep(ts.as_ref(), std::ptr::null_mut()) };
-        let interrupted = res == -1 && nix::Error::last_raw() == libc::EINTR;
-
-        if interrupted {
-            vm.check_signals()?;
+        let mut ts = *TimeSpec::from(dur).as_ref();
+        loop {
+            let mut rem = libc::timespec {
+                tv_sec: 0,
+                tv_nsec: 0,
+            };
+            let res = unsafe { libc::nanosleep(&ts, &mut rem) };
+            if res == -1 && nix::Error::last_raw() == libc::EINTR {
+                // PEP 475: if the signal handler doesn't raise, resume
+                // sleeping for the time remaining
+                vm.check_signals()?;
+                ts = rem;
+            } else {
+                break;
+            }
         }
 
         Ok(())
@@ -3,7 +3,7 @@ pub(crate) use _typing::make_module;
 #[pymodule]
 pub(crate) mod _typing {
     use crate::{
-        PyObjectRef, PyPayload, PyResult, VirtualMachine,
+        AsObject, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
         builtins::{PyGenericAlias, PyTupleRef, PyTypeRef, pystr::AsPyStr},
         function::{FuncArgs, IntoFuncArgs},
     };
@@ -188,7 +188,23 @@ pub(crate) mod _typing {
         name: PyObjectRef,
     }
     #[pyclass(flags(BASETYPE))]
-    impl TypeVarTuple {}
+    impl TypeVarTuple {
+        #[pygetset(magic)]
+        fn name(&self) -> PyObjectRef {
+            self.name.clone()
+        }
+
+        // PEP 646: `*Ts` in a call, subscription or annotation unpacks to a
+        // single `typing.Unpack[Ts]`
+        #[pymethod(magic)]
+        fn iter(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyResult {
+            let typing = vm.import("typing", 0)?;
+            let unpack = typing.get_attr("Unpack", vm)?;
+            let unpacked = unpack.get_item(zelf.as_object(), vm)?;
+            let items = vm.ctx.new_tuple(vec![unpacked]);
+            Ok(items.as_object().get_iter(vm)?.into())
+        }
+    }
 
     pub(crate) fn make_typevartuple(name: PyObjectRef) -> TypeVarTuple {
         TypeVarTuple { name }
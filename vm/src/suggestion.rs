@@ -69,7 +69,19 @@ pub fn offer_suggestions(exc: &PyBaseExceptionRef, vm: &VirtualMachine) -> Optio
         };
 
         let builtins: Vec<_> = tb.frame.builtins.as_object().try_to_value(vm).ok()?;
-        calculate_suggestions(builtins.iter(), &name)
+        if let Some(suggestions) = calculate_suggestions(builtins.iter(), &name) {
+            return Some(suggestions);
+        };
+
+        // no close match anywhere: a method body that dropped the `self.`
+        // prefix is the remaining misspelling CPython points at
+        let name_str = name.downcast_ref::<PyStr>()?;
+        let locals = tb.frame.locals(vm).ok()?;
+        let zelf = locals.get_item("self", vm).ok()?;
+        if zelf.has_attr(name_str, vm).ok()? {
+            return Some(vm.ctx.new_str(format!("self.{}", name_str.as_str())));
+        }
+        None
     } else {
         None
     }
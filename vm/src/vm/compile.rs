@@ -23,7 +23,16 @@ impl VirtualMachine {
         source_path: String,
         opts: CompileOpts,
     ) -> Result<PyRef<PyCode>, CompileError> {
-        compiler::compile(source, mode, &source_path, opts).map(|code| self.ctx.new_code(code))
+        let code = compiler::compile(source, mode, &source_path, opts)?;
+        // remember sources compiled from pseudo-files so tracebacks and
+        // linecache can show context for them later
+        if source_path.starts_with('<') && source_path.ends_with('>') {
+            self.state
+                .source_registry
+                .lock()
+                .insert(source_path, source.to_owned());
+        }
+        Ok(self.ctx.new_code(code))
     }
 
     pub fn run_script(&self, scope: Scope, path: &str) -> PyResult<()> {
@@ -46,7 +55,13 @@ impl VirtualMachine {
 
         match std::fs::read_to_string(path) {
             Ok(source) => {
-                self.run_code_string(scope, &source, path.to_owned())?;
+                // CPython publishes __main__.__file__ as an absolute path so
+                // that chdir() in the script doesn't orphan it
+                let path = std::path::absolute(path)
+                    .ok()
+                    .and_then(|path| path.into_os_string().into_string().ok())
+                    .unwrap_or_else(|| path.to_owned());
+                self.run_code_string(scope, &source, path)?;
             }
             Err(err) => {
                 error!("Failed reading file '{path}': {err}");
@@ -67,6 +82,11 @@ impl VirtualMachine {
             self.new_pyobj(source_path),
             self,
         )?;
+        // scripts are never run from a cache file; pickle and friends expect
+        // the attribute to exist regardless
+        scope
+            .globals
+            .set_item("__cached__", self.ctx.none(), self)?;
         self.run_code_obj(code_obj, scope)
     }
 
@@ -1,6 +1,18 @@
 use super::{Context, VirtualMachine, setting::Settings, thread};
 use crate::{PyResult, stdlib::atexit, vm::PyBaseExceptionRef};
 use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+/// What [`Interpreter::shutdown_with_timeout`] was able to tear down.
+#[derive(Debug)]
+pub struct ShutdownReport {
+    /// Exit code the process would use, as computed by [`Interpreter::finalize`].
+    pub exit_code: u8,
+    /// Names of non-daemon Python threads that were still alive when the
+    /// timeout elapsed. Their OS threads keep running; the host must not
+    /// assume interpreter state is reachable from them anymore.
+    pub stragglers: Vec<String>,
+}
 
 /// The general interface for the VM
 ///
@@ -106,9 +118,10 @@ impl Interpreter {
 
     /// Finalize vm and turns an exception to exit code.
     ///
-    /// Finalization steps including 4 steps:
+    /// Finalization steps including 5 steps:
     /// 1. Flush stdout and stderr.
     /// 1. Handle exit exception and turn it to exit code.
+    /// 1. Join non-daemon threads via `threading._shutdown`.
     /// 1. Run atexit exit functions.
     /// 1. Mark vm as finalized.
     ///
@@ -124,6 +137,8 @@ impl Interpreter {
                 0
             };
 
+            Self::wait_for_thread_shutdown(vm);
+
             atexit::_run_exitfuncs(vm);
 
             vm.state.finalizing.store(true, Ordering::Release);
@@ -133,6 +148,113 @@ impl Interpreter {
             exit_code
         })
     }
+
+    /// Finalize the interpreter without a pending exception. Convenience API
+    /// for embedders that drive the vm through [`Interpreter::enter`] and want
+    /// the documented finalization sequence on teardown.
+    pub fn shutdown(self) -> u8 {
+        self.finalize(None)
+    }
+
+    /// Like [`Interpreter::shutdown`], but bounding how long the teardown
+    /// waits for Python threads to finish.
+    ///
+    /// Threads cannot be killed safely, so "cancellation" is cooperative:
+    /// `sys.is_finalizing()` starts returning `True` before any joining
+    /// happens, giving well-behaved loops a signal to wind down, and each
+    /// non-daemon thread is then joined against the shared deadline instead
+    /// of blocking indefinitely in `threading._shutdown`. Finalizers (atexit
+    /// functions) run regardless, and threads still alive afterwards are
+    /// reported in [`ShutdownReport::stragglers`] so the host can decide
+    /// whether to exit the process anyway.
+    pub fn shutdown_with_timeout(self, timeout: Duration) -> ShutdownReport {
+        self.enter(|vm| {
+            vm.flush_std();
+
+            // the cooperative cancellation signal: threads polling
+            // sys.is_finalizing() know to wind down
+            vm.state.finalizing.store(true, Ordering::Release);
+
+            let stragglers = Self::join_threads_with_deadline(vm, Instant::now() + timeout);
+
+            atexit::_run_exitfuncs(vm);
+
+            vm.flush_std();
+
+            ShutdownReport {
+                exit_code: 0,
+                stragglers,
+            }
+        })
+    }
+
+    /// Join each non-daemon thread with whatever remains of `deadline`,
+    /// returning the names of those that outlived it. The timed counterpart
+    /// of [`Interpreter::wait_for_thread_shutdown`].
+    fn join_threads_with_deadline(vm: &VirtualMachine, deadline: Instant) -> Vec<String> {
+        use crate::{PyObjectRef, TryFromObject};
+
+        let mut stragglers = Vec::new();
+        let mut join_all = || -> PyResult<()> {
+            let modules = vm.sys_module.get_attr("modules", vm)?;
+            let Ok(threading) = modules.get_item("threading", vm) else {
+                // threading was never imported; no threads to join
+                return Ok(());
+            };
+            let current = threading.get_attr("current_thread", vm)?.call((), vm)?;
+            let threads = threading.get_attr("enumerate", vm)?.call((), vm)?;
+            for thread in Vec::<PyObjectRef>::try_from_object(vm, threads)? {
+                if thread.is(&current) {
+                    continue;
+                }
+                if thread.get_attr("daemon", vm)?.is_true(vm)? {
+                    // daemon threads are abandoned at shutdown, as always
+                    continue;
+                }
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                thread
+                    .get_attr("join", vm)?
+                    .call((remaining.as_secs_f64(),), vm)?;
+                let alive = thread.get_attr("is_alive", vm)?.call((), vm)?;
+                if alive.is_true(vm)? {
+                    let name = thread.get_attr("name", vm)?.str(vm)?;
+                    stragglers.push(name.as_str().to_owned());
+                }
+            }
+            Ok(())
+        };
+        if let Err(exc) = join_all() {
+            vm.run_unraisable(
+                exc,
+                Some("Exception ignored in threading shutdown".to_owned()),
+                vm.ctx.none(),
+            );
+        }
+        stragglers
+    }
+
+    /// Join non-daemon threads, like CPython's `wait_for_thread_shutdown`.
+    /// `threading._shutdown` is only called when the threading module was
+    /// actually imported during the session.
+    fn wait_for_thread_shutdown(vm: &VirtualMachine) {
+        let shutdown = || -> PyResult<()> {
+            let modules = vm.sys_module.get_attr("modules", vm)?;
+            let Ok(threading) = modules.get_item("threading", vm) else {
+                // threading was never imported; no threads to join
+                return Ok(());
+            };
+            let shutdown = threading.get_attr("_shutdown", vm)?;
+            shutdown.call((), vm)?;
+            Ok(())
+        };
+        if let Err(exc) = shutdown() {
+            vm.run_unraisable(
+                exc,
+                Some("Exception ignored in threading shutdown".to_owned()),
+                vm.ctx.none(),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -8,8 +8,10 @@ mod compile;
 mod context;
 mod interpreter;
 mod method;
+mod panic;
 mod setting;
 pub mod thread;
+pub(crate) mod tsan;
 mod vm_new;
 mod vm_object;
 mod vm_ops;
@@ -19,8 +21,8 @@ use crate::builtins::PyNone;
 use crate::{
     AsObject, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult,
     builtins::{
-        PyBaseExceptionRef, PyDictRef, PyInt, PyList, PyModule, PyStr, PyStrInterned, PyStrRef,
-        PyTypeRef,
+        PyBaseExceptionRef, PyBytesRef, PyDictRef, PyInt, PyList, PyModule, PyStr, PyStrInterned,
+        PyStrRef, PyTypeRef,
         code::PyCode,
         pystr::AsPyStr,
         tuple::{PyTuple, PyTupleTyped},
@@ -52,9 +54,10 @@ use std::{
 };
 
 pub use context::Context;
-pub use interpreter::Interpreter;
+pub use interpreter::{Interpreter, ShutdownReport};
 pub(crate) use method::PyMethod;
-pub use setting::{CheckHashPycsMode, Settings};
+pub(crate) use panic::catch_native_panic;
+pub use setting::{CheckHashPycsMode, PanicPolicy, Settings};
 
 pub const MAX_MEMORY_SIZE: usize = isize::MAX as usize;
 
@@ -106,8 +109,25 @@ pub struct PyGlobalState {
     pub after_forkers_child: PyMutex<Vec<PyObjectRef>>,
     pub after_forkers_parent: PyMutex<Vec<PyObjectRef>>,
     pub int_max_str_digits: AtomicCell<usize>,
+    /// Source text of code compiled from pseudo-files (`<stdin>`, `<string>`,
+    /// ...), keyed by source path, so tracebacks and linecache can show
+    /// context for REPL- and exec-defined code.
+    pub source_registry: PyMutex<HashMap<String, String>>,
+    /// History, completer and startup hook shared between the `readline`
+    /// module and the CLI shell's line editor.
+    pub readline_state: stdlib::readline::ReadlineState,
+    /// `-X slow_frame_threshold=ms`: report any frame whose execution span
+    /// exceeds the threshold. `None` disables the watchdog entirely.
+    pub slow_frame_threshold: Option<std::time::Duration>,
+    /// Invoked instead of the default structured log line when a frame
+    /// overruns the threshold, see [`VirtualMachine::set_slow_frame_hook`].
+    pub slow_frame_hook: PyMutex<Option<SlowFrameHook>>,
 }
 
+/// Callback for the slow-frame watchdog: the offending frame (still on the
+/// frame stack) and how long it ran.
+pub type SlowFrameHook = std::sync::Arc<dyn Fn(&VirtualMachine, &FrameRef, std::time::Duration) + Send + Sync>;
+
 pub fn process_hash_secret_seed() -> u32 {
     use std::sync::OnceLock;
     static SEED: OnceLock<u32> = OnceLock::new();
@@ -158,6 +178,25 @@ impl VirtualMachine {
             -1 => 4300,
             other => other,
         } as usize);
+
+        let slow_frame_threshold = settings
+            .xoptions
+            .iter()
+            .find(|(key, _)| key == "slow_frame_threshold")
+            .and_then(|(_, value)| value.as_deref()?.parse::<f64>().ok())
+            .filter(|ms| *ms > 0.0)
+            .map(|ms| std::time::Duration::from_secs_f64(ms / 1e3));
+        if settings
+            .xoptions
+            .iter()
+            .any(|(key, _)| key == "thread_sanitizer")
+        {
+            if cfg!(debug_assertions) {
+                tsan::enable();
+            } else {
+                warn!("-X thread_sanitizer is only available in debug builds");
+            }
+        }
         let mut vm = VirtualMachine {
             builtins,
             sys_module,
@@ -189,6 +228,10 @@ impl VirtualMachine {
                 after_forkers_child: PyMutex::default(),
                 after_forkers_parent: PyMutex::default(),
                 int_max_str_digits,
+                source_registry: PyMutex::default(),
+                readline_state: Default::default(),
+                slow_frame_threshold,
+                slow_frame_hook: PyMutex::default(),
             }),
             initialized: false,
             recursion_depth: Cell::new(0),
@@ -448,6 +491,17 @@ impl VirtualMachine {
 
     pub fn run_code_obj(&self, code: PyRef<PyCode>, scope: Scope) -> PyResult {
         let frame = Frame::new(code, scope, self.builtins.dict(), &[], self).into_ref(&self.ctx);
+        // code compiled with PyCF_ALLOW_TOP_LEVEL_AWAIT evaluates to a
+        // coroutine for the caller (usually an asyncio REPL) to drive
+        if frame
+            .code
+            .code
+            .flags
+            .contains(crate::bytecode::CodeFlags::IS_COROUTINE)
+        {
+            let name = frame.code.code.obj_name.to_owned();
+            return Ok(crate::builtins::PyCoroutine::new(frame, name, self).into_pyobject(self));
+        }
         self.run_frame(frame)
     }
 
@@ -501,19 +555,71 @@ impl VirtualMachine {
     ) -> PyResult<R> {
         self.with_recursion("", || {
             self.frames.borrow_mut().push(frame.clone());
+            let started = self
+                .state
+                .slow_frame_threshold
+                .map(|_| std::time::Instant::now());
             let result = f(frame);
+            if let (Some(threshold), Some(started)) = (self.state.slow_frame_threshold, started) {
+                let elapsed = started.elapsed();
+                if elapsed > threshold {
+                    self.report_slow_frame(elapsed);
+                }
+            }
             // defer dec frame
             let _popped = self.frames.borrow_mut().pop();
             result
         })
     }
 
+    /// Register the slow-frame watchdog callback, replacing any previous one
+    /// (or, with `None`, restoring the default structured `warn!` line). Only
+    /// meaningful together with `-X slow_frame_threshold=ms`.
+    pub fn set_slow_frame_hook(&self, hook: Option<SlowFrameHook>) {
+        *self.state.slow_frame_hook.lock() = hook;
+    }
+
+    /// A frame overran `-X slow_frame_threshold`: hand it to the registered
+    /// hook, or log the Python stack at `warn` level.
+    fn report_slow_frame(&self, elapsed: std::time::Duration) {
+        let frame = match self.frames.borrow().last() {
+            Some(frame) => frame.clone(),
+            None => return,
+        };
+        // clone the hook out so it runs without the lock (or a frames borrow)
+        // held; it may well re-enter the vm
+        let hook = self.state.slow_frame_hook.lock().clone();
+        if let Some(hook) = hook {
+            hook(self, &frame, elapsed);
+            return;
+        }
+        use std::fmt::Write;
+        let mut stack = String::new();
+        for frame in self.frames.borrow().iter() {
+            let code = frame.f_code();
+            let _ = writeln!(
+                stack,
+                "  File \"{}\", line {}, in {}",
+                code.co_filename().as_str(),
+                frame.f_lineno(),
+                code.obj_name.as_str(),
+            );
+        }
+        warn!(
+            "slow frame: {:?} exceeded the {:?} threshold\n{}",
+            elapsed,
+            self.state.slow_frame_threshold.unwrap(),
+            stack.trim_end(),
+        );
+    }
+
     /// Returns a basic CompileOpts instance with options accurate to the vm. Used
     /// as the CompileOpts for `vm.compile()`.
     #[cfg(feature = "rustpython-codegen")]
     pub fn compile_opts(&self) -> crate::compiler::CompileOpts {
         crate::compiler::CompileOpts {
             optimize: self.state.settings.optimize,
+            ..Default::default()
         }
     }
 
@@ -826,6 +932,15 @@ impl VirtualMachine {
     pub(crate) fn contextualize_exception(&self, exception: &PyBaseExceptionRef) {
         if let Some(context_exc) = self.topmost_exception() {
             if !context_exc.is(exception) {
+                // already chained to the current exception; this happens for
+                // every frame an in-flight exception propagates through, so
+                // skip re-walking the context chain
+                if exception
+                    .context()
+                    .is_some_and(|context| context.is(&context_exc))
+                {
+                    return;
+                }
                 let mut o = context_exc.clone();
                 while let Some(context) = o.context() {
                     if context.is(exception) {
@@ -978,6 +1093,29 @@ impl VirtualMachine {
         let s = unsafe { OsString::from_encoded_bytes_unchecked(bytes) };
         Ok(Cow::Owned(s))
     }
+
+    /// Encode a string through the codec registry, like `str.encode()` from
+    /// Python. Any codec the registry can resolve may be named, including
+    /// codecs registered at runtime via `codecs.register`; `errors` of `None`
+    /// means "strict".
+    pub fn encode(&self, s: &str, encoding: &str, errors: Option<&str>) -> PyResult<PyBytesRef> {
+        let errors = errors.map(|e| self.ctx.new_str(e));
+        self.state
+            .codec_registry
+            .encode_text(self.ctx.new_str(s), encoding, errors, self)
+    }
+
+    /// Decode bytes through the codec registry, like `bytes.decode()` from
+    /// Python. The counterpart of [`Self::encode`].
+    pub fn decode(&self, data: &[u8], encoding: &str, errors: Option<&str>) -> PyResult<PyStrRef> {
+        let errors = errors.map(|e| self.ctx.new_str(e));
+        self.state.codec_registry.decode_text(
+            self.ctx.new_bytes(data.to_vec()).into(),
+            encoding,
+            errors,
+            self,
+        )
+    }
 }
 
 impl AsRef<Context> for VirtualMachine {
@@ -0,0 +1,85 @@
+//! Bridging Rust panics out of native functions, per
+//! [`PanicPolicy`](super::PanicPolicy).
+
+use super::{VirtualMachine, setting::PanicPolicy};
+use crate::{AsObject, PyResult};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    /// Set while a native call runs under [`PanicPolicy::Exception`], so the
+    /// panic hook captures the backtrace instead of printing a report.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The backtrace captured at the panic site by the hook.
+    static BACKTRACE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install a process-wide panic hook that, for panics about to be turned into
+/// exceptions on this thread, captures the panic-site backtrace (before the
+/// frames are unwound) and suppresses the default stderr report. Every other
+/// panic goes to the previously installed hook untouched.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let prev = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(|catching| catching.get()) {
+                let bt = std::backtrace::Backtrace::capture();
+                if bt.status() == std::backtrace::BacktraceStatus::Captured {
+                    BACKTRACE.with(|slot| *slot.borrow_mut() = Some(bt.to_string()));
+                }
+            } else {
+                prev(info);
+            }
+        }));
+    });
+}
+
+/// The `&str`/`String` a panic payload usually carries.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Run a native function under the configured
+/// [`Settings::panic_policy`](super::Settings::panic_policy).
+pub(crate) fn catch_native_panic(
+    vm: &VirtualMachine,
+    f: impl FnOnce() -> PyResult,
+) -> PyResult {
+    match vm.state.settings.panic_policy {
+        PanicPolicy::Unwind => f(),
+        PanicPolicy::Abort => match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(res) => res,
+            // the default hook already printed the panic report
+            Err(_) => std::process::abort(),
+        },
+        PanicPolicy::Exception => {
+            install_hook();
+            let was_catching = CATCHING.with(|catching| catching.replace(true));
+            let res = panic::catch_unwind(AssertUnwindSafe(f));
+            CATCHING.with(|catching| catching.set(was_catching));
+            match res {
+                Ok(res) => res,
+                Err(payload) => {
+                    let msg = panic_message(payload.as_ref());
+                    let exc = vm.new_system_error(format!("native function panicked: {msg}"));
+                    if let Some(bt) = BACKTRACE.with(|slot| slot.borrow_mut().take()) {
+                        // purely informational; ignore a failure to attach
+                        let _ = exc
+                            .as_object()
+                            .set_attr("rust_backtrace", vm.ctx.new_str(bt), vm);
+                    }
+                    Err(exc)
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common::wtf8::Wtf8Buf;
 #[cfg(feature = "flame-it")]
 use std::ffi::OsString;
 
@@ -34,7 +35,8 @@ pub struct Settings {
     // int parse_argv;
     // PyWideStringList orig_argv;
     /// sys.argv
-    pub argv: Vec<String>,
+    /// arguments are WTF-8 so non-UTF-8 OS data survives as PEP 383 surrogates
+    pub argv: Vec<Wtf8Buf>,
 
     /// -Xfoo[=bar]
     pub xoptions: Vec<(String, Option<String>)>,
@@ -88,6 +90,10 @@ pub struct Settings {
     /// -X int_max_str_digits
     pub int_max_str_digits: i64,
 
+    /// -X deterministic: pin the hash seed, seed RNGs deterministically and
+    /// make perf counters advance by a fixed quantum, for reproducible runs
+    pub deterministic: bool,
+
     // /* --- Path configuration inputs ------------ */
     // int pathconfig_warnings;
     // wchar_t *program_name;
@@ -112,6 +118,27 @@ pub struct Settings {
     pub profile_output: Option<OsString>,
     #[cfg(feature = "flame-it")]
     pub profile_format: Option<String>,
+
+    /// What to do when a Rust panic unwinds out of a native function called
+    /// from Python code. Embedder API only; not reachable from the command
+    /// line.
+    pub panic_policy: PanicPolicy,
+}
+
+/// Policy for Rust panics escaping native functions, see
+/// [`Settings::panic_policy`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Let the panic unwind through the interpreter and surface wherever the
+    /// embedder entered it (the historical behavior).
+    #[default]
+    Unwind,
+    /// Catch the panic and raise `SystemError` in its place, carrying the
+    /// panic message; when `RUST_BACKTRACE` enables capture, the Rust
+    /// backtrace is attached as a `rust_backtrace` attribute.
+    Exception,
+    /// Print the panic report and abort the process.
+    Abort,
 }
 
 #[derive(Debug, Default, Copy, Clone, strum_macros::Display, strum_macros::EnumString)]
@@ -160,10 +187,12 @@ impl Default for Settings {
             allow_external_library: cfg!(feature = "importlib"),
             utf8_mode: 1,
             int_max_str_digits: 4300,
+            deterministic: false,
             #[cfg(feature = "flame-it")]
             profile_output: None,
             #[cfg(feature = "flame-it")]
             profile_format: None,
+            panic_policy: PanicPolicy::default(),
         }
     }
 }
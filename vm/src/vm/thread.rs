@@ -24,6 +24,12 @@ pub fn with_current_vm<R>(f: impl FnOnce(&VirtualMachine) -> R) -> R {
     })
 }
 
+/// Like [`with_current_vm`], but returns `None` when no vm is entered on
+/// this thread instead of panicking.
+pub(crate) fn try_with_current_vm<R>(f: impl FnOnce(&VirtualMachine) -> R) -> Option<R> {
+    VM_CURRENT.with(|x| unsafe { x.clone().into_inner().as_ref().map(f) })
+}
+
 pub fn enter_vm<R>(vm: &VirtualMachine, f: impl FnOnce() -> R) -> R {
     VM_STACK.with(|vms| {
         vms.borrow_mut().push(vm.into());
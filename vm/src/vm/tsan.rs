@@ -0,0 +1,114 @@
+//! Debug-build detector for unsynchronized mutation of builtin containers
+//! from several Python threads.
+//!
+//! Enabled with `-X thread_sanitizer` in debug builds. Every mutation of a
+//! list, dict or set records the mutating thread together with its Python
+//! stack; the first time a second thread mutates the same container, both
+//! stacks are written to stderr. The container locks make such mutations
+//! memory-safe, but the interleaving is still a logic race the program
+//! usually didn't intend, and it is exactly what any future free-threading
+//! work has to audit.
+//!
+//! Containers are keyed by address, so an address the allocator reuses can
+//! in rare cases attribute a stale mutation to a new container; each
+//! container is reported at most once to keep the output readable.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::ThreadId;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turn the detector on; called during vm construction for
+/// `-X thread_sanitizer`.
+pub(crate) fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn enabled() -> bool {
+    cfg!(debug_assertions) && ENABLED.load(Ordering::Relaxed)
+}
+
+struct LastWriter {
+    thread: ThreadId,
+    /// Thread name (or id) and Python stack, pre-rendered: the thread may be
+    /// gone by the time another one trips over the same container.
+    label: String,
+    stack: String,
+    reported: bool,
+}
+
+static WRITERS: Mutex<Option<HashMap<usize, LastWriter>>> = Mutex::new(None);
+
+/// Record a mutation of `container`. When the previous mutation of the same
+/// container came from a different thread, write both Python stacks to
+/// stderr (once per container).
+#[inline]
+pub(crate) fn trace_mutation<T: ?Sized>(container: &T, kind: &str) {
+    if !enabled() {
+        return;
+    }
+    trace_mutation_slow(container as *const T as *const () as usize, kind);
+}
+
+fn trace_mutation_slow(addr: usize, kind: &str) {
+    let thread = std::thread::current();
+    let label = match thread.name() {
+        Some(name) => format!("{name} ({:?})", thread.id()),
+        None => format!("{:?}", thread.id()),
+    };
+    let stack = current_python_stack().unwrap_or_else(|| "  (no Python frames)".to_owned());
+    let mut writers = WRITERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match writers.get_or_insert_default().entry(addr) {
+        Entry::Occupied(mut entry) => {
+            let prev = entry.get_mut();
+            if prev.thread != thread.id() && !prev.reported {
+                eprintln!(
+                    "thread sanitizer: {kind} mutated from two threads without synchronization\n\
+                     earlier mutation in thread {}:\n{}\n\
+                     current mutation in thread {}:\n{}",
+                    prev.label, prev.stack, label, stack,
+                );
+                prev.reported = true;
+            }
+            prev.thread = thread.id();
+            prev.label = label;
+            prev.stack = stack;
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(LastWriter {
+                thread: thread.id(),
+                label,
+                stack,
+                reported: false,
+            });
+        }
+    }
+}
+
+/// The current thread's Python stack in traceback format, if it is running
+/// inside a vm and has frames on its stack.
+fn current_python_stack() -> Option<String> {
+    super::thread::try_with_current_vm(|vm| {
+        use std::fmt::Write;
+        let frames = vm.frames.borrow();
+        if frames.is_empty() {
+            return None;
+        }
+        let mut stack = String::new();
+        for frame in frames.iter() {
+            let code = frame.f_code();
+            let _ = writeln!(
+                stack,
+                "  File \"{}\", line {}, in {}",
+                code.co_filename().as_str(),
+                frame.f_lineno(),
+                code.obj_name.as_str(),
+            );
+        }
+        Some(stack.trim_end().to_owned())
+    })?
+}